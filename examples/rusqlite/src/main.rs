@@ -1,6 +1,9 @@
 use chrono::{NaiveDate, NaiveDateTime};
 use rusqlite::{Connection, Result, Row};
-use sea_query::{ColumnDef, Expr, Func, Iden, Order, Query, SqliteQueryBuilder, Table};
+use sea_query::{
+    migration::{Migration, Migrator},
+    ColumnDef, Expr, Func, Iden, Order, Query, SqliteQueryBuilder, Table,
+};
 
 sea_query::sea_query_driver_rusqlite!();
 use sea_query_driver_rusqlite::RusqliteValues;
@@ -152,6 +155,10 @@ fn main() -> Result<()> {
     println!("{}", count);
     println!();
 
+    // A pending statement would keep the tables locked during the migration
+    drop(rows);
+    drop(stmt);
+
     // Delete
 
     let (sql, values) = Query::delete()
@@ -165,6 +172,26 @@ fn main() -> Result<()> {
     );
     println!("Delete character: {:?}", result);
 
+    // Migration
+
+    let migrator = Migrator::new()
+        .migration(
+            Migration::new(1, "create_font")
+                .up(Table::create()
+                    .table(Font::Table)
+                    .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+                    .to_owned())
+                .down(Table::drop().table(Font::Table).to_owned()),
+        )
+        .to_owned();
+    let mut executor = sea_query_driver_rusqlite::RusqliteMigrationExecutor(&conn);
+
+    let result = migrator.up(SqliteQueryBuilder, &mut executor);
+    println!("Migrate up: {:?}", result);
+
+    let result = migrator.down(SqliteQueryBuilder, &mut executor, 1);
+    println!("Migrate down: {:?}", result);
+
     Ok(())
 }
 
@@ -201,3 +228,9 @@ impl From<&Row<'_>> for CharacterStruct {
         }
     }
 }
+
+#[derive(Iden)]
+enum Font {
+    Table,
+    Id,
+}
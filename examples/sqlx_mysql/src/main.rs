@@ -1,7 +1,10 @@
 use bigdecimal::{BigDecimal, FromPrimitive};
 use chrono::NaiveDate;
 use rust_decimal::Decimal;
-use sea_query::{ColumnDef, Expr, Func, Iden, MysqlQueryBuilder, Order, Query, Table};
+use sea_query::{
+    migration::{Migration, Migrator},
+    ColumnDef, Expr, Func, Iden, MysqlQueryBuilder, Order, Query, Table,
+};
 use sqlx::{types::chrono::NaiveDateTime, MySqlPool, Row};
 
 sea_query::sea_query_driver_mysql!();
@@ -172,6 +175,28 @@ async fn main() {
         .execute(&mut pool)
         .await;
     println!("Delete character: {:?}", result);
+
+    // Migration
+
+    let migrator = Migrator::new()
+        .migration(
+            Migration::new(1, "create_font")
+                .up(Table::create()
+                    .table(Font::Table)
+                    .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+                    .to_owned())
+                .down(Table::drop().table(Font::Table).to_owned()),
+        )
+        .to_owned();
+    let mut executor = sea_query_driver_mysql::SqlxMySqlMigrationExecutor(&mut pool);
+
+    let result = migrator.up_async(MysqlQueryBuilder, &mut executor).await;
+    println!("Migrate up: {:?}", result);
+
+    let result = migrator
+        .down_async(MysqlQueryBuilder, &mut executor, 1)
+        .await;
+    println!("Migrate down: {:?}", result);
 }
 
 #[derive(Iden)]
@@ -198,3 +223,9 @@ struct CharacterStruct {
     big_decimal: BigDecimal,
     created: NaiveDateTime,
 }
+
+#[derive(Iden)]
+enum Font {
+    Table,
+    Id,
+}
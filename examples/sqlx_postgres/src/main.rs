@@ -1,7 +1,10 @@
 use bigdecimal::{BigDecimal, FromPrimitive};
 use chrono::{NaiveDate, NaiveDateTime};
 use rust_decimal::Decimal;
-use sea_query::{ColumnDef, Expr, Func, Iden, Order, PostgresQueryBuilder, Query, Table};
+use sea_query::{
+    migration::{Migration, Migrator},
+    ColumnDef, Expr, Func, Iden, Order, PostgresQueryBuilder, Query, Table,
+};
 use sqlx::{PgPool, Row};
 
 sea_query::sea_query_driver_postgres!();
@@ -174,6 +177,28 @@ async fn main() {
         .execute(&mut pool)
         .await;
     println!("Delete character: {:?}", result);
+
+    // Migration
+
+    let migrator = Migrator::new()
+        .migration(
+            Migration::new(1, "create_font")
+                .up(Table::create()
+                    .table(Font::Table)
+                    .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+                    .to_owned())
+                .down(Table::drop().table(Font::Table).to_owned()),
+        )
+        .to_owned();
+    let mut executor = sea_query_driver_postgres::SqlxPostgresMigrationExecutor(&mut pool);
+
+    let result = migrator.up_async(PostgresQueryBuilder, &mut executor).await;
+    println!("Migrate up: {:?}", result);
+
+    let result = migrator
+        .down_async(PostgresQueryBuilder, &mut executor, 1)
+        .await;
+    println!("Migrate down: {:?}", result);
 }
 
 #[derive(Iden)]
@@ -200,3 +225,9 @@ struct CharacterStruct {
     big_decimal: BigDecimal,
     created: NaiveDateTime,
 }
+
+#[derive(Iden)]
+enum Font {
+    Table,
+    Id,
+}
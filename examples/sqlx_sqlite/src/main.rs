@@ -1,5 +1,8 @@
 use chrono::{NaiveDate, NaiveDateTime};
-use sea_query::{ColumnDef, Expr, Func, Iden, Order, Query, SqliteQueryBuilder, Table};
+use sea_query::{
+    migration::{Migration, Migrator},
+    ColumnDef, Expr, Func, Iden, Order, Query, SqliteQueryBuilder, Table,
+};
 use sqlx::{Row, SqlitePool};
 
 sea_query::sea_query_driver_sqlite!();
@@ -146,6 +149,29 @@ async fn main() {
     let result = bind_query(sqlx::query(&sql), &values).execute(&pool).await;
 
     println!("Delete character: {:?}", result);
+
+    // Migration
+
+    let migrator = Migrator::new()
+        .migration(
+            Migration::new(1, "create_font")
+                .up(Table::create()
+                    .table(Font::Table)
+                    .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+                    .to_owned())
+                .down(Table::drop().table(Font::Table).to_owned()),
+        )
+        .to_owned();
+    let mut conn = pool.acquire().await.unwrap();
+    let mut executor = sea_query_driver_sqlite::SqlxSqliteMigrationExecutor(&mut conn);
+
+    let result = migrator.up_async(SqliteQueryBuilder, &mut executor).await;
+    println!("Migrate up: {:?}", result);
+
+    let result = migrator
+        .down_async(SqliteQueryBuilder, &mut executor, 1)
+        .await;
+    println!("Migrate down: {:?}", result);
 }
 
 #[derive(Iden)]
@@ -168,3 +194,9 @@ struct CharacterStruct {
     meta: Json,
     created: NaiveDateTime,
 }
+
+#[derive(Iden)]
+enum Font {
+    Table,
+    Id,
+}
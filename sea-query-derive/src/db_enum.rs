@@ -0,0 +1,95 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{DataEnum, DeriveInput, Error, Fields, Ident};
+
+use crate::{error::ErrorMsg, iden_attr::ContainerAttrs};
+
+/// Postgres enum type of a Rust enum with unit variants, one value per variant
+pub struct DbEnum<'a> {
+    input: &'a DeriveInput,
+    type_name: String,
+    values: Vec<(&'a Ident, String)>,
+}
+
+impl<'a> DbEnum<'a> {
+    pub fn new(input: &'a DeriveInput, data: &'a DataEnum) -> syn::Result<Self> {
+        let container = ContainerAttrs::parse(&input.attrs)?;
+        let values = data
+            .variants
+            .iter()
+            .map(|variant| match variant.fields {
+                Fields::Unit => Ok((&variant.ident, container.variant_name(variant)?)),
+                _ => Err(Error::new_spanned(variant, ErrorMsg::NonUnitVariant)),
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            input,
+            type_name: container.table_name(&input.ident),
+            values,
+        })
+    }
+
+    pub fn expand(&self) -> TokenStream {
+        let ident = &self.input.ident;
+        let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
+        let type_name = &self.type_name;
+        let values: Vec<&String> = self.values.iter().map(|(_, value)| value).collect();
+        let match_arms = self
+            .values
+            .iter()
+            .map(|(variant, value)| quote! { Self::#variant => #value });
+
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                pub fn type_name() -> &'static str {
+                    #type_name
+                }
+
+                pub fn values() -> Vec<&'static str> {
+                    vec![#(#values),*]
+                }
+
+                pub fn as_str(&self) -> &'static str {
+                    match self {
+                        #(#match_arms),*
+                    }
+                }
+
+                pub fn create_type() -> sea_query::extension::postgres::TypeCreateStatement {
+                    sea_query::extension::postgres::Type::create()
+                        .as_enum(sea_query::Alias::new(#type_name))
+                        .values([#(sea_query::Alias::new(#values)),*])
+                        .to_owned()
+                }
+
+                pub fn alter_type(
+                    existing: &[&str],
+                ) -> Vec<sea_query::extension::postgres::TypeAlterStatement> {
+                    sea_query::extension::postgres::Type::add_enum_values(
+                        sea_query::Alias::new(#type_name),
+                        existing.iter(),
+                        Self::values().iter(),
+                    )
+                }
+
+                pub fn column_type() -> sea_query::ColumnType {
+                    sea_query::ColumnType::Enum(
+                        #type_name.to_owned(),
+                        vec![#(#values.to_owned()),*],
+                    )
+                }
+
+                pub fn as_enum(self) -> sea_query::SimpleExpr {
+                    sea_query::Expr::val(self).as_enum(sea_query::Alias::new(#type_name))
+                }
+            }
+
+            impl #impl_generics From<#ident #ty_generics> for sea_query::Value #where_clause {
+                fn from(value: #ident #ty_generics) -> Self {
+                    value.as_str().into()
+                }
+            }
+        }
+    }
+}
@@ -1,21 +1,52 @@
 use syn::Ident;
 
-use crate::iden_path::IdenPath;
+use crate::{iden_path::IdenPath, rename_rule::RenameRule};
 
 #[derive(Debug, thiserror::Error)]
 pub enum ErrorMsg {
-    #[error("Only the attributes `#[iden = \"name\"]` or `#[iden(rename = \"name\") are supported in this position")]
+    #[error("Only the attributes `#[iden = \"name\"]`, `#[iden(rename = \"name\")]`, `#[iden(rename_all = \"case\")]` or `#[iden(prefix = \"prefix\")]` are supported in this position")]
     ContainerAttr,
+    #[error("Only the attributes `#[iden = \"name\"]` or `#[iden(rename = \"name\")]` are supported on {0}")]
+    RenameOnly(&'static str),
     #[error("Must be a string literal")]
     WrongLiteral,
     #[error("The method attribute only supports the `#[{0} = \"name\"]` or `#[iden({0} = \"name\")]` formats")]
     WrongNamedValueFormat(IdenPath, IdenPath),
-    #[error("Must one of the following attributes: `flatten`, `rename` or `method`")]
+    #[error("Must one of the following attributes: `flatten`, `rename`, `rename_all`, `prefix` or `method`")]
     WrongListFormat,
-    #[error("The iden attribute supports only the formats `#[iden = \"name\"]` or `#[iden(<ATTRIBUTE>)]` where ATTRIBUTE is either `flatten`, `rename` or `method`")]
+    #[error("Only one attribute is supported in this position, e.g. `#[iden(rename = \"name\")]`")]
+    MultipleAttrs,
+    #[error("The iden attribute supports only the formats `#[iden = \"name\"]` or `#[iden(<ATTRIBUTE>)]` where ATTRIBUTE is either `flatten`, `rename`, `rename_all`, `prefix` or `method`")]
     WrongAttributeFormat,
+    #[error("`rename_all` and `prefix` are only supported on the enum or struct itself")]
+    VariantAttr,
+    #[error(
+        "`{0}` is not a supported case convention, use one of {}",
+        RenameRule::supported()
+    )]
+    UnsupportedRenameRule(String),
     #[error("{0} is not a supported keyword")]
     UnsupportedKeyword(Ident),
     #[error("Must have a single field is supported for flattenning")]
     UnsupportedFlattenTarget,
+    #[error("The {0} attribute supports only the format `#[{0}(...)]`")]
+    ExpectedList(&'static str),
+    #[error("The {0} attribute supports only the format `#[{0}]`")]
+    ExpectedFlag(&'static str),
+    #[error("Missing `{0}`")]
+    MissingKey(&'static str),
+    #[error("Must be a field name")]
+    WrongIndexColumn,
+    #[error("`{0}` is not a field of the struct")]
+    UnknownIndexColumn(Ident),
+    #[error(
+        "Must be a `ColumnDef` method setting the type, like `\"text\"` or `\"string_len(64)\"`"
+    )]
+    WrongColumnType,
+    #[error("Only unit variants are supported in a database enum")]
+    NonUnitVariant,
+    #[error("Cannot infer the column type of this field, set it with `#[column(type = \"...\")]`")]
+    UnsupportedColumnType,
+    #[error("`{0}` is not a foreign key action, use one of `Restrict`, `Cascade`, `SetNull`, `NoAction` or `SetDefault`")]
+    UnsupportedForeignKeyAction(String),
 }
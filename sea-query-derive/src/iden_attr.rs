@@ -1,16 +1,26 @@
 use std::convert::{TryFrom, TryInto};
 
-use syn::{Attribute, Error, Ident, Lit, Meta, MetaNameValue, NestedMeta};
+use syn::{Attribute, Error, Field, Ident, Lit, Meta, MetaNameValue, NestedMeta, Variant};
 
-use crate::{error::ErrorMsg, iden_path::IdenPath};
+use crate::{error::ErrorMsg, find_attr, iden_path::IdenPath, rename_rule::RenameRule};
 
 #[derive(PartialEq)]
 pub enum IdenAttr {
     Rename(String),
+    RenameAll(RenameRule),
+    Prefix(String),
     Method(Ident),
     Flatten,
 }
 
+/// Attributes of the enum or struct deriving `Iden`
+#[derive(Default)]
+pub struct ContainerAttrs {
+    rename: Option<String>,
+    rename_all: Option<RenameRule>,
+    prefix: Option<String>,
+}
+
 impl IdenAttr {
     fn extract_method(meta: Meta) -> syn::Result<Self> {
         match meta {
@@ -31,23 +41,57 @@ impl IdenAttr {
                 Lit::Str(lit) => Ok(IdenAttr::Rename(lit.value())),
                 _ => Err(Error::new_spanned(&nv.lit, ErrorMsg::WrongLiteral)),
             },
+            Meta::List(list) if list.nested.len() > 1 => {
+                Err(Error::new_spanned(meta, ErrorMsg::MultipleAttrs))
+            }
             Meta::List(list) => match list.nested.first() {
-                Some(NestedMeta::Meta(Meta::Path(p))) if p.is_ident(&IdenPath::Flatten) => {
-                    Ok(IdenAttr::Flatten)
-                }
-                Some(NestedMeta::Meta(Meta::NameValue(nv))) => Self::extract_named_value_iden(nv),
-                _ => Err(Error::new_spanned(meta, ErrorMsg::WrongListFormat)),
+                Some(nested) => Self::extract_nested_iden(&meta, nested),
+                None => Err(Error::new_spanned(meta, ErrorMsg::WrongListFormat)),
             },
             a => Err(Error::new_spanned(a, ErrorMsg::WrongAttributeFormat)),
         }
     }
 
+    fn extract_nested_iden(meta: &Meta, nested: &NestedMeta) -> syn::Result<Self> {
+        match nested {
+            NestedMeta::Meta(Meta::Path(p)) if p.is_ident(&IdenPath::Flatten) => {
+                Ok(IdenAttr::Flatten)
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) => Self::extract_named_value_iden(nv),
+            _ => Err(Error::new_spanned(meta, ErrorMsg::WrongListFormat)),
+        }
+    }
+
+    /// Every attribute listed in `#[iden(...)]`, e.g. `#[iden(rename_all = "camelCase", prefix = "x_")]`
+    fn extract_all(attr: &Attribute) -> syn::Result<Vec<Self>> {
+        let meta = attr.parse_meta()?;
+        match &meta {
+            Meta::List(list) if list.path.is_ident(&IdenPath::Iden) && list.nested.len() > 1 => {
+                list.nested
+                    .iter()
+                    .map(|nested| Self::extract_nested_iden(&meta, nested))
+                    .collect()
+            }
+            _ => Ok(vec![meta.try_into()?]),
+        }
+    }
+
     fn extract_named_value_iden(nv: &MetaNameValue) -> syn::Result<Self> {
         match &nv.lit {
             Lit::Str(name) => {
                 // Don't match "iden" since that would mean `#[iden(iden = "name")]` would be accepted
                 if nv.path.is_ident(&IdenPath::Rename) {
                     Ok(Self::Rename(name.value()))
+                } else if nv.path.is_ident(&IdenPath::RenameAll) {
+                    match name.value().parse() {
+                        Ok(rule) => Ok(Self::RenameAll(rule)),
+                        Err(()) => Err(Error::new_spanned(
+                            name,
+                            ErrorMsg::UnsupportedRenameRule(name.value()),
+                        )),
+                    }
+                } else if nv.path.is_ident(&IdenPath::Prefix) {
+                    Ok(Self::Prefix(name.value()))
                 } else if nv.path.is_ident(&IdenPath::Method) {
                     Ok(Self::Method(Ident::new(name.value().as_str(), name.span())))
                 } else {
@@ -62,6 +106,86 @@ impl IdenAttr {
     }
 }
 
+impl IdenAttr {
+    /// Whether the attribute may only be set on the enum or struct itself
+    pub fn is_container_only(&self) -> bool {
+        matches!(self, Self::RenameAll(_) | Self::Prefix(_))
+    }
+}
+
+impl ContainerAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut container = Self::default();
+        for attr in attrs.iter().filter(|attr| is_iden_attr(attr)) {
+            for iden_attr in IdenAttr::extract_all(attr)? {
+                match iden_attr {
+                    IdenAttr::Rename(name) => container.rename = Some(name),
+                    IdenAttr::RenameAll(rule) => container.rename_all = Some(rule),
+                    IdenAttr::Prefix(prefix) => container.prefix = Some(prefix),
+                    IdenAttr::Method(_) | IdenAttr::Flatten => {
+                        return Err(Error::new_spanned(attr, ErrorMsg::ContainerAttr))
+                    }
+                }
+            }
+        }
+        Ok(container)
+    }
+
+    /// Name of the table, unless renamed the type name in the case convention
+    pub fn table_name(&self, ident: &Ident) -> String {
+        match &self.rename {
+            Some(name) => name.clone(),
+            None => self
+                .rename_all
+                .unwrap_or_default()
+                .apply(&ident.to_string()),
+        }
+    }
+
+    /// Name of a column not renamed by itself, the variant or field name in the case convention
+    /// following the prefix
+    pub fn column_name(&self, ident: &Ident) -> String {
+        let ident = ident.to_string();
+        format!(
+            "{}{}",
+            self.prefix.as_deref().unwrap_or_default(),
+            self.rename_all
+                .unwrap_or_default()
+                .apply(ident.trim_start_matches("r#"))
+        )
+    }
+
+    /// Name of the column of a named struct field, renamed by `#[iden = "name"]` or by convention
+    pub fn field_name(&self, field: &Field) -> syn::Result<String> {
+        // Unwrapping the ident is safe because a named field always has an ident.
+        self.renamed(field.ident.as_ref().unwrap(), &field.attrs, "struct fields")
+    }
+
+    /// Name of an enum value of a unit variant, renamed by `#[iden = "name"]` or by convention
+    pub fn variant_name(&self, variant: &Variant) -> syn::Result<String> {
+        self.renamed(&variant.ident, &variant.attrs, "enum variants")
+    }
+
+    fn renamed(
+        &self,
+        ident: &Ident,
+        attrs: &[Attribute],
+        position: &'static str,
+    ) -> syn::Result<String> {
+        match find_attr(attrs) {
+            Some(attr) => match IdenAttr::try_from(attr)? {
+                IdenAttr::Rename(name) => Ok(name),
+                _ => Err(Error::new_spanned(attr, ErrorMsg::RenameOnly(position))),
+            },
+            None => Ok(self.column_name(ident)),
+        }
+    }
+}
+
+pub fn is_iden_attr(attr: &Attribute) -> bool {
+    attr.path.is_ident(&IdenPath::Iden) || attr.path.is_ident(&IdenPath::Method)
+}
+
 impl TryFrom<&Attribute> for IdenAttr {
     type Error = Error;
 
@@ -5,6 +5,8 @@ pub enum IdenPath {
     Iden,
     Method,
     Rename,
+    RenameAll,
+    Prefix,
     Flatten,
 }
 
@@ -14,6 +16,8 @@ impl IdenPath {
             IdenPath::Iden => "iden",
             IdenPath::Method => "method",
             IdenPath::Rename => "rename",
+            IdenPath::RenameAll => "rename_all",
+            IdenPath::Prefix => "prefix",
             IdenPath::Flatten => "flatten",
         }
     }
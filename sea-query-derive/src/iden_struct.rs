@@ -0,0 +1,82 @@
+use heck::CamelCase;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FieldsNamed, Ident, Visibility};
+
+use crate::iden_attr::ContainerAttrs;
+
+/// Companion enum of a struct with named fields, one variant per field along with `Table`
+pub struct IdenStruct<'a> {
+    ident: Ident,
+    vis: &'a Visibility,
+    table_name: String,
+    columns: Vec<(Ident, String)>,
+}
+
+impl<'a> IdenStruct<'a> {
+    pub fn new(
+        ident: &Ident,
+        vis: &'a Visibility,
+        container: &ContainerAttrs,
+        table_name: String,
+        fields: &FieldsNamed,
+    ) -> syn::Result<Self> {
+        let columns = fields
+            .named
+            .iter()
+            .map(|field| {
+                // Unwrapping the ident is safe because a named field always has an ident.
+                let field_ident = field.ident.as_ref().unwrap();
+                let name = container.field_name(field)?;
+                let mut variant = name_of(field_ident).to_camel_case();
+                // `Table` is taken by the table name
+                if variant == "Table" {
+                    variant.push_str("Column");
+                }
+                Ok((format_ident!("{}", variant), name))
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            ident: format_ident!("{}Iden", ident),
+            vis,
+            table_name,
+            columns,
+        })
+    }
+
+    pub fn expand(&self) -> TokenStream {
+        let Self {
+            ident,
+            vis,
+            table_name,
+            ..
+        } = self;
+        let variants = self.columns.iter().map(|(variant, _)| variant);
+        let match_arms = self
+            .columns
+            .iter()
+            .map(|(variant, name)| quote! { Self::#variant => write!(s, "{}", #name).unwrap() });
+
+        quote! {
+            #[derive(Copy, Clone, Debug)]
+            #vis enum #ident {
+                Table,
+                #(#variants),*
+            }
+
+            impl sea_query::Iden for #ident {
+                fn unquoted(&self, s: &mut dyn sea_query::Write) {
+                    match self {
+                        Self::Table => write!(s, "{}", #table_name).unwrap(),
+                        #(#match_arms),*
+                    };
+                }
+            }
+        }
+    }
+}
+
+fn name_of(ident: &Ident) -> String {
+    ident.to_string().trim_start_matches("r#").to_owned()
+}
@@ -1,32 +1,46 @@
 use std::convert::TryFrom;
 
-use heck::SnakeCase;
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens, TokenStreamExt};
 use syn::{Error, Fields, FieldsNamed, Ident, Variant};
 
-use crate::{error::ErrorMsg, find_attr, iden_attr::IdenAttr};
+use crate::{
+    error::ErrorMsg,
+    find_attr,
+    iden_attr::{ContainerAttrs, IdenAttr},
+};
 
 pub struct IdenVariant<'a> {
     ident: &'a Ident,
     fields: &'a Fields,
+    container: &'a ContainerAttrs,
     table_name: &'a str,
     attr: Option<IdenAttr>,
 }
 
-impl<'a> TryFrom<(&'a str, &'a Variant)> for IdenVariant<'a> {
+impl<'a> TryFrom<(&'a ContainerAttrs, &'a str, &'a Variant)> for IdenVariant<'a> {
     type Error = Error;
 
-    fn try_from((table_name, value): (&'a str, &'a Variant)) -> Result<Self, Self::Error> {
+    fn try_from(
+        (container, table_name, value): (&'a ContainerAttrs, &'a str, &'a Variant),
+    ) -> Result<Self, Self::Error> {
         let Variant {
             ident,
             fields,
             attrs,
             ..
         } = value;
-        let attr = find_attr(attrs).map(IdenAttr::try_from).transpose()?;
+        let attr = match find_attr(attrs) {
+            Some(attr) => match IdenAttr::try_from(attr)? {
+                iden_attr if iden_attr.is_container_only() => {
+                    return Err(Error::new_spanned(attr, ErrorMsg::VariantAttr))
+                }
+                iden_attr => Some(iden_attr),
+            },
+            None => None,
+        };
 
-        Self::new(ident, fields, table_name, attr)
+        Self::new(ident, fields, container, table_name, attr)
     }
 }
 
@@ -44,6 +58,7 @@ impl<'a> IdenVariant<'a> {
     fn new(
         ident: &'a Ident,
         fields: &'a Fields,
+        container: &'a ContainerAttrs,
         table_name: &'a str,
         attr: Option<IdenAttr>,
     ) -> syn::Result<Self> {
@@ -71,6 +86,7 @@ impl<'a> IdenVariant<'a> {
         Ok(Self {
             ident,
             fields,
+            container,
             table_name,
             attr,
         })
@@ -117,12 +133,12 @@ impl<'a> IdenVariant<'a> {
         tokens.append_all(self.write_variant_name(variant))
     }
 
-    fn table_or_snake_case(&self) -> TokenStream {
+    fn table_or_column_name(&self) -> TokenStream {
         if self.ident == "Table" {
             let table_name = self.table_name;
             quote! { #table_name }
         } else {
-            let name = self.ident.to_string().to_snake_case();
+            let name = self.container.column_name(self.ident);
             quote! { #name }
         }
     }
@@ -134,9 +150,9 @@ impl<'a> IdenVariant<'a> {
             .map(|a| match a {
                 IdenAttr::Rename(name) => quote! { #name },
                 IdenAttr::Method(method) => quote! { self.#method() },
-                IdenAttr::Flatten => unreachable!(),
+                IdenAttr::RenameAll(_) | IdenAttr::Prefix(_) | IdenAttr::Flatten => unreachable!(),
             })
-            .unwrap_or_else(|| self.table_or_snake_case());
+            .unwrap_or_else(|| self.table_or_column_name());
 
         write_variant(variant, name)
     }
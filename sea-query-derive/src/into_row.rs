@@ -0,0 +1,98 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, DeriveInput, Error, FieldsNamed, Ident, Meta};
+
+use crate::{error::ErrorMsg, iden_attr::ContainerAttrs};
+
+pub const SKIP_INSERT: &str = "skip_insert";
+pub const SKIP_UPDATE: &str = "skip_update";
+
+/// `IntoRow` of a struct with named fields, one column per field
+pub struct IntoRow<'a> {
+    input: &'a DeriveInput,
+    columns: Vec<Column<'a>>,
+}
+
+struct Column<'a> {
+    field: &'a Ident,
+    name: String,
+    skip_insert: bool,
+    skip_update: bool,
+}
+
+impl<'a> IntoRow<'a> {
+    pub fn new(input: &'a DeriveInput, fields: &'a FieldsNamed) -> syn::Result<Self> {
+        let container = ContainerAttrs::parse(&input.attrs)?;
+        let columns = fields
+            .named
+            .iter()
+            .map(|field| {
+                Ok(Column {
+                    // Unwrapping the ident is safe because a named field always has an ident.
+                    field: field.ident.as_ref().unwrap(),
+                    name: container.field_name(field)?,
+                    skip_insert: has_flag(&field.attrs, SKIP_INSERT)?,
+                    skip_update: has_flag(&field.attrs, SKIP_UPDATE)?,
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+
+        Ok(Self { input, columns })
+    }
+
+    pub fn expand(&self) -> TokenStream {
+        let ident = &self.input.ident;
+        let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
+
+        let inserted = || self.columns.iter().filter(|column| !column.skip_insert);
+        let insert_names = inserted().map(|column| column.iden());
+        let insert_values = inserted().map(|column| column.value());
+        let update_pairs = self
+            .columns
+            .iter()
+            .filter(|column| !column.skip_update)
+            .map(|column| {
+                let (iden, value) = (column.iden(), column.value());
+                quote! { (#iden, #value) }
+            });
+
+        quote! {
+            impl #impl_generics sea_query::IntoRow for #ident #ty_generics #where_clause {
+                fn columns() -> Vec<sea_query::DynIden> {
+                    vec![#(#insert_names),*]
+                }
+
+                fn values(&self) -> Vec<sea_query::Value> {
+                    vec![#(#insert_values),*]
+                }
+
+                fn update_values(&self) -> Vec<(sea_query::DynIden, sea_query::Value)> {
+                    vec![#(#update_pairs),*]
+                }
+            }
+        }
+    }
+}
+
+impl Column<'_> {
+    fn iden(&self) -> TokenStream {
+        let name = &self.name;
+        quote! { sea_query::IntoIden::into_iden(sea_query::Alias::new(#name)) }
+    }
+
+    fn value(&self) -> TokenStream {
+        let field = self.field;
+        quote! { sea_query::Value::from(::std::clone::Clone::clone(&self.#field)) }
+    }
+}
+
+/// Whether a field has the `#[name]` attribute
+fn has_flag(attrs: &[Attribute], name: &'static str) -> syn::Result<bool> {
+    match attrs.iter().find(|attr| attr.path.is_ident(name)) {
+        Some(attr) => match attr.parse_meta()? {
+            Meta::Path(_) => Ok(true),
+            meta => Err(Error::new_spanned(meta, ErrorMsg::ExpectedFlag(name))),
+        },
+        None => Ok(false),
+    }
+}
@@ -1,43 +1,50 @@
-use std::convert::{TryFrom, TryInto};
+use std::convert::TryFrom;
 
-use heck::SnakeCase;
 use proc_macro::{self, TokenStream};
 use quote::{quote, quote_spanned};
 use syn::{parse_macro_input, Attribute, DataEnum, DataStruct, DeriveInput, Fields};
 
+mod db_enum;
 mod error;
 mod iden_attr;
 mod iden_path;
+mod iden_struct;
 mod iden_variant;
+mod into_row;
+mod rename_rule;
+mod table_attr;
+mod table_create;
 
-use self::{error::ErrorMsg, iden_attr::IdenAttr, iden_path::IdenPath, iden_variant::IdenVariant};
+use self::{
+    db_enum::DbEnum,
+    iden_attr::{is_iden_attr, ContainerAttrs},
+    iden_struct::IdenStruct,
+    iden_variant::IdenVariant,
+    into_row::IntoRow,
+    table_create::TableCreate,
+};
 
 fn find_attr(attrs: &[Attribute]) -> Option<&Attribute> {
-    attrs
-        .iter()
-        .find(|attr| attr.path.is_ident(&IdenPath::Iden) || attr.path.is_ident(&IdenPath::Method))
+    attrs.iter().find(|attr| is_iden_attr(attr))
 }
 
 #[proc_macro_derive(Iden, attributes(iden, method))]
 pub fn derive_iden(input: TokenStream) -> TokenStream {
     let DeriveInput {
-        ident, data, attrs, ..
+        ident,
+        vis,
+        data,
+        attrs,
+        ..
     } = parse_macro_input!(input);
 
-    let table_name = match find_attr(&attrs) {
-        Some(att) => match att.try_into() {
-            Ok(IdenAttr::Rename(lit)) => lit,
-            Ok(_) => {
-                return syn::Error::new_spanned(att, ErrorMsg::ContainerAttr)
-                    .into_compile_error()
-                    .into()
-            }
-            Err(e) => return e.into_compile_error().into(),
-        },
-        None => ident.to_string().to_snake_case(),
+    let container = match ContainerAttrs::parse(&attrs) {
+        Ok(container) => container,
+        Err(e) => return e.into_compile_error().into(),
     };
+    let table_name = container.table_name(&ident);
 
-    // Currently we only support enums and unit structs
+    // Currently we only support enums, unit structs and structs with named fields
     let variants =
         match data {
             syn::Data::Enum(DataEnum { variants, .. }) => variants,
@@ -54,8 +61,17 @@ pub fn derive_iden(input: TokenStream) -> TokenStream {
                 }
                 .into()
             }
+            syn::Data::Struct(DataStruct {
+                fields: Fields::Named(fields),
+                ..
+            }) => {
+                return match IdenStruct::new(&ident, &vis, &container, table_name, &fields) {
+                    Ok(iden_struct) => iden_struct.expand().into(),
+                    Err(e) => e.to_compile_error().into(),
+                }
+            }
             _ => return quote_spanned! {
-                ident.span() => compile_error!("you can only derive Iden on enums or unit structs");
+                ident.span() => compile_error!("you can only derive Iden on enums, unit structs or structs with named fields");
             }
             .into(),
         };
@@ -66,7 +82,7 @@ pub fn derive_iden(input: TokenStream) -> TokenStream {
 
     let match_arms = match variants
         .iter()
-        .map(|v| (table_name.as_str(), v))
+        .map(|v| (&container, table_name.as_str(), v))
         .map(IdenVariant::try_from)
         .collect::<syn::Result<Vec<_>>>()
     {
@@ -86,3 +102,96 @@ pub fn derive_iden(input: TokenStream) -> TokenStream {
 
     output.into()
 }
+
+/// Derive `create_table()`, building the `TableCreateStatement` of a struct with one column per field.
+///
+/// Table and column names follow the `#[iden]` attributes, as in `derive(Iden)`.
+/// Column types are inferred from the field types, `Option` fields are nullable.
+///
+/// - `#[column(primary_key, auto_increment, unique, default = 0, default_expr = "SQL", type = "string_len(64)")]` on fields
+/// - `#[foreign_key(name = "fk", table = "font", column = "id", on_delete = "Cascade", on_update = "Cascade")]` on fields
+/// - `#[index(name = "idx", columns(field, ...), unique)]` on the struct
+#[proc_macro_derive(Table, attributes(iden, column, index, foreign_key))]
+pub fn derive_table(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input);
+
+    let fields = match &input.data {
+        syn::Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => fields,
+        _ => {
+            return quote_spanned! {
+                input.ident.span() => compile_error!("you can only derive Table on structs with named fields");
+            }
+            .into()
+        }
+    };
+
+    match TableCreate::new(&input, fields).and_then(|table| table.expand()) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Derive `sea_query::IntoRow`, listing the columns and values of a struct with one column per field,
+/// for `InsertStatement::from_rows` and `UpdateStatement::set_from`.
+///
+/// Column names follow the `#[iden]` attributes, as in `derive(Iden)`.
+/// Field values are cloned and converted with `Value::from`.
+///
+/// - `#[skip_insert]` on fields left out of inserts, like generated columns
+/// - `#[skip_update]` on fields left out of updates, like primary keys
+#[proc_macro_derive(IntoRow, attributes(iden, skip_insert, skip_update))]
+pub fn derive_into_row(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input);
+
+    let fields = match &input.data {
+        syn::Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => fields,
+        _ => {
+            return quote_spanned! {
+                input.ident.span() => compile_error!("you can only derive IntoRow on structs with named fields");
+            }
+            .into()
+        }
+    };
+
+    match IntoRow::new(&input, fields) {
+        Ok(into_row) => into_row.expand().into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+/// Derive the Postgres enum type of an enum with unit variants, one value per variant.
+///
+/// The type name and the values follow the `#[iden]` attributes, as in `derive(Iden)`.
+/// Requires the `backend-postgres` feature of `sea-query`, generating
+///
+/// - `type_name()`, `values()` and `as_str(&self)` naming the type and its values
+/// - `create_type()`, the `TypeCreateStatement` of the type
+/// - `alter_type(existing)`, the `TypeAlterStatement`s adding the values missing from `existing`
+/// - `column_type()`, the matching `ColumnType::Enum`
+/// - `as_enum(self)`, the value cast to the type
+/// - `From<T> for Value`
+#[proc_macro_derive(DbEnum, attributes(iden))]
+pub fn derive_db_enum(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input);
+
+    let data = match &input.data {
+        syn::Data::Enum(data) => data,
+        _ => {
+            return quote_spanned! {
+                input.ident.span() => compile_error!("you can only derive DbEnum on enums");
+            }
+            .into()
+        }
+    };
+
+    match DbEnum::new(&input, data) {
+        Ok(db_enum) => db_enum.expand().into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,80 @@
+use std::{fmt::Display, str::FromStr};
+
+use heck::{CamelCase, KebabCase, MixedCase, ShoutySnakeCase, SnakeCase};
+
+/// Case convention of the names written by the derived `Iden`, set by `#[iden(rename_all = "...")]`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    #[default]
+    Snake,
+    ScreamingSnake,
+    Kebab,
+}
+
+impl RenameRule {
+    const ALL: [Self; 7] = [
+        Self::Lower,
+        Self::Upper,
+        Self::Pascal,
+        Self::Camel,
+        Self::Snake,
+        Self::ScreamingSnake,
+        Self::Kebab,
+    ];
+
+    const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Lower => "lowercase",
+            Self::Upper => "UPPERCASE",
+            Self::Pascal => "PascalCase",
+            Self::Camel => "camelCase",
+            Self::Snake => "snake_case",
+            Self::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            Self::Kebab => "kebab-case",
+        }
+    }
+
+    /// Convert a Rust identifier, either a type, a variant or a field name
+    pub fn apply(&self, ident: &str) -> String {
+        match self {
+            Self::Lower => ident.to_snake_case().replace('_', ""),
+            Self::Upper => ident.to_shouty_snake_case().replace('_', ""),
+            Self::Pascal => ident.to_camel_case(),
+            Self::Camel => ident.to_mixed_case(),
+            Self::Snake => ident.to_snake_case(),
+            Self::ScreamingSnake => ident.to_shouty_snake_case(),
+            Self::Kebab => ident.to_kebab_case(),
+        }
+    }
+
+    /// The supported conventions, as listed in error messages
+    pub fn supported() -> String {
+        Self::ALL
+            .iter()
+            .map(|rule| format!("`{}`", rule))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl FromStr for RenameRule {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .iter()
+            .find(|rule| rule.as_str() == s)
+            .copied()
+            .ok_or(())
+    }
+}
+
+impl Display for RenameRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
@@ -0,0 +1,197 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Attribute, Error, Expr, Ident, Lit, Meta, MetaList, NestedMeta, Path};
+
+use crate::error::ErrorMsg;
+
+pub const COLUMN: &str = "column";
+pub const INDEX: &str = "index";
+pub const FOREIGN_KEY: &str = "foreign_key";
+
+const FOREIGN_KEY_ACTIONS: [&str; 5] = ["Restrict", "Cascade", "SetNull", "NoAction", "SetDefault"];
+
+/// `#[column(...)]` of a struct field
+#[derive(Default)]
+pub struct ColumnAttr {
+    pub primary_key: bool,
+    pub auto_increment: bool,
+    pub unique: bool,
+    pub default: Option<TokenStream>,
+    pub column_type: Option<TokenStream>,
+}
+
+/// `#[index(...)]` of the struct
+pub struct IndexAttr {
+    pub name: Option<String>,
+    pub columns: Vec<Ident>,
+    pub unique: bool,
+}
+
+/// `#[foreign_key(...)]` of a struct field
+pub struct ForeignKeyAttr {
+    pub name: Option<String>,
+    pub table: String,
+    pub column: String,
+    pub on_delete: Option<Ident>,
+    pub on_update: Option<Ident>,
+}
+
+impl ColumnAttr {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut column = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path.is_ident(COLUMN)) {
+            for meta in list_items(attr, COLUMN)? {
+                match &meta {
+                    Meta::Path(path) if path.is_ident("primary_key") => column.primary_key = true,
+                    Meta::Path(path) if path.is_ident("auto_increment") => {
+                        column.auto_increment = true
+                    }
+                    Meta::Path(path) if path.is_ident("unique") => column.unique = true,
+                    Meta::NameValue(nv) if nv.path.is_ident("default") => {
+                        let lit = &nv.lit;
+                        column.default = Some(quote! { #lit });
+                    }
+                    Meta::NameValue(nv) if nv.path.is_ident("default_expr") => {
+                        let sql = string_value(&nv.lit)?;
+                        column.default = Some(quote! { sea_query::Expr::cust(#sql) });
+                    }
+                    Meta::NameValue(nv) if nv.path.is_ident("type") => {
+                        column.column_type = Some(column_type(&nv.lit)?);
+                    }
+                    _ => return Err(unsupported_keyword(meta.path(), COLUMN)),
+                }
+            }
+        }
+        Ok(column)
+    }
+}
+
+impl IndexAttr {
+    pub fn parse(attr: &Attribute) -> syn::Result<Self> {
+        let mut index = Self {
+            name: None,
+            columns: Vec::new(),
+            unique: false,
+        };
+        for meta in list_items(attr, INDEX)? {
+            match &meta {
+                Meta::Path(path) if path.is_ident("unique") => index.unique = true,
+                Meta::NameValue(nv) if nv.path.is_ident("name") => {
+                    index.name = Some(string_value(&nv.lit)?)
+                }
+                Meta::List(MetaList { path, nested, .. }) if path.is_ident("columns") => {
+                    for column in nested.iter() {
+                        match column {
+                            NestedMeta::Meta(Meta::Path(path)) if path.get_ident().is_some() => {
+                                index.columns.push(path.get_ident().unwrap().clone())
+                            }
+                            _ => {
+                                return Err(Error::new_spanned(column, ErrorMsg::WrongIndexColumn))
+                            }
+                        }
+                    }
+                }
+                _ => return Err(unsupported_keyword(meta.path(), INDEX)),
+            }
+        }
+        if index.columns.is_empty() {
+            return Err(Error::new_spanned(attr, ErrorMsg::MissingKey("columns")));
+        }
+        Ok(index)
+    }
+}
+
+impl ForeignKeyAttr {
+    pub fn parse(attr: &Attribute) -> syn::Result<Self> {
+        let mut name = None;
+        let mut table = None;
+        let mut column = None;
+        let mut on_delete = None;
+        let mut on_update = None;
+        for meta in list_items(attr, FOREIGN_KEY)? {
+            match &meta {
+                Meta::NameValue(nv) if nv.path.is_ident("name") => {
+                    name = Some(string_value(&nv.lit)?)
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("table") => {
+                    table = Some(string_value(&nv.lit)?)
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("column") => {
+                    column = Some(string_value(&nv.lit)?)
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("on_delete") => {
+                    on_delete = Some(foreign_key_action(&nv.lit)?)
+                }
+                Meta::NameValue(nv) if nv.path.is_ident("on_update") => {
+                    on_update = Some(foreign_key_action(&nv.lit)?)
+                }
+                _ => return Err(unsupported_keyword(meta.path(), FOREIGN_KEY)),
+            }
+        }
+        Ok(Self {
+            name,
+            table: table.ok_or_else(|| Error::new_spanned(attr, ErrorMsg::MissingKey("table")))?,
+            column: column
+                .ok_or_else(|| Error::new_spanned(attr, ErrorMsg::MissingKey("column")))?,
+            on_delete,
+            on_update,
+        })
+    }
+}
+
+/// Items of a `#[name(...)]` attribute
+fn list_items(attr: &Attribute, name: &'static str) -> syn::Result<Vec<Meta>> {
+    match attr.parse_meta()? {
+        Meta::List(list) => list
+            .nested
+            .into_iter()
+            .map(|nested| match nested {
+                NestedMeta::Meta(meta) => Ok(meta),
+                NestedMeta::Lit(lit) => Err(Error::new_spanned(lit, ErrorMsg::ExpectedList(name))),
+            })
+            .collect(),
+        meta => Err(Error::new_spanned(meta, ErrorMsg::ExpectedList(name))),
+    }
+}
+
+fn string_value(lit: &Lit) -> syn::Result<String> {
+    match lit {
+        Lit::Str(s) => Ok(s.value()),
+        _ => Err(Error::new_spanned(lit, ErrorMsg::WrongLiteral)),
+    }
+}
+
+/// A `ColumnDef` method setting the type, written as `"text"` or `"string_len(64)"`
+fn column_type(lit: &Lit) -> syn::Result<TokenStream> {
+    let wrong_column_type = || Error::new_spanned(lit, ErrorMsg::WrongColumnType);
+    let expr: Expr = match lit {
+        Lit::Str(s) => s.parse().map_err(|_| wrong_column_type())?,
+        _ => return Err(Error::new_spanned(lit, ErrorMsg::WrongLiteral)),
+    };
+    match expr {
+        Expr::Path(path) if path.path.get_ident().is_some() => Ok(quote! { #path() }),
+        Expr::Call(call) if matches!(&*call.func, Expr::Path(path) if path.path.get_ident().is_some()) => {
+            Ok(quote! { #call })
+        }
+        _ => Err(wrong_column_type()),
+    }
+}
+
+fn foreign_key_action(lit: &Lit) -> syn::Result<Ident> {
+    let action = string_value(lit)?;
+    if FOREIGN_KEY_ACTIONS.contains(&action.as_str()) {
+        Ok(Ident::new(&action, lit.span()))
+    } else {
+        Err(Error::new_spanned(
+            lit,
+            ErrorMsg::UnsupportedForeignKeyAction(action),
+        ))
+    }
+}
+
+fn unsupported_keyword(path: &Path, name: &'static str) -> Error {
+    match path.get_ident() {
+        Some(ident) => Error::new_spanned(path, ErrorMsg::UnsupportedKeyword(ident.clone())),
+        None => Error::new_spanned(path, ErrorMsg::ExpectedList(name)),
+    }
+}
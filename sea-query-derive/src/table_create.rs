@@ -0,0 +1,231 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    DeriveInput, Error, Field, FieldsNamed, GenericArgument, Ident, PathArguments, Type, TypePath,
+};
+
+use crate::{
+    error::ErrorMsg,
+    iden_attr::ContainerAttrs,
+    table_attr::{ColumnAttr, ForeignKeyAttr, IndexAttr, FOREIGN_KEY, INDEX},
+};
+
+/// `create_table` of a struct with named fields, one column per field
+pub struct TableCreate<'a> {
+    input: &'a DeriveInput,
+    table_name: String,
+    columns: Vec<Column<'a>>,
+    indexes: Vec<IndexAttr>,
+}
+
+struct Column<'a> {
+    field: &'a Field,
+    name: String,
+    attr: ColumnAttr,
+    foreign_keys: Vec<ForeignKeyAttr>,
+}
+
+impl<'a> TableCreate<'a> {
+    pub fn new(input: &'a DeriveInput, fields: &'a FieldsNamed) -> syn::Result<Self> {
+        let container = ContainerAttrs::parse(&input.attrs)?;
+        let columns = fields
+            .named
+            .iter()
+            .map(|field| {
+                Ok(Column {
+                    field,
+                    name: container.field_name(field)?,
+                    attr: ColumnAttr::parse(&field.attrs)?,
+                    foreign_keys: field
+                        .attrs
+                        .iter()
+                        .filter(|attr| attr.path.is_ident(FOREIGN_KEY))
+                        .map(ForeignKeyAttr::parse)
+                        .collect::<syn::Result<_>>()?,
+                })
+            })
+            .collect::<syn::Result<Vec<_>>>()?;
+        let indexes = input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident(INDEX))
+            .map(IndexAttr::parse)
+            .collect::<syn::Result<_>>()?;
+
+        Ok(Self {
+            input,
+            table_name: container.table_name(&input.ident),
+            columns,
+            indexes,
+        })
+    }
+
+    pub fn expand(&self) -> syn::Result<TokenStream> {
+        let ident = &self.input.ident;
+        let (impl_generics, ty_generics, where_clause) = self.input.generics.split_for_impl();
+        let table_name = &self.table_name;
+
+        // A primary key of several columns is set on the table rather than on each column
+        let primary_keys: Vec<&Column> = self
+            .columns
+            .iter()
+            .filter(|column| column.attr.primary_key)
+            .collect();
+        let composite_key = primary_keys.len() > 1;
+
+        let mut calls = Vec::new();
+        for column in self.columns.iter() {
+            let column_def = column.column_def(!composite_key)?;
+            calls.push(quote! { .col(#column_def) });
+        }
+        if composite_key {
+            let names = primary_keys.iter().map(|column| alias(&column.name));
+            calls.push(quote! {
+                .primary_key(sea_query::Index::create()#(.col(#names))*)
+            });
+        }
+        for index in self.indexes.iter() {
+            let mut index_calls = Vec::new();
+            if let Some(name) = &index.name {
+                index_calls.push(quote! { .name(#name) });
+            }
+            for column in index.columns.iter() {
+                let name = alias(&self.column_name(column)?);
+                index_calls.push(quote! { .col(#name) });
+            }
+            if index.unique {
+                index_calls.push(quote! { .unique() });
+            }
+            calls.push(quote! { .index(sea_query::Index::create()#(#index_calls)*) });
+        }
+        for column in self.columns.iter() {
+            for foreign_key in column.foreign_keys.iter() {
+                let mut fk_calls = Vec::new();
+                if let Some(name) = &foreign_key.name {
+                    fk_calls.push(quote! { .name(#name) });
+                }
+                let (table, from) = (alias(table_name), alias(&column.name));
+                let (ref_table, to) = (alias(&foreign_key.table), alias(&foreign_key.column));
+                fk_calls.push(quote! { .from(#table, #from).to(#ref_table, #to) });
+                if let Some(action) = &foreign_key.on_delete {
+                    fk_calls.push(quote! { .on_delete(sea_query::ForeignKeyAction::#action) });
+                }
+                if let Some(action) = &foreign_key.on_update {
+                    fk_calls.push(quote! { .on_update(sea_query::ForeignKeyAction::#action) });
+                }
+                calls.push(quote! {
+                    .foreign_key(sea_query::ForeignKey::create()#(#fk_calls)*)
+                });
+            }
+        }
+
+        let table = alias(table_name);
+        Ok(quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                pub fn create_table() -> sea_query::TableCreateStatement {
+                    sea_query::Table::create()
+                        .table(#table)
+                        #(#calls)*
+                        .to_owned()
+                }
+            }
+        })
+    }
+
+    fn column_name(&self, field: &Ident) -> syn::Result<String> {
+        self.columns
+            .iter()
+            .find(|column| column.field.ident.as_ref() == Some(field))
+            .map(|column| column.name.clone())
+            .ok_or_else(|| Error::new_spanned(field, ErrorMsg::UnknownIndexColumn(field.clone())))
+    }
+}
+
+impl Column<'_> {
+    fn column_def(&self, primary_key: bool) -> syn::Result<TokenStream> {
+        let name = alias(&self.name);
+        let (ty, nullable) = match option_inner(&self.field.ty) {
+            Some(inner) => (inner, true),
+            None => (&self.field.ty, false),
+        };
+        let column_type = match &self.attr.column_type {
+            Some(column_type) => column_type.clone(),
+            None => column_type(ty)
+                .ok_or_else(|| Error::new_spanned(ty, ErrorMsg::UnsupportedColumnType))?,
+        };
+
+        let mut calls = vec![quote! { .#column_type }];
+        if !nullable {
+            calls.push(quote! { .not_null() });
+        }
+        if self.attr.auto_increment {
+            calls.push(quote! { .auto_increment() });
+        }
+        if self.attr.unique {
+            calls.push(quote! { .unique_key() });
+        }
+        if self.attr.primary_key && primary_key {
+            calls.push(quote! { .primary_key() });
+        }
+        if let Some(default) = &self.attr.default {
+            calls.push(quote! { .default(#default) });
+        }
+        Ok(quote! { sea_query::ColumnDef::new(#name)#(#calls)* })
+    }
+}
+
+fn alias(name: &str) -> TokenStream {
+    quote! { sea_query::Alias::new(#name) }
+}
+
+/// The last segment of a type path and its generic type arguments
+fn last_segment(ty: &Type) -> Option<(String, Vec<&Type>)> {
+    let segment = match ty {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+    let args = match &segment.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    };
+    Some((segment.ident.to_string(), args))
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    match last_segment(ty)? {
+        (ident, args) if ident == "Option" && args.len() == 1 => Some(args[0]),
+        _ => None,
+    }
+}
+
+/// The `ColumnDef` method setting the column type of a Rust type
+fn column_type(ty: &Type) -> Option<TokenStream> {
+    let (ident, args) = last_segment(ty)?;
+    Some(match ident.as_str() {
+        "bool" => quote! { boolean() },
+        "i8" => quote! { tiny_integer() },
+        "i16" | "u8" => quote! { small_integer() },
+        "i32" | "u16" => quote! { integer() },
+        "i64" | "u32" => quote! { big_integer() },
+        "f32" => quote! { float() },
+        "f64" => quote! { double() },
+        "char" => quote! { char_len(1) },
+        "String" => quote! { string() },
+        "Vec" if args.len() == 1 && last_segment(args[0])?.0 == "u8" => quote! { binary() },
+        "NaiveDate" => quote! { date() },
+        "NaiveTime" => quote! { time() },
+        "NaiveDateTime" => quote! { date_time() },
+        "DateTime" => quote! { timestamp_with_time_zone() },
+        "Uuid" => quote! { uuid() },
+        "Decimal" | "BigDecimal" => quote! { decimal() },
+        "Json" => quote! { json() },
+        _ => return None,
+    })
+}
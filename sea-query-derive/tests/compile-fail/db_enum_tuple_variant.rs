@@ -0,0 +1,9 @@
+use sea_query::DbEnum;
+
+#[derive(DbEnum)]
+enum FontFamily {
+    Serif,
+    Custom(String),
+}
+
+fn main() {}
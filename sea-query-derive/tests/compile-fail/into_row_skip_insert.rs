@@ -0,0 +1,10 @@
+use sea_query::IntoRow;
+
+#[derive(IntoRow)]
+struct Glyph {
+    #[skip_insert(always)]
+    id: i32,
+    aspect: f64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use sea_query::Iden;
+
+#[derive(Iden)]
+enum Asset {
+    Table,
+    #[iden(rename = "asset_id", method = "id")]
+    Id,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use sea_query::Table;
+
+#[derive(Table)]
+struct Character {
+    id: i32,
+    #[foreign_key(table = "font", column = "id", on_delete = "cascade")]
+    font_id: i32,
+}
+
+fn main() {}
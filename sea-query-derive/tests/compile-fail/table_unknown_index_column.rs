@@ -0,0 +1,10 @@
+use sea_query::Table;
+
+#[derive(Table)]
+#[index(name = "idx-user-email", columns(email))]
+struct User {
+    id: i32,
+    mail: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sea_query::Table;
+
+#[derive(Table)]
+struct User {
+    id: i32,
+    tags: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use sea_query::Table;
+
+#[derive(Table)]
+struct User {
+    #[column(type = "text + 1")]
+    name: String,
+}
+
+fn main() {}
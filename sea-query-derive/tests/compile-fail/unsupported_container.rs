@@ -1,8 +1,6 @@
 use sea_query::Iden;
 
 #[derive(Iden)]
-struct User {
-    id: usize,
-}
+struct User(usize);
 
 fn main() {}
@@ -0,0 +1,9 @@
+use sea_query::Iden;
+
+#[derive(Iden)]
+struct User {
+    #[iden(flatten)]
+    id: usize,
+}
+
+fn main() {}
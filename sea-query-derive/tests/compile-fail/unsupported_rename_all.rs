@@ -0,0 +1,11 @@
+use sea_query::Iden;
+
+#[derive(Iden)]
+#[iden(rename_all = "camel_case")]
+enum User {
+    Table,
+    Id,
+    FirstName,
+}
+
+fn main() {}
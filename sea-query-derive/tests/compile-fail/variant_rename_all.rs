@@ -0,0 +1,11 @@
+use sea_query::Iden;
+
+#[derive(Iden)]
+enum User {
+    Table,
+    Id,
+    #[iden(rename_all = "camelCase")]
+    FirstName,
+}
+
+fn main() {}
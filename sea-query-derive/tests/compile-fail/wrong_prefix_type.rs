@@ -0,0 +1,11 @@
+use sea_query::Iden;
+
+#[derive(Iden)]
+#[iden(prefix = 1)]
+enum User {
+    Table,
+    Id,
+    FirstName,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use sea_query::Iden;
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Iden, EnumIter)]
+#[iden(rename_all = "camelCase", prefix = "x_")]
+enum FooBar {
+    Table,
+    Id,
+    FooBar,
+}
+
+fn main() {
+    let expected = ["fooBar", "x_id", "x_fooBar"];
+    FooBar::iter()
+        .map(|var| Iden::to_string(&var))
+        .zip(expected)
+        .for_each(|(iden, exp)| assert_eq!(iden, exp));
+}
@@ -0,0 +1,27 @@
+use sea_query::Iden;
+
+#[derive(Iden)]
+#[allow(dead_code)]
+struct User {
+    id: i32,
+    #[iden = "mail"]
+    email: String,
+    first_name: String,
+    table: String,
+}
+
+fn main() {
+    let variants = [
+        UserIden::Table,
+        UserIden::Id,
+        UserIden::Email,
+        UserIden::FirstName,
+        UserIden::TableColumn,
+    ];
+    let expected = ["user", "id", "mail", "first_name", "table"];
+    variants
+        .iter()
+        .map(Iden::to_string)
+        .zip(expected)
+        .for_each(|(iden, exp)| assert_eq!(iden, exp))
+}
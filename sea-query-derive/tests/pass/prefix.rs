@@ -0,0 +1,21 @@
+use sea_query::Iden;
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Iden, EnumIter)]
+#[iden = "users"]
+#[iden(prefix = "usr_")]
+enum User {
+    Table,
+    Id,
+    FirstName,
+    #[iden = "email"]
+    Email,
+}
+
+fn main() {
+    let expected = ["users", "usr_id", "usr_first_name", "email"];
+    User::iter()
+        .map(|var| Iden::to_string(&var))
+        .zip(expected)
+        .for_each(|(iden, exp)| assert_eq!(iden, exp));
+}
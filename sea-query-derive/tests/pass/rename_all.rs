@@ -0,0 +1,34 @@
+use sea_query::Iden;
+use strum::{EnumIter, IntoEnumIterator};
+
+#[derive(Iden, EnumIter)]
+#[iden(rename_all = "camelCase")]
+enum UserProfile {
+    Table,
+    Id,
+    FirstName,
+    #[iden = "e_mail"]
+    Email,
+}
+
+#[derive(Iden, EnumIter)]
+#[iden(rename_all = "SCREAMING_SNAKE_CASE")]
+enum LegacyUser {
+    Table,
+    UserId,
+    LastLogin,
+}
+
+fn main() {
+    let expected = ["userProfile", "id", "firstName", "e_mail"];
+    UserProfile::iter()
+        .map(|var| Iden::to_string(&var))
+        .zip(expected)
+        .for_each(|(iden, exp)| assert_eq!(iden, exp));
+
+    let expected = ["LEGACY_USER", "USER_ID", "LAST_LOGIN"];
+    LegacyUser::iter()
+        .map(|var| Iden::to_string(&var))
+        .zip(expected)
+        .for_each(|(iden, exp)| assert_eq!(iden, exp));
+}
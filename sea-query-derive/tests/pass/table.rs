@@ -0,0 +1,30 @@
+use sea_query::{PostgresQueryBuilder, Table};
+
+#[derive(Table)]
+#[allow(dead_code)]
+struct Event {
+    #[column(primary_key)]
+    id: uuid::Uuid,
+    price: rust_decimal::Decimal,
+    day: chrono::NaiveDate,
+    starts_at: Option<chrono::NaiveTime>,
+    created_at: chrono::NaiveDateTime,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+fn main() {
+    assert_eq!(
+        Event::create_table().to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "event" ("#,
+            r#""id" uuid NOT NULL PRIMARY KEY,"#,
+            r#""price" decimal NOT NULL,"#,
+            r#""day" date NOT NULL,"#,
+            r#""starts_at" time,"#,
+            r#""created_at" timestamp without time zone NOT NULL,"#,
+            r#""updated_at" timestamp with time zone NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
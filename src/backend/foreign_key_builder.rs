@@ -1,12 +1,31 @@
-use crate::*;
+use crate::{error::Result, *};
 
 pub trait ForeignKeyBuilder: QuotedBuilder {
+    /// Whether foreign keys can be added to and dropped from existing tables.
+    fn alterable_foreign_keys(&self) -> bool {
+        true
+    }
+
+    /// Check that the backend supports [`ForeignKeyCreateStatement`] on an existing table.
+    fn check_foreign_key_create_statement(
+        &self,
+        _create: &ForeignKeyCreateStatement,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`ForeignKeyDropStatement`].
+    fn check_foreign_key_drop_statement(&self, _drop: &ForeignKeyDropStatement) -> Result<()> {
+        Ok(())
+    }
+
     /// Translate [`ForeignKeyCreateStatement`] into SQL statement.
     fn prepare_foreign_key_create_statement(
         &self,
         create: &ForeignKeyCreateStatement,
         sql: &mut SqlWriter,
     ) {
+        check_supported(self.check_foreign_key_create_statement(create));
         self.prepare_foreign_key_create_statement_internal(create, sql, false)
     }
 
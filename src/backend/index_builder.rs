@@ -1,6 +1,44 @@
-use crate::*;
+use crate::{
+    error::{Error, Result},
+    *,
+};
 
 pub trait IndexBuilder: QuotedBuilder {
+    /// Check that the backend supports [`IndexCreateStatement`].
+    fn check_index_create_statement(&self, _create: &IndexCreateStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`IndexCreateStatement`] inside a table definition.
+    fn check_table_index_expression(&self, create: &IndexCreateStatement) -> Result<()> {
+        if create.r#where.is_some() {
+            return Err(Error::Unsupported(
+                "Partial index is not available in a table definition.".to_owned(),
+            ));
+        }
+        if !create.include_columns.is_empty() {
+            return Err(Error::Unsupported(
+                "Covering index is not available in a table definition.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Check that the backend supports [`IndexDropStatement`].
+    fn check_index_drop_statement(&self, _drop: &IndexDropStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`IndexRenameStatement`].
+    fn check_index_rename_statement(&self, _rename: &IndexRenameStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`IndexReindexStatement`].
+    fn check_index_reindex_statement(&self, _reindex: &IndexReindexStatement) -> Result<()> {
+        Ok(())
+    }
+
     /// Translate [`IndexCreateStatement`] into SQL expression.
     fn prepare_table_index_expression(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         self.prepare_index_prefix(create, sql);
@@ -15,6 +53,7 @@ pub trait IndexBuilder: QuotedBuilder {
 
     /// Translate [`IndexCreateStatement`] into SQL statement.
     fn prepare_index_create_statement(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_index_create_statement(create));
         write!(sql, "CREATE ").unwrap();
         self.prepare_index_prefix(create, sql);
         write!(sql, "INDEX ").unwrap();
@@ -29,11 +68,32 @@ pub trait IndexBuilder: QuotedBuilder {
         self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_where(&create.r#where, sql);
     }
 
     /// Translate [`IndexDropStatement`] into SQL statement.
     fn prepare_index_drop_statement(&self, drop: &IndexDropStatement, sql: &mut SqlWriter);
 
+    /// Translate [`IndexRenameStatement`] into SQL statement.
+    fn prepare_index_rename_statement(&self, rename: &IndexRenameStatement, sql: &mut SqlWriter);
+
+    /// Translate [`IndexReindexStatement`] into SQL statement.
+    fn prepare_index_reindex_statement(&self, reindex: &IndexReindexStatement, sql: &mut SqlWriter);
+
+    /// Translate [`IndexDropOpt`] into SQL statement.
+    fn prepare_index_drop_opt(&self, drop_opt: &IndexDropOpt, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match drop_opt {
+                IndexDropOpt::Cascade => "CASCADE",
+                IndexDropOpt::Restrict => "RESTRICT",
+            }
+        )
+        .unwrap();
+    }
+
     #[doc(hidden)]
     /// Write the index type (Btree, hash, ...).
     fn prepare_index_type(&self, _col_index_type: &Option<IndexType>, _sql: &mut SqlWriter) {}
@@ -50,6 +110,26 @@ pub trait IndexBuilder: QuotedBuilder {
         }
     }
 
+    #[doc(hidden)]
+    /// Write the column index collation.
+    fn write_column_index_collate(&self, col_collate: &Option<String>, sql: &mut SqlWriter) {
+        if let Some(collate) = col_collate {
+            write!(sql, " COLLATE ").unwrap();
+            Alias::new(collate).prepare(sql, self.quote());
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write the column index nulls ordering.
+    fn write_column_index_nulls(&self, col_nulls: &Option<NullOrdering>, sql: &mut SqlWriter) {
+        if let Some(nulls) = col_nulls {
+            match nulls {
+                NullOrdering::First => write!(sql, " NULLS FIRST").unwrap(),
+                NullOrdering::Last => write!(sql, " NULLS LAST").unwrap(),
+            }
+        }
+    }
+
     #[doc(hidden)]
     /// Write the column index prefix.
     fn prepare_index_columns(&self, columns: &[IndexColumn], sql: &mut SqlWriter) {
@@ -58,24 +138,97 @@ pub trait IndexBuilder: QuotedBuilder {
             if !first {
                 write!(sql, ", ").unwrap();
             }
-            col.name.prepare(sql, self.quote());
-            self.write_column_index_prefix(&col.prefix, sql);
+            match &col.expr {
+                Some(expr) => {
+                    write!(sql, "(").unwrap();
+                    self.prepare_index_expr(expr, sql);
+                    write!(sql, ")").unwrap();
+                }
+                None => {
+                    col.name.prepare(sql, self.quote());
+                    self.write_column_index_prefix(&col.prefix, sql);
+                }
+            }
+            self.write_column_index_collate(&col.collate, sql);
             if let Some(order) = &col.order {
                 match order {
                     IndexOrder::Asc => write!(sql, " ASC").unwrap(),
                     IndexOrder::Desc => write!(sql, " DESC").unwrap(),
                 }
             }
+            self.write_column_index_nulls(&col.nulls, sql);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
+    #[doc(hidden)]
+    /// Write the non-key columns of a covering index.
+    fn prepare_index_include(&self, columns: &[DynIden], sql: &mut SqlWriter) {
+        if columns.is_empty() {
+            return;
+        }
+        write!(sql, " INCLUDE (").unwrap();
+        columns.iter().fold(true, |first, col| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            col.prepare(sql, self.quote());
             false
         });
         write!(sql, ")").unwrap();
     }
 
+    #[doc(hidden)]
+    /// Write the predicate of a partial index.
+    fn prepare_index_where(&self, condition: &Option<SimpleExpr>, sql: &mut SqlWriter) {
+        if let Some(condition) = condition {
+            write!(sql, " WHERE ").unwrap();
+            self.prepare_index_expr(condition, sql);
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write an index expression.
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter);
+
     #[doc(hidden)]
     /// Write index name.
     fn prepare_index_name(&self, name: &Option<String>, sql: &mut SqlWriter) {
         if let Some(name) = name {
-            write!(sql, "{}{}{}", self.quote(), name, self.quote()).unwrap();
+            Alias::new(name).prepare(sql, self.quote());
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write the index name, qualified by its schema if any.
+    fn prepare_index_schema_name(
+        &self,
+        schema: &Option<DynIden>,
+        name: &Option<String>,
+        sql: &mut SqlWriter,
+    ) {
+        if let Some(schema) = schema {
+            schema.prepare(sql, self.quote());
+            write!(sql, ".").unwrap();
+        }
+        self.prepare_index_name(name, sql);
+    }
+
+    #[doc(hidden)]
+    /// Write the table name, qualified by a schema if any.
+    fn prepare_index_schema_table(
+        &self,
+        schema: &Option<DynIden>,
+        table: &Option<DynIden>,
+        sql: &mut SqlWriter,
+    ) {
+        if let Some(table) = table {
+            if let Some(schema) = schema {
+                schema.prepare(sql, self.quote());
+                write!(sql, ".").unwrap();
+            }
+            table.prepare(sql, self.quote());
         }
     }
 }
@@ -21,17 +21,43 @@ pub use sqlite::*;
 
 mod foreign_key_builder;
 mod index_builder;
+mod namespace_builder;
+mod privilege_builder;
 mod query_builder;
 mod table_builder;
+mod transaction_builder;
+mod trigger_builder;
+mod view_builder;
 
 pub use self::foreign_key_builder::*;
 pub use self::index_builder::*;
+pub use self::namespace_builder::*;
+pub use self::privilege_builder::*;
 pub use self::query_builder::*;
 pub use self::table_builder::*;
+pub use self::transaction_builder::*;
+pub use self::trigger_builder::*;
+pub use self::view_builder::*;
 
-pub trait GenericBuilder: QueryBuilder + SchemaBuilder {}
+/// Panic with the error of a statement the backend does not support, as the SQL is built without a `Result`
+pub(crate) fn check_supported(result: error::Result<()>) {
+    if let Err(err) = result {
+        panic!("{}", err);
+    }
+}
+
+pub trait GenericBuilder: QueryBuilder + SchemaBuilder + TransactionBuilder {}
 
-pub trait SchemaBuilder: TableBuilder + IndexBuilder + ForeignKeyBuilder {}
+pub trait SchemaBuilder:
+    TableBuilder
+    + IndexBuilder
+    + ForeignKeyBuilder
+    + ViewBuilder
+    + TriggerBuilder
+    + NamespaceBuilder
+    + PrivilegeBuilder
+{
+}
 
 pub trait QuotedBuilder {
     /// The type of quote the builder uses.
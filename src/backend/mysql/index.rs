@@ -1,6 +1,81 @@
 use super::*;
+use crate::error::{Error, Result};
 
 impl IndexBuilder for MysqlQueryBuilder {
+    fn check_index_create_statement(&self, create: &IndexCreateStatement) -> Result<()> {
+        if create.concurrently {
+            return Err(Error::Unsupported(
+                "Create index concurrently is not available in MySQL.".to_owned(),
+            ));
+        }
+        if create.if_not_exists {
+            return Err(Error::Unsupported(
+                "Create index if not exists is not available in MySQL.".to_owned(),
+            ));
+        }
+        self.check_table_index_expression(create)
+    }
+
+    fn check_table_index_expression(&self, create: &IndexCreateStatement) -> Result<()> {
+        if create.r#where.is_some() {
+            return Err(Error::Unsupported(
+                "Partial index is not available in MySQL.".to_owned(),
+            ));
+        }
+        if !create.include_columns.is_empty() {
+            return Err(Error::Unsupported(
+                "Covering index is not available in MySQL.".to_owned(),
+            ));
+        }
+        for column in create.index.columns.iter() {
+            if column.collate.is_some() {
+                return Err(Error::Unsupported(
+                    "Index column collation is not available in MySQL.".to_owned(),
+                ));
+            }
+            if column.nulls.is_some() {
+                return Err(Error::Unsupported(
+                    "Index column nulls ordering is not available in MySQL.".to_owned(),
+                ));
+            }
+        }
+        check_index_type(&create.index_type)
+    }
+
+    fn check_index_drop_statement(&self, drop: &IndexDropStatement) -> Result<()> {
+        if drop.if_exists {
+            return Err(Error::Unsupported(
+                "Drop index if exists is not available in MySQL.".to_owned(),
+            ));
+        }
+        if drop.concurrently {
+            return Err(Error::Unsupported(
+                "Drop index concurrently is not available in MySQL.".to_owned(),
+            ));
+        }
+        if drop.option.is_some() {
+            return Err(Error::Unsupported(
+                "Drop index option is not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_index_rename_statement(&self, rename: &IndexRenameStatement) -> Result<()> {
+        if rename.if_exists {
+            return Err(Error::Unsupported(
+                "Rename index if exists is not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_index_reindex_statement(&self, _reindex: &IndexReindexStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Reindex is not available in MySQL.".to_owned(),
+        ))
+    }
+
     fn prepare_table_index_expression(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         self.prepare_index_prefix(create, sql);
         write!(sql, "KEY ").unwrap();
@@ -13,6 +88,7 @@ impl IndexBuilder for MysqlQueryBuilder {
     }
 
     fn prepare_index_create_statement(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_index_create_statement(create));
         write!(sql, "CREATE ").unwrap();
         self.prepare_index_prefix(create, sql);
         write!(sql, "INDEX ").unwrap();
@@ -30,16 +106,34 @@ impl IndexBuilder for MysqlQueryBuilder {
     }
 
     fn prepare_index_drop_statement(&self, drop: &IndexDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_index_drop_statement(drop));
         write!(sql, "DROP INDEX ").unwrap();
-        if let Some(name) = &drop.index.name {
-            write!(sql, "`{}`", name).unwrap();
-        }
+        self.prepare_index_name(&drop.index.name, sql);
 
         write!(sql, " ON ").unwrap();
-        if let Some(table) = &drop.table {
-            table.prepare(sql, self.quote());
-        }
+        self.prepare_index_schema_table(&drop.schema, &drop.table, sql);
     }
+
+    fn prepare_index_rename_statement(&self, rename: &IndexRenameStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_index_rename_statement(rename));
+        write!(sql, "ALTER TABLE ").unwrap();
+        self.prepare_index_schema_table(&rename.schema, &rename.table, sql);
+
+        write!(sql, " RENAME INDEX ").unwrap();
+        self.prepare_index_name(&rename.from_name, sql);
+
+        write!(sql, " TO ").unwrap();
+        self.prepare_index_name(&rename.to_name, sql);
+    }
+
+    fn prepare_index_reindex_statement(
+        &self,
+        reindex: &IndexReindexStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_index_reindex_statement(reindex));
+    }
+
     fn prepare_index_type(&self, col_index_type: &Option<IndexType>, sql: &mut SqlWriter) {
         if let Some(index_type) = col_index_type {
             if !matches!(index_type, IndexType::FullText) {
@@ -50,6 +144,9 @@ impl IndexBuilder for MysqlQueryBuilder {
                         IndexType::BTree => "BTREE".to_owned(),
                         IndexType::FullText => unreachable!(),
                         IndexType::Hash => "HASH".to_owned(),
+                        IndexType::Gin | IndexType::Gist | IndexType::Brin | IndexType::SpGist => {
+                            unreachable!("Index type is not available in MySQL.")
+                        }
                         IndexType::Custom(custom) => custom.to_string(),
                     }
                 )
@@ -58,6 +155,22 @@ impl IndexBuilder for MysqlQueryBuilder {
         }
     }
 
+    fn write_column_index_collate(&self, col_collate: &Option<String>, _sql: &mut SqlWriter) {
+        if col_collate.is_some() {
+            unreachable!("Index column collation is not available in MySQL.")
+        }
+    }
+
+    fn write_column_index_nulls(&self, col_nulls: &Option<NullOrdering>, _sql: &mut SqlWriter) {
+        if col_nulls.is_some() {
+            unreachable!("Index column nulls ordering is not available in MySQL.")
+        }
+    }
+
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "{}", self.simple_expr_to_string(expr)).unwrap();
+    }
+
     fn prepare_index_prefix(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         if create.primary {
             write!(sql, "PRIMARY ").unwrap();
@@ -70,3 +183,12 @@ impl IndexBuilder for MysqlQueryBuilder {
         }
     }
 }
+
+fn check_index_type(index_type: &Option<IndexType>) -> Result<()> {
+    match index_type {
+        Some(IndexType::Gin | IndexType::Gist | IndexType::Brin | IndexType::SpGist) => Err(
+            Error::Unsupported("Index type is not available in MySQL.".to_owned()),
+        ),
+        _ => Ok(()),
+    }
+}
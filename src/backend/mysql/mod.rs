@@ -1,7 +1,12 @@
 pub(crate) mod foreign_key;
 pub(crate) mod index;
+pub(crate) mod namespace;
+pub(crate) mod privilege;
 pub(crate) mod query;
 pub(crate) mod table;
+pub(crate) mod transaction;
+pub(crate) mod trigger;
+pub(crate) mod view;
 
 use super::*;
 
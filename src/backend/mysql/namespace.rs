@@ -0,0 +1,120 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl NamespaceBuilder for MysqlQueryBuilder {
+    fn check_schema_create_statement(&self, create: &SchemaCreateStatement) -> Result<()> {
+        if create.authorization.is_some() {
+            return Err(Error::Unsupported(
+                "Schema authorization is not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_schema_drop_statement(&self, drop: &SchemaDropStatement) -> Result<()> {
+        if drop.names.len() > 1 {
+            return Err(Error::Unsupported(
+                "Dropping multiple schemas is not available in MySQL.".to_owned(),
+            ));
+        }
+        if drop.option.is_some() {
+            return Err(Error::Unsupported(
+                "Drop schema option is not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_search_path_statement(&self, _search_path: &SearchPathStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Search path is not available in MySQL.".to_owned(),
+        ))
+    }
+
+    fn check_database_attach_statement(&self, _attach: &DatabaseAttachStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Attach database is not available in MySQL.".to_owned(),
+        ))
+    }
+
+    fn check_database_detach_statement(&self, _detach: &DatabaseDetachStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Detach database is not available in MySQL.".to_owned(),
+        ))
+    }
+
+    fn prepare_schema_create_statement(&self, create: &SchemaCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_schema_create_statement(create));
+        write!(sql, "CREATE SCHEMA ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+
+    fn prepare_schema_drop_statement(&self, drop: &SchemaDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_schema_drop_statement(drop));
+        write!(sql, "DROP SCHEMA ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = drop.names.first() {
+            name.prepare(sql, self.quote());
+        }
+    }
+
+    fn prepare_search_path_statement(
+        &self,
+        search_path: &SearchPathStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_search_path_statement(search_path));
+    }
+
+    fn prepare_database_create_statement(
+        &self,
+        create: &DatabaseCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_database_create_statement(create));
+        write!(sql, "CREATE DATABASE ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(charset) = &create.charset {
+            write!(sql, " CHARACTER SET {}", charset).unwrap();
+        }
+
+        if let Some(collate) = &create.collate {
+            write!(sql, " COLLATE {}", collate).unwrap();
+        }
+    }
+
+    fn prepare_database_attach_statement(
+        &self,
+        attach: &DatabaseAttachStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_database_attach_statement(attach));
+    }
+
+    fn prepare_database_detach_statement(
+        &self,
+        detach: &DatabaseDetachStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_database_detach_statement(detach));
+    }
+}
@@ -0,0 +1,126 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl PrivilegeBuilder for MysqlQueryBuilder {
+    fn check_grant_statement(&self, grant: &GrantStatement) -> Result<()> {
+        check_privileges(&grant.privileges, &grant.object)
+    }
+
+    fn check_revoke_statement(&self, revoke: &RevokeStatement) -> Result<()> {
+        if revoke.grant_option_for {
+            return Err(Error::Unsupported(
+                "Revoke grant option for is not available in MySQL.".to_owned(),
+            ));
+        }
+        if revoke.option.is_some() {
+            return Err(Error::Unsupported(
+                "Revoke option is not available in MySQL.".to_owned(),
+            ));
+        }
+        check_privileges(&revoke.privileges, &revoke.object)
+    }
+
+    fn check_role_create_statement(&self, create: &RoleCreateStatement) -> Result<()> {
+        if !create.options.is_empty() {
+            return Err(Error::Unsupported(
+                "Role options are not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare_revoke_statement(&self, revoke: &RevokeStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_revoke_statement(revoke));
+        write!(sql, "REVOKE ").unwrap();
+        self.prepare_privileges(&revoke.privileges, sql);
+
+        if let Some(object) = &revoke.object {
+            write!(sql, " ON ").unwrap();
+            self.prepare_privilege_object(object, sql);
+        }
+
+        write!(sql, " FROM ").unwrap();
+        self.prepare_privilege_idens(&revoke.grantees, sql);
+    }
+
+    fn prepare_privilege(&self, privilege: &Privilege, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match privilege {
+                Privilege::All => "ALL PRIVILEGES",
+                Privilege::Select => "SELECT",
+                Privilege::Insert => "INSERT",
+                Privilege::Update => "UPDATE",
+                Privilege::Delete => "DELETE",
+                Privilege::References => "REFERENCES",
+                Privilege::Trigger => "TRIGGER",
+                Privilege::Usage => "USAGE",
+                Privilege::Create => "CREATE",
+                Privilege::Temporary => "CREATE TEMPORARY TABLES",
+                Privilege::Execute => "EXECUTE",
+                Privilege::Truncate | Privilege::Connect =>
+                    unreachable!("Privilege {:?} is not available in MySQL.", privilege),
+            }
+        )
+        .unwrap();
+    }
+
+    fn prepare_privilege_object(&self, object: &PrivilegeObject, sql: &mut SqlWriter) {
+        match object {
+            PrivilegeObject::Table(tables) => {
+                if let Some(table) = tables.first() {
+                    TableBuilder::prepare_table_ref(self, table, sql);
+                }
+            }
+            PrivilegeObject::Schema(schemas) | PrivilegeObject::AllTablesInSchema(schemas) => {
+                if let Some(schema) = schemas.first() {
+                    schema.prepare(sql, self.quote());
+                    write!(sql, ".*").unwrap();
+                }
+            }
+            PrivilegeObject::Sequence(_) => unreachable!("Sequence is not available in MySQL."),
+        }
+    }
+
+    fn prepare_role_create_statement(&self, create: &RoleCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_role_create_statement(create));
+        write!(sql, "CREATE ROLE ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+}
+
+/// Check the privileges and the object of a grant or revoke
+fn check_privileges(
+    privileges: &[(Privilege, Vec<DynIden>)],
+    object: &Option<PrivilegeObject>,
+) -> Result<()> {
+    if let Some((privilege, _)) = privileges
+        .iter()
+        .find(|(privilege, _)| matches!(privilege, Privilege::Truncate | Privilege::Connect))
+    {
+        return Err(Error::Unsupported(format!(
+            "Privilege {:?} is not available in MySQL.",
+            privilege
+        )));
+    }
+    let message =
+        match object {
+            Some(PrivilegeObject::Table(tables)) if tables.len() > 1 => {
+                "Granting on multiple tables is not available in MySQL."
+            }
+            Some(
+                PrivilegeObject::Schema(schemas) | PrivilegeObject::AllTablesInSchema(schemas),
+            ) if schemas.len() > 1 => "Granting on multiple schemas is not available in MySQL.",
+            Some(PrivilegeObject::Sequence(_)) => "Sequence is not available in MySQL.",
+            _ => return Ok(()),
+        };
+    Err(Error::Unsupported(message.to_owned()))
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::error::{Error, Result};
 
 impl QueryBuilder for MysqlQueryBuilder {
     fn prepare_returning(
@@ -30,4 +31,55 @@ impl QueryBuilder for MysqlQueryBuilder {
         write!(sql, " ").unwrap();
         self.prepare_order(&order_expr.order, sql, collector);
     }
+
+    fn check_explain_statement(&self, explain: &ExplainStatement) -> Result<()> {
+        if explain.verbose {
+            return Err(Error::Unsupported(
+                "Explain verbose is not available in MySQL.".to_owned(),
+            ));
+        }
+        if explain.buffers {
+            return Err(Error::Unsupported(
+                "Explain buffers is not available in MySQL.".to_owned(),
+            ));
+        }
+        // EXPLAIN ANALYZE only outputs FORMAT=TREE
+        if explain.analyze && explain.format.is_some() {
+            return Err(Error::Unsupported(
+                "Explain analyze with a format is not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare_explain_statement(
+        &self,
+        explain: &ExplainStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        check_supported(self.check_explain_statement(explain));
+        write!(sql, "EXPLAIN").unwrap();
+
+        if explain.analyze {
+            write!(sql, " ANALYZE").unwrap();
+        }
+
+        if let Some(format) = &explain.format {
+            write!(
+                sql,
+                " FORMAT={}",
+                match format {
+                    ExplainFormat::Text => "TRADITIONAL",
+                    ExplainFormat::Json => "JSON",
+                }
+            )
+            .unwrap();
+        }
+
+        if let Some(query) = &explain.query {
+            write!(sql, " ").unwrap();
+            self.prepare_query_statement(query, sql, collector);
+        }
+    }
 }
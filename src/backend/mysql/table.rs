@@ -1,6 +1,33 @@
 use super::*;
+use crate::error::{Error, Result};
 
 impl TableBuilder for MysqlQueryBuilder {
+    fn check_column_def(&self, column_def: &ColumnDef) -> Result<()> {
+        let identity = column_def
+            .spec
+            .iter()
+            .find_map(|column_spec| match column_spec {
+                ColumnSpec::Identity(identity) => Some(identity),
+                _ => None,
+            });
+        match identity {
+            // AUTO_INCREMENT accepts explicit values, as `GENERATED BY DEFAULT` does
+            Some(ColumnIdentity {
+                generation: IdentityGeneration::Always,
+                ..
+            }) => Err(Error::Unsupported(
+                "Identity generated always is not available in MySQL.".to_owned(),
+            )),
+            Some(ColumnIdentity {
+                increment: Some(increment),
+                ..
+            }) if *increment != 1 => Err(Error::Unsupported(
+                "Identity increment is not available in MySQL.".to_owned(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
     fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut SqlWriter) {
         column_def.name.prepare(sql, self.quote());
 
@@ -99,16 +126,105 @@ impl TableBuilder for MysqlQueryBuilder {
         match column_spec {
             ColumnSpec::Null => write!(sql, "NULL"),
             ColumnSpec::NotNull => write!(sql, "NOT NULL"),
-            ColumnSpec::Default(value) => write!(sql, "DEFAULT {}", self.value_to_string(value)),
+            ColumnSpec::Default(value) => {
+                write!(sql, "DEFAULT {}", self.column_expr_to_string(value))
+            }
             ColumnSpec::AutoIncrement => write!(sql, "AUTO_INCREMENT"),
             ColumnSpec::UniqueKey => write!(sql, "UNIQUE"),
             ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
+            ColumnSpec::Identity(_) => write!(sql, "AUTO_INCREMENT"),
+            ColumnSpec::Comment(comment) => write!(
+                sql,
+                "COMMENT {}",
+                self.value_to_string(&comment.as_str().into())
+            ),
+            ColumnSpec::OnUpdate(value) => {
+                // Unlike DEFAULT, ON UPDATE does not accept an expression in parentheses
+                write!(sql, "ON UPDATE {}", self.simple_expr_to_string(value))
+            }
             ColumnSpec::Extra(string) => write!(sql, "{}", string),
         }
         .unwrap()
     }
 
+    fn prepare_table_identity_opt(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        let start = create.columns.iter().find_map(|column_def| {
+            column_def
+                .spec
+                .iter()
+                .find_map(|column_spec| match column_spec {
+                    ColumnSpec::Identity(identity) => identity.start,
+                    _ => None,
+                })
+        });
+        if let Some(start) = start {
+            write!(sql, " AUTO_INCREMENT={}", start).unwrap();
+        }
+    }
+
+    fn prepare_table_comment(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        if let Some(comment) = &create.comment {
+            write!(
+                sql,
+                " COMMENT={}",
+                self.value_to_string(&comment.as_str().into())
+            )
+            .unwrap();
+        }
+    }
+
+    fn check_table_partitions(&self, partitions: &[TablePartition]) -> Result<()> {
+        let mut partition_by = 0;
+        for partition in partitions.iter() {
+            match partition {
+                TablePartition::PartitionBy(method, keys) => {
+                    partition_by += 1;
+                    if keys.len() > 1
+                        && (matches!(method, PartitionMethod::Hash) || !by_columns(keys))
+                    {
+                        return Err(Error::Unsupported(
+                            "Partitioning by several keys other than columns of RANGE or LIST is not available in MySQL."
+                                .to_owned(),
+                        ));
+                    }
+                }
+                TablePartition::Partition(_, bound) => {
+                    if let PartitionBound::FromTo(_, _)
+                    | PartitionBound::Modulus(_, _)
+                    | PartitionBound::Default = bound
+                    {
+                        return Err(Error::Unsupported(
+                            "Partition bound is not available in MySQL.".to_owned(),
+                        ));
+                    }
+                }
+                TablePartition::Partitions(_) => {}
+                TablePartition::PartitionOf(_, _) => {
+                    return Err(Error::Unsupported(
+                        "Partition of is not available in MySQL.".to_owned(),
+                    ))
+                }
+            }
+        }
+        match partition_by {
+            0 if !partitions.is_empty() => Err(Error::Unsupported(
+                "Partitions without partition by are not available in MySQL.".to_owned(),
+            )),
+            0 | 1 => Ok(()),
+            _ => Err(Error::Unsupported(
+                "Partitioning a table more than once is not available in MySQL.".to_owned(),
+            )),
+        }
+    }
+
+    fn prepare_table_create_statement(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_table_create_statement(create));
+        self.prepare_table_create_statement_common(create, sql);
+        self.prepare_table_partitions(&create.partitions, sql);
+    }
+
     fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_table_alter_statement(alter));
         let alter_option = match &alter.alter_option {
             Some(alter_option) => alter_option,
             None => panic!("No alter option found"),
@@ -137,6 +253,18 @@ impl TableBuilder for MysqlQueryBuilder {
                 write!(sql, "DROP COLUMN ").unwrap();
                 column_name.prepare(sql, self.quote());
             }
+            TableAlterOption::DropColumnDefault(column_name) => {
+                write!(sql, "ALTER COLUMN ").unwrap();
+                column_name.prepare(sql, self.quote());
+                write!(sql, " DROP DEFAULT").unwrap();
+            }
+            TableAlterOption::AddPrimaryKey(index) => {
+                write!(sql, "ADD ").unwrap();
+                self.prepare_table_index_expression(index, sql);
+            }
+            TableAlterOption::DropPrimaryKey(_) => {
+                write!(sql, "DROP PRIMARY KEY").unwrap();
+            }
         }
     }
 
@@ -151,3 +279,69 @@ impl TableBuilder for MysqlQueryBuilder {
         }
     }
 }
+
+impl MysqlQueryBuilder {
+    /// Translate the partitioning of a table, the method and count followed by the partition definitions
+    fn prepare_table_partitions(&self, partitions: &[TablePartition], sql: &mut SqlWriter) {
+        // Partitioning by plain columns takes any column type, instead of an integer expression
+        let mut columns = None;
+        for partition in partitions.iter() {
+            if let TablePartition::PartitionBy(method, keys) = partition {
+                if !matches!(method, PartitionMethod::Hash) && by_columns(keys) {
+                    columns = Some(keys.len());
+                }
+                write!(
+                    sql,
+                    " PARTITION BY {}{} ({})",
+                    match method {
+                        PartitionMethod::Range => "RANGE",
+                        PartitionMethod::List => "LIST",
+                        PartitionMethod::Hash => "HASH",
+                    },
+                    if columns.is_some() { " COLUMNS" } else { "" },
+                    self.exprs_to_string(keys)
+                )
+                .unwrap();
+            }
+        }
+        for partition in partitions.iter() {
+            if let TablePartition::Partitions(num) = partition {
+                write!(sql, " PARTITIONS {}", num).unwrap();
+            }
+        }
+        let mut count = 0;
+        for partition in partitions.iter() {
+            if let TablePartition::Partition(name, bound) = partition {
+                write!(sql, "{}PARTITION ", if count == 0 { " ( " } else { ", " }).unwrap();
+                name.prepare(sql, self.quote());
+                match bound {
+                    PartitionBound::LessThan(values) => {
+                        write!(sql, " VALUES LESS THAN ({})", self.exprs_to_string(values))
+                    }
+                    PartitionBound::LessThanMaxValue => match columns {
+                        Some(columns) => write!(
+                            sql,
+                            " VALUES LESS THAN ({})",
+                            vec!["MAXVALUE"; columns].join(", ")
+                        ),
+                        None => write!(sql, " VALUES LESS THAN MAXVALUE"),
+                    },
+                    PartitionBound::In(values) => {
+                        write!(sql, " VALUES IN ({})", self.exprs_to_string(values))
+                    }
+                    _ => unreachable!("Partition bound is not available in MySQL."),
+                }
+                .unwrap();
+                count += 1;
+            }
+        }
+        if count > 0 {
+            write!(sql, " )").unwrap();
+        }
+    }
+}
+
+/// Whether the partition keys are all plain columns
+fn by_columns(keys: &[SimpleExpr]) -> bool {
+    keys.iter().all(|key| matches!(key, SimpleExpr::Column(_)))
+}
@@ -0,0 +1,99 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl TransactionBuilder for MysqlQueryBuilder {
+    /// MySQL commits implicitly before and after most schema statements.
+    fn transactional_ddl(&self) -> bool {
+        false
+    }
+
+    fn check_transaction_begin_statement(&self, begin: &TransactionBeginStatement) -> Result<()> {
+        if begin.isolation_level.is_some() {
+            return Err(Error::Unsupported(
+                "Isolation level of begin is not available in MySQL, set transaction beforehand."
+                    .to_owned(),
+            ));
+        }
+        if begin.behavior.is_some() {
+            return Err(Error::Unsupported(
+                "Transaction behavior is not available in MySQL.".to_owned(),
+            ));
+        }
+        check_deferrable(&begin.deferrable)
+    }
+
+    fn check_transaction_set_statement(&self, set: &TransactionSetStatement) -> Result<()> {
+        check_deferrable(&set.deferrable)
+    }
+
+    fn check_lock_table_statement(&self, lock: &LockTableStatement) -> Result<()> {
+        if lock.nowait {
+            return Err(Error::Unsupported(
+                "Lock table nowait is not available in MySQL.".to_owned(),
+            ));
+        }
+        for (_, mode) in lock.tables.iter() {
+            match mode.as_ref().or(lock.mode.as_ref()) {
+                Some(LockMode::Read | LockMode::ReadLocal | LockMode::Write) => {}
+                Some(mode) => {
+                    return Err(Error::Unsupported(format!(
+                        "Lock mode {:?} is not available in MySQL.",
+                        mode
+                    )))
+                }
+                None => {
+                    return Err(Error::Unsupported(
+                        "Lock mode is required in MySQL.".to_owned(),
+                    ))
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn prepare_transaction_begin_statement(
+        &self,
+        begin: &TransactionBeginStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_transaction_begin_statement(begin));
+        write!(sql, "START TRANSACTION").unwrap();
+        self.prepare_transaction_modes(&None, &begin.access_mode, &begin.deferrable, sql);
+    }
+
+    fn prepare_lock_table_statement(&self, lock: &LockTableStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_lock_table_statement(lock));
+        write!(sql, "LOCK TABLES ").unwrap();
+
+        lock.tables.iter().fold(true, |first, (table, mode)| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            TableBuilder::prepare_table_ref(self, table, sql);
+            write!(sql, " ").unwrap();
+            match mode.as_ref().or(lock.mode.as_ref()) {
+                Some(mode) => self.prepare_lock_mode(mode, sql),
+                None => unreachable!("Lock mode is required in MySQL."),
+            }
+            false
+        });
+    }
+
+    fn prepare_lock_mode(&self, mode: &LockMode, sql: &mut SqlWriter) {
+        match mode {
+            LockMode::Read | LockMode::ReadLocal | LockMode::Write => {
+                self.prepare_lock_mode_common(mode, sql)
+            }
+            _ => unreachable!("Lock mode {:?} is not available in MySQL.", mode),
+        }
+    }
+}
+
+fn check_deferrable(deferrable: &Option<bool>) -> Result<()> {
+    if deferrable.is_some() {
+        return Err(Error::Unsupported(
+            "Deferrable transaction is not available in MySQL.".to_owned(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl TriggerBuilder for MysqlQueryBuilder {
+    fn check_trigger_create_statement(&self, create: &TriggerCreateStatement) -> Result<()> {
+        if create.events.len() > 1 {
+            return Err(Error::Unsupported(
+                "Multiple trigger events are not available in MySQL.".to_owned(),
+            ));
+        }
+        match create.events.first() {
+            Some(TriggerEvent::Update(columns)) if !columns.is_empty() => {
+                return Err(Error::Unsupported(
+                    "UPDATE OF columns is not available in MySQL.".to_owned(),
+                ));
+            }
+            Some(TriggerEvent::Truncate) => {
+                return Err(Error::Unsupported(
+                    "TRUNCATE trigger is not available in MySQL.".to_owned(),
+                ));
+            }
+            _ => {}
+        }
+        if !create.for_each_row {
+            return Err(Error::Unsupported(
+                "Trigger without FOR EACH ROW is not available in MySQL.".to_owned(),
+            ));
+        }
+        if create.timing == Some(TriggerTiming::InsteadOf) {
+            return Err(Error::Unsupported(
+                "INSTEAD OF trigger is not available in MySQL.".to_owned(),
+            ));
+        }
+        if create.when.is_some() {
+            return Err(Error::Unsupported(
+                "Trigger WHEN condition is not available in MySQL.".to_owned(),
+            ));
+        }
+        if let Some(TriggerAction::ExecuteFunction(_)) = create.action {
+            return Err(Error::Unsupported(
+                "EXECUTE FUNCTION is not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_trigger_drop_statement(&self, drop: &TriggerDropStatement) -> Result<()> {
+        if drop.table.is_some() {
+            return Err(Error::Unsupported(
+                "Drop trigger ON table is not available in MySQL.".to_owned(),
+            ));
+        }
+        if drop.option.is_some() {
+            return Err(Error::Unsupported(
+                "Drop trigger options are not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare_trigger_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "{}", self.simple_expr_to_string(expr)).unwrap();
+    }
+
+    fn prepare_trigger_statement(&self, statement: &QueryStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", self.query_statement_to_string(statement)).unwrap();
+    }
+}
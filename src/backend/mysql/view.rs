@@ -0,0 +1,57 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl ViewBuilder for MysqlQueryBuilder {
+    fn check_view_create_statement(&self, create: &ViewCreateStatement) -> Result<()> {
+        if create.materialized {
+            return Err(Error::Unsupported(
+                "Materialized view is not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_view_drop_statement(&self, drop: &ViewDropStatement) -> Result<()> {
+        if drop.materialized {
+            return Err(Error::Unsupported(
+                "Materialized view is not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_view_rename_statement(&self, rename: &ViewRenameStatement) -> Result<()> {
+        if rename.materialized {
+            return Err(Error::Unsupported(
+                "Materialized view is not available in MySQL.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_view_refresh_statement(&self, _refresh: &ViewRefreshStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Materialized view is not available in MySQL.".to_owned(),
+        ))
+    }
+
+    fn prepare_view_rename_statement(&self, rename: &ViewRenameStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_view_rename_statement(rename));
+        write!(sql, "RENAME TABLE ").unwrap();
+        if let Some(from_name) = &rename.from_name {
+            TableBuilder::prepare_table_ref(self, from_name, sql);
+        }
+        write!(sql, " TO ").unwrap();
+        if let Some(to_name) = &rename.to_name {
+            to_name.prepare(sql, self.quote());
+        }
+    }
+
+    fn prepare_view_refresh_statement(&self, refresh: &ViewRefreshStatement, _sql: &mut SqlWriter) {
+        check_supported(self.check_view_refresh_statement(refresh));
+    }
+
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(MysqlQueryBuilder)).unwrap();
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{error::Result, *};
+
+pub trait NamespaceBuilder: QuotedBuilder {
+    /// Check that the backend supports [`SchemaCreateStatement`].
+    fn check_schema_create_statement(&self, _create: &SchemaCreateStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`SchemaDropStatement`].
+    fn check_schema_drop_statement(&self, _drop: &SchemaDropStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`SearchPathStatement`].
+    fn check_search_path_statement(&self, _search_path: &SearchPathStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`DatabaseCreateStatement`].
+    fn check_database_create_statement(&self, _create: &DatabaseCreateStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`DatabaseDropStatement`].
+    fn check_database_drop_statement(&self, _drop: &DatabaseDropStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`DatabaseAttachStatement`].
+    fn check_database_attach_statement(&self, _attach: &DatabaseAttachStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`DatabaseDetachStatement`].
+    fn check_database_detach_statement(&self, _detach: &DatabaseDetachStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Translate [`SchemaCreateStatement`] into SQL statement.
+    fn prepare_schema_create_statement(&self, create: &SchemaCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_schema_create_statement(create));
+        write!(sql, "CREATE SCHEMA ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(role) = &create.authorization {
+            write!(sql, " AUTHORIZATION ").unwrap();
+            role.prepare(sql, self.quote());
+        }
+    }
+
+    /// Translate [`SchemaDropStatement`] into SQL statement.
+    fn prepare_schema_drop_statement(&self, drop: &SchemaDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_schema_drop_statement(drop));
+        write!(sql, "DROP SCHEMA ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        drop.names.iter().fold(true, |first, name| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            name.prepare(sql, self.quote());
+            false
+        });
+
+        if let Some(option) = &drop.option {
+            write!(
+                sql,
+                " {}",
+                match option {
+                    SchemaDropOpt::Cascade => "CASCADE",
+                    SchemaDropOpt::Restrict => "RESTRICT",
+                }
+            )
+            .unwrap();
+        }
+    }
+
+    /// Translate [`SearchPathStatement`] into SQL statement.
+    fn prepare_search_path_statement(&self, search_path: &SearchPathStatement, sql: &mut SqlWriter);
+
+    /// Translate [`DatabaseCreateStatement`] into SQL statement.
+    fn prepare_database_create_statement(
+        &self,
+        create: &DatabaseCreateStatement,
+        sql: &mut SqlWriter,
+    );
+
+    /// Translate [`DatabaseDropStatement`] into SQL statement.
+    fn prepare_database_drop_statement(&self, drop: &DatabaseDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_database_drop_statement(drop));
+        write!(sql, "DROP DATABASE ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &drop.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+
+    /// Translate [`DatabaseAttachStatement`] into SQL statement.
+    fn prepare_database_attach_statement(
+        &self,
+        attach: &DatabaseAttachStatement,
+        sql: &mut SqlWriter,
+    );
+
+    /// Translate [`DatabaseDetachStatement`] into SQL statement.
+    fn prepare_database_detach_statement(
+        &self,
+        detach: &DatabaseDetachStatement,
+        sql: &mut SqlWriter,
+    );
+}
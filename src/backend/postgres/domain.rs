@@ -0,0 +1,69 @@
+use super::*;
+use crate::extension::postgres::*;
+
+impl DomainBuilder for PostgresQueryBuilder {
+    fn prepare_domain_create_statement(
+        &self,
+        create: &DomainCreateStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "CREATE DOMAIN ").unwrap();
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(as_type) = &create.as_type {
+            write!(sql, " AS ").unwrap();
+            self.prepare_column_type(as_type, sql);
+        }
+
+        if let Some(default) = &create.default {
+            write!(sql, " DEFAULT ").unwrap();
+            self.prepare_simple_expr(default, sql, collector);
+        }
+
+        for constraint in create.constraints.iter() {
+            match constraint {
+                DomainConstraint::NotNull => write!(sql, " NOT NULL").unwrap(),
+                DomainConstraint::Null => write!(sql, " NULL").unwrap(),
+                DomainConstraint::Check(name, condition) => {
+                    if let Some(name) = name {
+                        write!(sql, " CONSTRAINT ").unwrap();
+                        name.prepare(sql, self.quote());
+                    }
+                    write!(sql, " CHECK (").unwrap();
+                    self.prepare_simple_expr(condition, sql, collector);
+                    write!(sql, ")").unwrap();
+                }
+            }
+        }
+    }
+
+    fn prepare_domain_drop_statement(
+        &self,
+        drop: &DomainDropStatement,
+        sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "DROP DOMAIN ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        drop.names.iter().fold(true, |first, name| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            name.prepare(sql, self.quote());
+            false
+        });
+
+        if let Some(option) = &drop.option {
+            write!(sql, " ").unwrap();
+            self.prepare_drop_type_opt(option, sql);
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use super::*;
+use crate::extension::postgres::*;
+
+impl ExtensionBuilder for PostgresQueryBuilder {
+    fn prepare_extension_create_statement(
+        &self,
+        create: &ExtensionCreateStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "CREATE EXTENSION ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            Alias::new(name).prepare(sql, self.quote());
+        }
+
+        if let Some(schema) = &create.schema {
+            write!(sql, " SCHEMA ").unwrap();
+            schema.prepare(sql, self.quote());
+        }
+
+        if let Some(version) = &create.version {
+            write!(sql, " VERSION ").unwrap();
+            self.prepare_value(&version.as_str().into(), sql, collector);
+        }
+
+        if create.cascade {
+            write!(sql, " CASCADE").unwrap();
+        }
+    }
+
+    fn prepare_extension_drop_statement(
+        &self,
+        drop: &ExtensionDropStatement,
+        sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "DROP EXTENSION ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        drop.names.iter().fold(true, |first, name| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            Alias::new(name).prepare(sql, self.quote());
+            false
+        });
+
+        if let Some(option) = &drop.option {
+            write!(
+                sql,
+                " {}",
+                match option {
+                    ExtensionDropOpt::Cascade => "CASCADE",
+                    ExtensionDropOpt::Restrict => "RESTRICT",
+                }
+            )
+            .unwrap();
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use super::*;
+use crate::extension::postgres::*;
+
+impl FunctionBuilder for PostgresQueryBuilder {
+    fn prepare_function_create_statement(
+        &self,
+        create: &FunctionCreateStatement,
+        sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "CREATE ").unwrap();
+
+        if create.or_replace {
+            write!(sql, "OR REPLACE ").unwrap();
+        }
+
+        write!(sql, "FUNCTION ").unwrap();
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        write!(sql, "(").unwrap();
+        create.args.iter().fold(true, |first, (name, data_type)| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            name.prepare(sql, self.quote());
+            write!(sql, " {}", data_type).unwrap();
+            false
+        });
+        write!(sql, ")").unwrap();
+
+        if let Some(returns) = &create.returns {
+            write!(sql, " RETURNS {}", returns).unwrap();
+        }
+
+        if let Some(language) = &create.language {
+            write!(sql, " LANGUAGE {}", language).unwrap();
+        }
+
+        if let Some(body) = &create.body {
+            let tag = dollar_quote_tag(body);
+            write!(sql, " AS {}{}{}", tag, body, tag).unwrap();
+        }
+    }
+}
+
+/// A dollar-quote tag whose first occurrence after the body is the closing one
+fn dollar_quote_tag(body: &str) -> String {
+    (0..)
+        .map(|i| match i {
+            0 => "$$".to_owned(),
+            1 => "$body$".to_owned(),
+            i => format!("$body{}$", i - 1),
+        })
+        .find(|tag| format!("{}{}", body, tag).find(tag.as_str()) == Some(body.len()))
+        .unwrap()
+}
@@ -1,6 +1,16 @@
 use super::*;
+use crate::error::{Error, Result};
 
 impl IndexBuilder for PostgresQueryBuilder {
+    fn check_table_index_expression(&self, create: &IndexCreateStatement) -> Result<()> {
+        if create.r#where.is_some() {
+            return Err(Error::Unsupported(
+                "Partial index is not available in a table definition.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
     fn prepare_table_index_expression(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         if create.index.name.is_some() {
             write!(sql, "CONSTRAINT ").unwrap();
@@ -11,13 +21,24 @@ impl IndexBuilder for PostgresQueryBuilder {
         self.prepare_index_prefix(create, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include_columns, sql);
     }
 
     fn prepare_index_create_statement(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_index_create_statement(create));
         write!(sql, "CREATE ").unwrap();
         self.prepare_index_prefix(create, sql);
         write!(sql, "INDEX ").unwrap();
 
+        if create.concurrently {
+            write!(sql, "CONCURRENTLY ").unwrap();
+        }
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
         self.prepare_index_name(&create.index.name, sql);
 
         write!(sql, " ON ").unwrap();
@@ -28,12 +49,64 @@ impl IndexBuilder for PostgresQueryBuilder {
         self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_include(&create.include_columns, sql);
+
+        self.prepare_index_where(&create.r#where, sql);
     }
 
     fn prepare_index_drop_statement(&self, drop: &IndexDropStatement, sql: &mut SqlWriter) {
         write!(sql, "DROP INDEX ").unwrap();
-        if let Some(name) = &drop.index.name {
-            write!(sql, "\"{}\"", name).unwrap();
+
+        if drop.concurrently {
+            write!(sql, "CONCURRENTLY ").unwrap();
+        }
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        self.prepare_index_schema_name(&drop.schema, &drop.index.name, sql);
+
+        if let Some(option) = &drop.option {
+            write!(sql, " ").unwrap();
+            self.prepare_index_drop_opt(option, sql);
+        }
+    }
+
+    fn prepare_index_rename_statement(&self, rename: &IndexRenameStatement, sql: &mut SqlWriter) {
+        write!(sql, "ALTER INDEX ").unwrap();
+
+        if rename.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        self.prepare_index_schema_name(&rename.schema, &rename.from_name, sql);
+
+        write!(sql, " RENAME TO ").unwrap();
+        self.prepare_index_name(&rename.to_name, sql);
+    }
+
+    fn prepare_index_reindex_statement(
+        &self,
+        reindex: &IndexReindexStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "REINDEX ").unwrap();
+        if reindex.name.is_some() {
+            write!(sql, "INDEX ").unwrap();
+        } else {
+            write!(sql, "TABLE ").unwrap();
+        }
+
+        if reindex.concurrently {
+            write!(sql, "CONCURRENTLY ").unwrap();
+        }
+
+        if reindex.name.is_some() {
+            self.prepare_index_schema_name(&reindex.schema, &reindex.name, sql);
+        } else {
+            self.prepare_index_schema_table(&reindex.schema, &reindex.table, sql);
         }
     }
 
@@ -46,6 +119,10 @@ impl IndexBuilder for PostgresQueryBuilder {
                     IndexType::BTree => "BTREE".to_owned(),
                     IndexType::FullText => "GIN".to_owned(),
                     IndexType::Hash => "HASH".to_owned(),
+                    IndexType::Gin => "GIN".to_owned(),
+                    IndexType::Gist => "GIST".to_owned(),
+                    IndexType::Brin => "BRIN".to_owned(),
+                    IndexType::SpGist => "SPGIST".to_owned(),
                     IndexType::Custom(custom) => custom.to_string(),
                 }
             )
@@ -53,6 +130,10 @@ impl IndexBuilder for PostgresQueryBuilder {
         }
     }
 
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "{}", self.simple_expr_to_string(expr)).unwrap();
+    }
+
     fn prepare_index_prefix(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         if create.primary {
             write!(sql, "PRIMARY KEY ").unwrap();
@@ -1,8 +1,17 @@
+pub(crate) mod domain;
+pub(crate) mod extension;
 pub(crate) mod foreign_key;
+pub(crate) mod function;
 pub(crate) mod index;
+pub(crate) mod namespace;
+pub(crate) mod privilege;
 pub(crate) mod query;
+pub(crate) mod sequence;
 pub(crate) mod table;
+pub(crate) mod transaction;
+pub(crate) mod trigger;
 pub(crate) mod types;
+pub(crate) mod view;
 
 use super::*;
 
@@ -0,0 +1,95 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl NamespaceBuilder for PostgresQueryBuilder {
+    fn check_database_create_statement(&self, create: &DatabaseCreateStatement) -> Result<()> {
+        if create.if_not_exists {
+            return Err(Error::Unsupported(
+                "CREATE DATABASE IF NOT EXISTS is not available in Postgres.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_database_attach_statement(&self, _attach: &DatabaseAttachStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Attach database is not available in Postgres.".to_owned(),
+        ))
+    }
+
+    fn check_database_detach_statement(&self, _detach: &DatabaseDetachStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Detach database is not available in Postgres.".to_owned(),
+        ))
+    }
+
+    fn prepare_search_path_statement(
+        &self,
+        search_path: &SearchPathStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_search_path_statement(search_path));
+        write!(sql, "SET ").unwrap();
+
+        if search_path.local {
+            write!(sql, "LOCAL ").unwrap();
+        }
+
+        write!(sql, "search_path TO ").unwrap();
+
+        search_path.schemas.iter().fold(true, |first, schema| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            schema.prepare(sql, self.quote());
+            false
+        });
+    }
+
+    fn prepare_database_create_statement(
+        &self,
+        create: &DatabaseCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_database_create_statement(create));
+        write!(sql, "CREATE DATABASE ").unwrap();
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(charset) = &create.charset {
+            write!(
+                sql,
+                " ENCODING {}",
+                self.value_to_string(&charset.as_str().into())
+            )
+            .unwrap();
+        }
+
+        if let Some(collate) = &create.collate {
+            write!(
+                sql,
+                " LC_COLLATE {}",
+                self.value_to_string(&collate.as_str().into())
+            )
+            .unwrap();
+        }
+    }
+
+    fn prepare_database_attach_statement(
+        &self,
+        attach: &DatabaseAttachStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_database_attach_statement(attach));
+    }
+
+    fn prepare_database_detach_statement(
+        &self,
+        detach: &DatabaseDetachStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_database_detach_statement(detach));
+    }
+}
@@ -0,0 +1,47 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl PrivilegeBuilder for PostgresQueryBuilder {
+    fn check_role_create_statement(&self, create: &RoleCreateStatement) -> Result<()> {
+        if create.if_not_exists {
+            return Err(Error::Unsupported(
+                "Create role if not exists is not available in Postgres.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare_role_create_statement(&self, create: &RoleCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_role_create_statement(create));
+        write!(sql, "CREATE ROLE ").unwrap();
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if !create.options.is_empty() {
+            write!(sql, " WITH").unwrap();
+        }
+
+        for option in create.options.iter() {
+            match option {
+                RoleOption::Login(b) => write!(sql, " {}LOGIN", if *b { "" } else { "NO" }),
+                RoleOption::Superuser(b) => {
+                    write!(sql, " {}SUPERUSER", if *b { "" } else { "NO" })
+                }
+                RoleOption::CreateDb(b) => write!(sql, " {}CREATEDB", if *b { "" } else { "NO" }),
+                RoleOption::CreateRole(b) => {
+                    write!(sql, " {}CREATEROLE", if *b { "" } else { "NO" })
+                }
+                RoleOption::Inherit(b) => write!(sql, " {}INHERIT", if *b { "" } else { "NO" }),
+                RoleOption::Password(password) => write!(
+                    sql,
+                    " PASSWORD {}",
+                    self.value_to_string(&password.as_str().into())
+                ),
+                RoleOption::ConnectionLimit(limit) => write!(sql, " CONNECTION LIMIT {}", limit),
+            }
+            .unwrap();
+        }
+    }
+}
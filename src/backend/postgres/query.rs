@@ -20,6 +20,16 @@ impl QueryBuilder for PostgresQueryBuilder {
         write!(buffer, "{}", string).unwrap()
     }
 
+    /// Only operator expressions are enclosed in parentheses, to keep them apart from the constraints that follow
+    fn column_expr_to_string(&self, simple_expr: &SimpleExpr) -> String {
+        match simple_expr {
+            SimpleExpr::Unary(_, _) | SimpleExpr::Binary(_, _, _) => {
+                format!("({})", self.simple_expr_to_string(simple_expr))
+            }
+            _ => self.simple_expr_to_string(simple_expr),
+        }
+    }
+
     fn prepare_bin_oper(
         &self,
         bin_oper: &BinOper,
@@ -53,6 +63,9 @@ impl QueryBuilder for PostgresQueryBuilder {
                     PgFunction::WebsearchToTsquery => "WEBSEARCH_TO_TSQUERY",
                     PgFunction::TsRank => "TS_RANK",
                     PgFunction::TsRankCd => "TS_RANK_CD",
+                    PgFunction::Nextval => "NEXTVAL",
+                    PgFunction::Currval => "CURRVAL",
+                    PgFunction::Setval => "SETVAL",
                 }
             )
             .unwrap(),
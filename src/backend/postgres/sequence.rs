@@ -0,0 +1,110 @@
+use super::*;
+use crate::extension::postgres::*;
+
+impl SequenceBuilder for PostgresQueryBuilder {
+    fn prepare_sequence_create_statement(
+        &self,
+        create: &SequenceCreateStatement,
+        sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "CREATE SEQUENCE ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        for option in create.options.iter() {
+            self.prepare_sequence_opt(option, sql);
+        }
+    }
+
+    fn prepare_sequence_alter_statement(
+        &self,
+        alter: &SequenceAlterStatement,
+        sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "ALTER SEQUENCE ").unwrap();
+
+        if alter.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &alter.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(new_name) = &alter.rename_to {
+            write!(sql, " RENAME TO ").unwrap();
+            new_name.prepare(sql, self.quote());
+            return;
+        }
+
+        for option in alter.options.iter() {
+            self.prepare_sequence_opt(option, sql);
+        }
+    }
+
+    fn prepare_sequence_drop_statement(
+        &self,
+        drop: &SequenceDropStatement,
+        sql: &mut SqlWriter,
+        _collector: &mut dyn FnMut(Value),
+    ) {
+        write!(sql, "DROP SEQUENCE ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        drop.names.iter().fold(true, |first, name| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            name.prepare(sql, self.quote());
+            false
+        });
+
+        if let Some(option) = &drop.option {
+            write!(
+                sql,
+                " {}",
+                match option {
+                    SequenceDropOpt::Cascade => "CASCADE",
+                    SequenceDropOpt::Restrict => "RESTRICT",
+                }
+            )
+            .unwrap();
+        }
+    }
+}
+
+impl PostgresQueryBuilder {
+    fn prepare_sequence_opt(&self, opt: &SequenceOption, sql: &mut SqlWriter) {
+        match opt {
+            SequenceOption::IncrementBy(value) => write!(sql, " INCREMENT BY {}", value).unwrap(),
+            SequenceOption::MinValue(Some(value)) => write!(sql, " MINVALUE {}", value).unwrap(),
+            SequenceOption::MinValue(None) => write!(sql, " NO MINVALUE").unwrap(),
+            SequenceOption::MaxValue(Some(value)) => write!(sql, " MAXVALUE {}", value).unwrap(),
+            SequenceOption::MaxValue(None) => write!(sql, " NO MAXVALUE").unwrap(),
+            SequenceOption::StartWith(value) => write!(sql, " START WITH {}", value).unwrap(),
+            SequenceOption::Restart(Some(value)) => write!(sql, " RESTART WITH {}", value).unwrap(),
+            SequenceOption::Restart(None) => write!(sql, " RESTART").unwrap(),
+            SequenceOption::Cache(value) => write!(sql, " CACHE {}", value).unwrap(),
+            SequenceOption::Cycle(true) => write!(sql, " CYCLE").unwrap(),
+            SequenceOption::Cycle(false) => write!(sql, " NO CYCLE").unwrap(),
+            SequenceOption::OwnedBy(Some((table, column))) => {
+                write!(sql, " OWNED BY ").unwrap();
+                table.prepare(sql, self.quote());
+                write!(sql, ".").unwrap();
+                column.prepare(sql, self.quote());
+            }
+            SequenceOption::OwnedBy(None) => write!(sql, " OWNED BY NONE").unwrap(),
+        }
+    }
+}
@@ -1,13 +1,29 @@
 use super::*;
+use crate::error::{Error, Result};
 
 impl TableBuilder for PostgresQueryBuilder {
+    fn check_column_def(&self, column_def: &ColumnDef) -> Result<()> {
+        if column_def
+            .spec
+            .iter()
+            .any(|column_spec| matches!(column_spec, ColumnSpec::OnUpdate(_)))
+        {
+            return Err(Error::Unsupported(
+                "Column ON UPDATE is not available in Postgres.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
     fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut SqlWriter) {
         column_def.name.prepare(sql, self.quote());
 
         self.prepare_column_type_check_auto_increment(column_def, sql);
 
         for column_spec in column_def.spec.iter() {
-            if let ColumnSpec::AutoIncrement = column_spec {
+            if let ColumnSpec::AutoIncrement | ColumnSpec::OnUpdate(_) | ColumnSpec::Comment(_) =
+                column_spec
+            {
                 continue;
             }
             write!(sql, " ").unwrap();
@@ -107,16 +123,86 @@ impl TableBuilder for PostgresQueryBuilder {
         match column_spec {
             ColumnSpec::Null => write!(sql, "NULL"),
             ColumnSpec::NotNull => write!(sql, "NOT NULL"),
-            ColumnSpec::Default(value) => write!(sql, "DEFAULT {}", self.value_to_string(value)),
+            ColumnSpec::Default(value) => {
+                write!(sql, "DEFAULT {}", self.column_expr_to_string(value))
+            }
             ColumnSpec::AutoIncrement => write!(sql, ""),
             ColumnSpec::UniqueKey => write!(sql, "UNIQUE"),
             ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
+            ColumnSpec::OnUpdate(_) => write!(sql, ""),
+            ColumnSpec::Comment(_) => write!(sql, ""),
+            ColumnSpec::Identity(identity) => {
+                self.prepare_column_identity(identity, sql);
+                Ok(())
+            }
             ColumnSpec::Extra(string) => write!(sql, "{}", string),
         }
         .unwrap()
     }
 
+    fn check_table_partitions(&self, partitions: &[TablePartition]) -> Result<()> {
+        let (mut partition_by, mut partition_of) = (0, 0);
+        for partition in partitions.iter() {
+            match partition {
+                TablePartition::PartitionBy(_, _) => partition_by += 1,
+                TablePartition::PartitionOf(_, bound) => {
+                    if let PartitionBound::LessThan(_) | PartitionBound::LessThanMaxValue = bound {
+                        return Err(Error::Unsupported(
+                            "Partition bound is not available in Postgres.".to_owned(),
+                        ));
+                    }
+                    partition_of += 1;
+                }
+                TablePartition::Partition(_, _) | TablePartition::Partitions(_) => {
+                    return Err(Error::Unsupported(
+                        "Partition definition is not available in Postgres.".to_owned(),
+                    ))
+                }
+            }
+        }
+        if partition_by > 1 || partition_of > 1 {
+            return Err(Error::Unsupported(
+                "Partitioning a table more than once is not available in Postgres.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare_table_create_statement(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_table_create_statement(create));
+        let partition_of = create
+            .partitions
+            .iter()
+            .find_map(|partition| match partition {
+                TablePartition::PartitionOf(parent, bound) => Some((parent, bound)),
+                _ => None,
+            });
+        match partition_of {
+            Some((parent, bound)) => self.prepare_table_partition_of(create, parent, bound, sql),
+            None => self.prepare_table_create_statement_common(create, sql),
+        }
+        for partition in create.partitions.iter() {
+            if let TablePartition::PartitionBy(method, keys) = partition {
+                write!(
+                    sql,
+                    " PARTITION BY {} ({})",
+                    match method {
+                        PartitionMethod::Range => "RANGE",
+                        PartitionMethod::List => "LIST",
+                        PartitionMethod::Hash => "HASH",
+                    },
+                    keys.iter()
+                        .map(|key| self.column_expr_to_string(key))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )
+                .unwrap();
+            }
+        }
+    }
+
     fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_table_alter_statement(alter));
         let alter_option = match &alter.alter_option {
             Some(alter_option) => alter_option,
             None => panic!("No alter option found"),
@@ -137,13 +223,24 @@ impl TableBuilder for PostgresQueryBuilder {
                 write!(sql, " TYPE").unwrap();
                 self.prepare_column_type_check_auto_increment(column_def, sql);
                 for column_spec in column_def.spec.iter() {
-                    if let ColumnSpec::AutoIncrement = column_spec {
+                    if let ColumnSpec::AutoIncrement
+                    | ColumnSpec::OnUpdate(_)
+                    | ColumnSpec::Comment(_) = column_spec
+                    {
                         continue;
                     }
                     write!(sql, ", ").unwrap();
                     write!(sql, "ALTER COLUMN ").unwrap();
                     column_def.name.prepare(sql, self.quote());
-                    write!(sql, " SET ").unwrap();
+                    if let ColumnSpec::Null = column_spec {
+                        write!(sql, " DROP NOT NULL").unwrap();
+                        continue;
+                    }
+                    if let ColumnSpec::Identity(_) = column_spec {
+                        write!(sql, " ADD ").unwrap();
+                    } else {
+                        write!(sql, " SET ").unwrap();
+                    }
                     self.prepare_column_spec(column_spec, sql);
                 }
             }
@@ -157,6 +254,64 @@ impl TableBuilder for PostgresQueryBuilder {
                 write!(sql, "DROP COLUMN ").unwrap();
                 column_name.prepare(sql, self.quote());
             }
+            TableAlterOption::DropColumnDefault(column_name) => {
+                write!(sql, "ALTER COLUMN ").unwrap();
+                column_name.prepare(sql, self.quote());
+                write!(sql, " DROP DEFAULT").unwrap();
+            }
+            TableAlterOption::AddPrimaryKey(index) => {
+                write!(sql, "ADD ").unwrap();
+                self.prepare_table_index_expression(index, sql);
+            }
+            TableAlterOption::DropPrimaryKey(name) => {
+                let name = match (name, &alter.table) {
+                    (Some(name), _) => name.clone(),
+                    (None, Some(table)) => format!("{}_pkey", table.to_string()),
+                    (None, None) => String::new(),
+                };
+                write!(sql, "DROP CONSTRAINT ").unwrap();
+                Alias::new(&name).prepare(sql, self.quote());
+            }
+        }
+    }
+
+    fn table_comment_statements(&self, create: &TableCreateStatement) -> Vec<String> {
+        let table_ref = match &create.table {
+            Some(table_ref) => table_ref,
+            None => return Vec::new(),
+        };
+        let mut statements = Vec::new();
+        if let Some(comment) = &create.comment {
+            let mut sql = SqlWriter::new();
+            write!(sql, "COMMENT ON TABLE ").unwrap();
+            TableBuilder::prepare_table_ref(self, table_ref, &mut sql);
+            write!(
+                sql,
+                " IS {}",
+                self.value_to_string(&comment.as_str().into())
+            )
+            .unwrap();
+            statements.push(sql.result());
+        }
+        for column_def in create.columns.iter() {
+            statements.extend(self.column_comment_statement(table_ref, column_def));
+        }
+        statements
+    }
+
+    fn table_alter_comment_statements(&self, alter: &TableAlterStatement) -> Vec<String> {
+        match (&alter.table, &alter.alter_option) {
+            (
+                Some(table),
+                Some(
+                    TableAlterOption::AddColumn(column_def)
+                    | TableAlterOption::ModifyColumn(column_def),
+                ),
+            ) => self
+                .column_comment_statement(&TableRef::Table(table.clone()), column_def)
+                .into_iter()
+                .collect(),
+            _ => Vec::new(),
         }
     }
 
@@ -173,6 +328,129 @@ impl TableBuilder for PostgresQueryBuilder {
 }
 
 impl PostgresQueryBuilder {
+    /// Translate a partition of a partitioned table, whose columns only add constraints to the columns of the parent
+    fn prepare_table_partition_of(
+        &self,
+        create: &TableCreateStatement,
+        parent: &TableRef,
+        bound: &PartitionBound,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "CREATE TABLE ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(table_ref) = &create.table {
+            TableBuilder::prepare_table_ref(self, table_ref, sql);
+        }
+
+        write!(sql, " PARTITION OF ").unwrap();
+        TableBuilder::prepare_table_ref(self, parent, sql);
+
+        let mut count = 0;
+        for column_def in create.columns.iter() {
+            write!(sql, "{}", if count == 0 { " ( " } else { ", " }).unwrap();
+            column_def.name.prepare(sql, self.quote());
+            write!(sql, " WITH OPTIONS").unwrap();
+            for column_spec in column_def.spec.iter() {
+                if let ColumnSpec::AutoIncrement
+                | ColumnSpec::OnUpdate(_)
+                | ColumnSpec::Comment(_) = column_spec
+                {
+                    continue;
+                }
+                write!(sql, " ").unwrap();
+                self.prepare_column_spec(column_spec, sql);
+            }
+            count += 1;
+        }
+        for index in create.indexes.iter() {
+            write!(sql, "{}", if count == 0 { " ( " } else { ", " }).unwrap();
+            self.prepare_table_index_expression(index, sql);
+            count += 1;
+        }
+        for foreign_key in create.foreign_keys.iter() {
+            write!(sql, "{}", if count == 0 { " ( " } else { ", " }).unwrap();
+            self.prepare_foreign_key_create_statement_internal(foreign_key, sql, true);
+            count += 1;
+        }
+        if count > 0 {
+            write!(sql, " )").unwrap();
+        }
+
+        match bound {
+            PartitionBound::FromTo(from, to) => write!(
+                sql,
+                " FOR VALUES FROM ({}) TO ({})",
+                self.exprs_to_string(from),
+                self.exprs_to_string(to)
+            ),
+            PartitionBound::In(values) => {
+                write!(sql, " FOR VALUES IN ({})", self.exprs_to_string(values))
+            }
+            PartitionBound::Modulus(modulus, remainder) => write!(
+                sql,
+                " FOR VALUES WITH (MODULUS {}, REMAINDER {})",
+                modulus, remainder
+            ),
+            PartitionBound::Default => write!(sql, " DEFAULT"),
+            PartitionBound::LessThan(_) | PartitionBound::LessThanMaxValue => {
+                unreachable!("Partition bound is not available in Postgres.")
+            }
+        }
+        .unwrap();
+    }
+
+    fn column_comment_statement(
+        &self,
+        table_ref: &TableRef,
+        column_def: &ColumnDef,
+    ) -> Option<String> {
+        let comment = column_def
+            .spec
+            .iter()
+            .find_map(|column_spec| match column_spec {
+                ColumnSpec::Comment(comment) => Some(comment),
+                _ => None,
+            })?;
+        let mut sql = SqlWriter::new();
+        write!(sql, "COMMENT ON COLUMN ").unwrap();
+        TableBuilder::prepare_table_ref(self, table_ref, &mut sql);
+        write!(sql, ".").unwrap();
+        column_def.name.prepare(&mut sql, self.quote());
+        write!(
+            sql,
+            " IS {}",
+            self.value_to_string(&comment.as_str().into())
+        )
+        .unwrap();
+        Some(sql.result())
+    }
+
+    fn prepare_column_identity(&self, identity: &ColumnIdentity, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "GENERATED {} AS IDENTITY",
+            match identity.generation {
+                IdentityGeneration::Always => "ALWAYS",
+                IdentityGeneration::ByDefault => "BY DEFAULT",
+            }
+        )
+        .unwrap();
+        let mut options = Vec::new();
+        if let Some(start) = identity.start {
+            options.push(format!("START WITH {}", start));
+        }
+        if let Some(increment) = identity.increment {
+            options.push(format!("INCREMENT BY {}", increment));
+        }
+        if !options.is_empty() {
+            write!(sql, " ({})", options.join(" ")).unwrap();
+        }
+    }
+
     fn prepare_column_type_check_auto_increment(
         &self,
         column_def: &ColumnDef,
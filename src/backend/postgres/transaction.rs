@@ -0,0 +1,75 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl TransactionBuilder for PostgresQueryBuilder {
+    fn check_transaction_begin_statement(&self, begin: &TransactionBeginStatement) -> Result<()> {
+        if begin.behavior.is_some() {
+            return Err(Error::Unsupported(
+                "Transaction behavior is not available in Postgres.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_lock_table_statement(&self, lock: &LockTableStatement) -> Result<()> {
+        if lock.tables.iter().any(|(_, mode)| mode.is_some()) {
+            return Err(Error::Unsupported(
+                "Lock mode per table is not available in Postgres.".to_owned(),
+            ));
+        }
+        if let Some(mode @ (LockMode::Read | LockMode::ReadLocal | LockMode::Write)) = &lock.mode {
+            return Err(Error::Unsupported(format!(
+                "Lock mode {:?} is not available in Postgres.",
+                mode
+            )));
+        }
+        Ok(())
+    }
+
+    fn prepare_transaction_begin_statement(
+        &self,
+        begin: &TransactionBeginStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_transaction_begin_statement(begin));
+        write!(sql, "BEGIN").unwrap();
+        self.prepare_transaction_modes(
+            &begin.isolation_level,
+            &begin.access_mode,
+            &begin.deferrable,
+            sql,
+        );
+    }
+
+    fn prepare_lock_table_statement(&self, lock: &LockTableStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_lock_table_statement(lock));
+        write!(sql, "LOCK TABLE ").unwrap();
+
+        lock.tables.iter().fold(true, |first, (table, _)| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            TableBuilder::prepare_table_ref(self, table, sql);
+            false
+        });
+
+        if let Some(mode) = &lock.mode {
+            write!(sql, " IN ").unwrap();
+            self.prepare_lock_mode(mode, sql);
+            write!(sql, " MODE").unwrap();
+        }
+
+        if lock.nowait {
+            write!(sql, " NOWAIT").unwrap();
+        }
+    }
+
+    fn prepare_lock_mode(&self, mode: &LockMode, sql: &mut SqlWriter) {
+        match mode {
+            LockMode::Read | LockMode::ReadLocal | LockMode::Write => {
+                unreachable!("Lock mode {:?} is not available in Postgres.", mode)
+            }
+            _ => self.prepare_lock_mode_common(mode, sql),
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl TriggerBuilder for PostgresQueryBuilder {
+    fn check_trigger_create_statement(&self, create: &TriggerCreateStatement) -> Result<()> {
+        if let Some(TriggerAction::Statements(_)) = create.action {
+            return Err(Error::Unsupported(
+                "Trigger statement list is not available in Postgres.".to_owned(),
+            ));
+        }
+        if create.if_not_exists {
+            return Err(Error::Unsupported(
+                "Create trigger if not exists is not available in Postgres.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare_trigger_drop_statement(&self, drop: &TriggerDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_trigger_drop_statement(drop));
+        write!(sql, "DROP TRIGGER ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &drop.name {
+            name.prepare(sql, self.quote());
+        }
+
+        write!(sql, " ON ").unwrap();
+        if let Some(table) = &drop.table {
+            TableBuilder::prepare_table_ref(self, table, sql);
+        }
+
+        if let Some(option) = &drop.option {
+            write!(
+                sql,
+                " {}",
+                match option {
+                    TriggerDropOpt::Cascade => "CASCADE",
+                    TriggerDropOpt::Restrict => "RESTRICT",
+                }
+            )
+            .unwrap();
+        }
+    }
+
+    fn prepare_trigger_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "{}", self.simple_expr_to_string(expr)).unwrap();
+    }
+
+    fn prepare_trigger_statement(&self, statement: &QueryStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", self.query_statement_to_string(statement)).unwrap();
+    }
+}
@@ -15,10 +15,37 @@ impl TypeBuilder for PostgresQueryBuilder {
         }
 
         if let Some(as_type) = &create.as_type {
-            write!(sql, " AS ").unwrap();
+            write!(sql, " AS").unwrap();
             self.prepare_create_as_type(as_type, sql);
         }
 
+        if !create.fields.is_empty() {
+            write!(sql, " (").unwrap();
+
+            for (count, (name, column_type)) in create.fields.iter().enumerate() {
+                if count > 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                name.prepare(sql, self.quote());
+                write!(sql, " ").unwrap();
+                self.prepare_column_type(column_type, sql);
+            }
+
+            write!(sql, ")").unwrap();
+        }
+
+        if let Some(subtype) = &create.subtype {
+            write!(sql, " (SUBTYPE = ").unwrap();
+            self.prepare_column_type(subtype, sql);
+
+            if let Some(subtype_diff) = &create.subtype_diff {
+                write!(sql, ", SUBTYPE_DIFF = ").unwrap();
+                subtype_diff.prepare(sql, self.quote());
+            }
+
+            write!(sql, ")").unwrap();
+        }
+
         if !create.values.is_empty() {
             write!(sql, " (").unwrap();
 
@@ -79,13 +106,15 @@ impl PostgresQueryBuilder {
             sql,
             "{}",
             match as_type {
-                TypeAs::Enum => "ENUM",
+                TypeAs::Composite => "",
+                TypeAs::Enum => " ENUM",
+                TypeAs::Range => " RANGE",
             }
         )
         .unwrap()
     }
 
-    fn prepare_drop_type_opt(&self, opt: &TypeDropOpt, sql: &mut SqlWriter) {
+    pub(crate) fn prepare_drop_type_opt(&self, opt: &TypeDropOpt, sql: &mut SqlWriter) {
         write!(
             sql,
             "{}",
@@ -0,0 +1,55 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl ViewBuilder for PostgresQueryBuilder {
+    fn check_view_create_statement(&self, create: &ViewCreateStatement) -> Result<()> {
+        if create.materialized && create.or_replace {
+            return Err(Error::Unsupported(
+                "OR REPLACE of materialized view is not available in Postgres.".to_owned(),
+            ));
+        }
+        if create.materialized && create.check_option {
+            return Err(Error::Unsupported(
+                "WITH CHECK OPTION of materialized view is not available in Postgres.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare_view_rename_statement(&self, rename: &ViewRenameStatement, sql: &mut SqlWriter) {
+        write!(sql, "ALTER ").unwrap();
+        if rename.materialized {
+            write!(sql, "MATERIALIZED ").unwrap();
+        }
+        write!(sql, "VIEW ").unwrap();
+        if let Some(from_name) = &rename.from_name {
+            TableBuilder::prepare_table_ref(self, from_name, sql);
+        }
+        write!(sql, " RENAME TO ").unwrap();
+        if let Some(to_name) = &rename.to_name {
+            to_name.prepare(sql, self.quote());
+        }
+    }
+
+    fn prepare_view_refresh_statement(&self, refresh: &ViewRefreshStatement, sql: &mut SqlWriter) {
+        write!(sql, "REFRESH MATERIALIZED VIEW ").unwrap();
+
+        if refresh.concurrently {
+            write!(sql, "CONCURRENTLY ").unwrap();
+        }
+
+        if let Some(view) = &refresh.view {
+            TableBuilder::prepare_table_ref(self, view, sql);
+        }
+
+        match refresh.with_data {
+            Some(true) => write!(sql, " WITH DATA").unwrap(),
+            Some(false) => write!(sql, " WITH NO DATA").unwrap(),
+            None => {}
+        }
+    }
+
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(PostgresQueryBuilder)).unwrap();
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{error::Result, *};
+
+pub trait PrivilegeBuilder: TableBuilder {
+    /// Check that the backend supports [`GrantStatement`].
+    fn check_grant_statement(&self, _grant: &GrantStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`RevokeStatement`].
+    fn check_revoke_statement(&self, _revoke: &RevokeStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`RoleCreateStatement`].
+    fn check_role_create_statement(&self, _create: &RoleCreateStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`RoleDropStatement`].
+    fn check_role_drop_statement(&self, _drop: &RoleDropStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Translate [`GrantStatement`] into SQL statement.
+    fn prepare_grant_statement(&self, grant: &GrantStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_grant_statement(grant));
+        write!(sql, "GRANT ").unwrap();
+        self.prepare_privileges(&grant.privileges, sql);
+
+        if let Some(object) = &grant.object {
+            write!(sql, " ON ").unwrap();
+            self.prepare_privilege_object(object, sql);
+        }
+
+        write!(sql, " TO ").unwrap();
+        self.prepare_privilege_idens(&grant.grantees, sql);
+
+        if grant.with_grant_option {
+            write!(sql, " WITH GRANT OPTION").unwrap();
+        }
+    }
+
+    /// Translate [`RevokeStatement`] into SQL statement.
+    fn prepare_revoke_statement(&self, revoke: &RevokeStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_revoke_statement(revoke));
+        write!(sql, "REVOKE ").unwrap();
+
+        if revoke.grant_option_for {
+            write!(sql, "GRANT OPTION FOR ").unwrap();
+        }
+
+        self.prepare_privileges(&revoke.privileges, sql);
+
+        if let Some(object) = &revoke.object {
+            write!(sql, " ON ").unwrap();
+            self.prepare_privilege_object(object, sql);
+        }
+
+        write!(sql, " FROM ").unwrap();
+        self.prepare_privilege_idens(&revoke.grantees, sql);
+
+        if let Some(option) = &revoke.option {
+            self.prepare_revoke_opt(option, sql);
+        }
+    }
+
+    /// Translate [`RevokeOpt`] into SQL statement.
+    fn prepare_revoke_opt(&self, option: &RevokeOpt, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            " {}",
+            match option {
+                RevokeOpt::Cascade => "CASCADE",
+                RevokeOpt::Restrict => "RESTRICT",
+            }
+        )
+        .unwrap();
+    }
+
+    /// Translate a list of privileges, each with optional columns, into SQL statement.
+    fn prepare_privileges(&self, privileges: &[(Privilege, Vec<DynIden>)], sql: &mut SqlWriter) {
+        privileges.iter().fold(true, |first, (privilege, columns)| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_privilege(privilege, sql);
+            if !columns.is_empty() {
+                write!(sql, " (").unwrap();
+                columns.iter().fold(true, |first, column| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    column.prepare(sql, self.quote());
+                    false
+                });
+                write!(sql, ")").unwrap();
+            }
+            false
+        });
+    }
+
+    /// Translate [`Privilege`] into SQL statement.
+    fn prepare_privilege(&self, privilege: &Privilege, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match privilege {
+                Privilege::All => "ALL",
+                Privilege::Select => "SELECT",
+                Privilege::Insert => "INSERT",
+                Privilege::Update => "UPDATE",
+                Privilege::Delete => "DELETE",
+                Privilege::Truncate => "TRUNCATE",
+                Privilege::References => "REFERENCES",
+                Privilege::Trigger => "TRIGGER",
+                Privilege::Usage => "USAGE",
+                Privilege::Create => "CREATE",
+                Privilege::Connect => "CONNECT",
+                Privilege::Temporary => "TEMPORARY",
+                Privilege::Execute => "EXECUTE",
+            }
+        )
+        .unwrap();
+    }
+
+    /// Translate [`PrivilegeObject`] into SQL statement.
+    fn prepare_privilege_object(&self, object: &PrivilegeObject, sql: &mut SqlWriter) {
+        let names = match object {
+            PrivilegeObject::Table(tables) => {
+                write!(sql, "TABLE ").unwrap();
+                tables.iter().fold(true, |first, table| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_table_ref(table, sql);
+                    false
+                });
+                return;
+            }
+            PrivilegeObject::Schema(names) => {
+                write!(sql, "SCHEMA ").unwrap();
+                names
+            }
+            PrivilegeObject::Sequence(names) => {
+                write!(sql, "SEQUENCE ").unwrap();
+                names
+            }
+            PrivilegeObject::AllTablesInSchema(names) => {
+                write!(sql, "ALL TABLES IN SCHEMA ").unwrap();
+                names
+            }
+        };
+        self.prepare_privilege_idens(names, sql);
+    }
+
+    /// Translate a list of roles or schemas into SQL statement.
+    fn prepare_privilege_idens(&self, roles: &[DynIden], sql: &mut SqlWriter) {
+        roles.iter().fold(true, |first, role| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            role.prepare(sql, self.quote());
+            false
+        });
+    }
+
+    /// Translate [`RoleCreateStatement`] into SQL statement.
+    fn prepare_role_create_statement(&self, create: &RoleCreateStatement, sql: &mut SqlWriter);
+
+    /// Translate [`RoleDropStatement`] into SQL statement.
+    fn prepare_role_drop_statement(&self, drop: &RoleDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_role_drop_statement(drop));
+        write!(sql, "DROP ROLE ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        self.prepare_privilege_idens(&drop.names, sql);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::*;
+use crate::{error::Result, *};
 
 pub trait QueryBuilder: QuotedBuilder {
     /// The type of placeholder the builder uses for values, and whether it is numbered.
@@ -184,6 +184,62 @@ pub trait QueryBuilder: QuotedBuilder {
         self.prepare_returning(&update.returning, sql, collector);
     }
 
+    /// Translate [`QueryStatement`] into SQL statement.
+    fn prepare_query_statement(
+        &self,
+        query: &QueryStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        match query {
+            QueryStatement::Select(select) => self.prepare_select_statement(select, sql, collector),
+            QueryStatement::Insert(insert) => self.prepare_insert_statement(insert, sql, collector),
+            QueryStatement::Update(update) => self.prepare_update_statement(update, sql, collector),
+            QueryStatement::Delete(delete) => self.prepare_delete_statement(delete, sql, collector),
+        }
+    }
+
+    /// Check that the backend supports [`ExplainStatement`].
+    fn check_explain_statement(&self, _explain: &ExplainStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Translate [`ExplainStatement`] into SQL statement.
+    fn prepare_explain_statement(
+        &self,
+        explain: &ExplainStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        check_supported(self.check_explain_statement(explain));
+        write!(sql, "EXPLAIN").unwrap();
+
+        let mut options = Vec::new();
+        if explain.analyze {
+            options.push("ANALYZE");
+        }
+        if explain.verbose {
+            options.push("VERBOSE");
+        }
+        if explain.buffers {
+            options.push("BUFFERS");
+        }
+        if let Some(format) = &explain.format {
+            options.push(match format {
+                ExplainFormat::Text => "FORMAT TEXT",
+                ExplainFormat::Json => "FORMAT JSON",
+            });
+        }
+        if !options.is_empty() {
+            write!(sql, " ({})", options.join(", ")).unwrap();
+        }
+
+        if let Some(query) = &explain.query {
+            write!(sql, " ").unwrap();
+            self.prepare_query_statement(query, sql, collector);
+        }
+    }
+
     /// Translate [`DeleteStatement`] into SQL statement.
     fn prepare_delete_statement(
         &self,
@@ -494,6 +550,11 @@ pub trait QueryBuilder: QuotedBuilder {
                 write!(sql, " AS ").unwrap();
                 alias.prepare(sql, self.quote());
             }
+            TableRef::FunctionCall(func, alias) => {
+                self.prepare_simple_expr(func, sql, collector);
+                write!(sql, " AS ").unwrap();
+                alias.prepare(sql, self.quote());
+            }
         }
     }
 
@@ -726,6 +787,9 @@ pub trait QueryBuilder: QuotedBuilder {
                 "{}",
                 match keyword {
                     Keyword::Null => "NULL",
+                    Keyword::CurrentDate => "CURRENT_DATE",
+                    Keyword::CurrentTime => "CURRENT_TIME",
+                    Keyword::CurrentTimestamp => "CURRENT_TIMESTAMP",
                     Keyword::Custom(_) => "",
                 }
             )
@@ -733,6 +797,54 @@ pub trait QueryBuilder: QuotedBuilder {
         }
     }
 
+    /// Convert a [`SimpleExpr`] into syntax-specific string, with values inlined
+    fn simple_expr_to_string(&self, simple_expr: &SimpleExpr) -> String
+    where
+        Self: Sized,
+    {
+        let mut sql = SqlWriter::new();
+        let mut values = Vec::new();
+        self.prepare_simple_expr(simple_expr, &mut sql, &mut |v| values.push(v));
+        inject_parameters(&sql.result(), values, self)
+    }
+
+    /// Convert a [`SimpleExpr`] of a column definition, e.g. a default value, into syntax-specific string.
+    /// Expressions other than literals and keywords are enclosed in parentheses.
+    fn column_expr_to_string(&self, simple_expr: &SimpleExpr) -> String
+    where
+        Self: Sized,
+    {
+        match simple_expr {
+            SimpleExpr::Value(_) | SimpleExpr::Keyword(_) | SimpleExpr::Custom(_) => {
+                self.simple_expr_to_string(simple_expr)
+            }
+            _ => format!("({})", self.simple_expr_to_string(simple_expr)),
+        }
+    }
+
+    /// Convert a list of [`SimpleExpr`]s into a comma separated, syntax-specific string
+    fn exprs_to_string(&self, exprs: &[SimpleExpr]) -> String
+    where
+        Self: Sized,
+    {
+        exprs
+            .iter()
+            .map(|expr| self.simple_expr_to_string(expr))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// Convert a [`QueryStatement`] into syntax-specific string, with values inlined
+    fn query_statement_to_string(&self, query: &QueryStatement) -> String
+    where
+        Self: Sized,
+    {
+        let mut sql = SqlWriter::new();
+        let mut values = Vec::new();
+        self.prepare_query_statement(query, &mut sql, &mut |v| values.push(v));
+        inject_parameters(&sql.result(), values, self)
+    }
+
     /// Convert a SQL value into syntax-specific string
     fn value_to_string(&self, v: &Value) -> String {
         let mut s = String::new();
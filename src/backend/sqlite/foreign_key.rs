@@ -1,20 +1,34 @@
 use super::*;
+use crate::error::{Error, Result};
 
 impl ForeignKeyBuilder for SqliteQueryBuilder {
+    fn alterable_foreign_keys(&self) -> bool {
+        false
+    }
+
+    fn check_foreign_key_create_statement(
+        &self,
+        _create: &ForeignKeyCreateStatement,
+    ) -> Result<()> {
+        Err(Error::Unsupported(
+            "Sqlite does not support modification of foreign key constraints to existing tables"
+                .to_owned(),
+        ))
+    }
+
+    fn check_foreign_key_drop_statement(&self, _drop: &ForeignKeyDropStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Sqlite does not support modification of foreign key constraints to existing tables"
+                .to_owned(),
+        ))
+    }
+
     fn prepare_foreign_key_drop_statement(
         &self,
         drop: &ForeignKeyDropStatement,
-        sql: &mut SqlWriter,
+        _sql: &mut SqlWriter,
     ) {
-        write!(sql, "ALTER TABLE ").unwrap();
-        if let Some(table) = &drop.table {
-            table.prepare(sql, self.quote());
-        }
-
-        write!(sql, " DROP FOREIGN KEY ").unwrap();
-        if let Some(name) = &drop.foreign_key.name {
-            write!(sql, "`{}`", name).unwrap();
-        }
+        check_supported(self.check_foreign_key_drop_statement(drop));
     }
 
     fn prepare_foreign_key_create_statement_internal(
@@ -24,7 +38,7 @@ impl ForeignKeyBuilder for SqliteQueryBuilder {
         inside_table_creation: bool,
     ) {
         if !inside_table_creation {
-            panic!("Sqlite does not support modification of foreign key constraints to existing tables");
+            check_supported(self.check_foreign_key_create_statement(create));
         }
 
         write!(sql, "FOREIGN KEY (").unwrap();
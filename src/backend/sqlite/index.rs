@@ -1,6 +1,64 @@
 use super::*;
+use crate::error::{Error, Result};
 
 impl IndexBuilder for SqliteQueryBuilder {
+    fn check_index_create_statement(&self, create: &IndexCreateStatement) -> Result<()> {
+        if !create.include_columns.is_empty() {
+            return Err(Error::Unsupported(
+                "Covering index is not available in SQLite.".to_owned(),
+            ));
+        }
+        if create.concurrently {
+            return Err(Error::Unsupported(
+                "Create index concurrently is not available in SQLite.".to_owned(),
+            ));
+        }
+        check_index(create)
+    }
+
+    fn check_table_index_expression(&self, create: &IndexCreateStatement) -> Result<()> {
+        if create.r#where.is_some() {
+            return Err(Error::Unsupported(
+                "Partial index is not available in a table definition.".to_owned(),
+            ));
+        }
+        if !create.include_columns.is_empty() {
+            return Err(Error::Unsupported(
+                "Covering index is not available in a table definition.".to_owned(),
+            ));
+        }
+        check_index(create)
+    }
+
+    fn check_index_drop_statement(&self, drop: &IndexDropStatement) -> Result<()> {
+        if drop.concurrently {
+            return Err(Error::Unsupported(
+                "Drop index concurrently is not available in SQLite.".to_owned(),
+            ));
+        }
+        if drop.option.is_some() {
+            return Err(Error::Unsupported(
+                "Drop index option is not available in SQLite.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_index_rename_statement(&self, _rename: &IndexRenameStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Index rename is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn check_index_reindex_statement(&self, reindex: &IndexReindexStatement) -> Result<()> {
+        if reindex.concurrently {
+            return Err(Error::Unsupported(
+                "Reindex concurrently is not available in SQLite.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
     fn prepare_table_index_expression(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         if create.index.name.is_some() {
             write!(sql, "CONSTRAINT ").unwrap();
@@ -16,10 +74,15 @@ impl IndexBuilder for SqliteQueryBuilder {
     }
 
     fn prepare_index_create_statement(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_index_create_statement(create));
         write!(sql, "CREATE ").unwrap();
         self.prepare_index_prefix(create, sql);
         write!(sql, "INDEX ").unwrap();
 
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
         self.prepare_index_name(&create.index.name, sql);
 
         write!(sql, " ON ").unwrap();
@@ -30,23 +93,51 @@ impl IndexBuilder for SqliteQueryBuilder {
         // self.prepare_index_type(&create.index_type, sql);
 
         self.prepare_index_columns(&create.index.columns, sql);
+
+        self.prepare_index_where(&create.r#where, sql);
     }
 
     fn prepare_index_drop_statement(&self, drop: &IndexDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_index_drop_statement(drop));
         write!(sql, "DROP INDEX ").unwrap();
-        if let Some(name) = &drop.index.name {
-            let quote = self.quote();
-            write!(sql, "{}{}{}", quote, name, quote).unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
         }
 
-        write!(sql, " ON ").unwrap();
-        if let Some(table) = &drop.table {
-            table.prepare(sql, self.quote());
+        self.prepare_index_schema_name(&drop.schema, &drop.index.name, sql);
+    }
+
+    fn prepare_index_rename_statement(&self, rename: &IndexRenameStatement, _sql: &mut SqlWriter) {
+        check_supported(self.check_index_rename_statement(rename));
+    }
+
+    fn prepare_index_reindex_statement(
+        &self,
+        reindex: &IndexReindexStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_index_reindex_statement(reindex));
+        write!(sql, "REINDEX ").unwrap();
+        if reindex.name.is_some() {
+            self.prepare_index_schema_name(&reindex.schema, &reindex.name, sql);
+        } else {
+            self.prepare_index_schema_table(&reindex.schema, &reindex.table, sql);
         }
     }
 
     fn write_column_index_prefix(&self, _col_prefix: &Option<u32>, _sql: &mut SqlWriter) {}
 
+    fn write_column_index_nulls(&self, col_nulls: &Option<NullOrdering>, _sql: &mut SqlWriter) {
+        if col_nulls.is_some() {
+            unreachable!("Index column nulls ordering is not available in SQLite.")
+        }
+    }
+
+    fn prepare_index_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "{}", self.simple_expr_to_string(expr)).unwrap();
+    }
+
     fn prepare_index_prefix(&self, create: &IndexCreateStatement, sql: &mut SqlWriter) {
         if create.primary {
             write!(sql, "PRIMARY ").unwrap();
@@ -56,3 +147,25 @@ impl IndexBuilder for SqliteQueryBuilder {
         }
     }
 }
+
+fn check_index(create: &IndexCreateStatement) -> Result<()> {
+    if matches!(
+        create.index_type,
+        Some(IndexType::Gin | IndexType::Gist | IndexType::Brin | IndexType::SpGist)
+    ) {
+        return Err(Error::Unsupported(
+            "Index type is not available in SQLite.".to_owned(),
+        ));
+    }
+    if create
+        .index
+        .columns
+        .iter()
+        .any(|column| column.nulls.is_some())
+    {
+        return Err(Error::Unsupported(
+            "Index column nulls ordering is not available in SQLite.".to_owned(),
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl NamespaceBuilder for SqliteQueryBuilder {
+    fn check_schema_create_statement(&self, _create: &SchemaCreateStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Schema is not available in SQLite, attach a database instead.".to_owned(),
+        ))
+    }
+
+    fn check_schema_drop_statement(&self, _drop: &SchemaDropStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Schema is not available in SQLite, detach a database instead.".to_owned(),
+        ))
+    }
+
+    fn check_search_path_statement(&self, _search_path: &SearchPathStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Search path is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn check_database_create_statement(&self, _create: &DatabaseCreateStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Create database is not available in SQLite, attach a database instead.".to_owned(),
+        ))
+    }
+
+    fn check_database_drop_statement(&self, _drop: &DatabaseDropStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Drop database is not available in SQLite, detach a database instead.".to_owned(),
+        ))
+    }
+
+    fn prepare_schema_create_statement(
+        &self,
+        create: &SchemaCreateStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_schema_create_statement(create));
+    }
+
+    fn prepare_schema_drop_statement(&self, drop: &SchemaDropStatement, _sql: &mut SqlWriter) {
+        check_supported(self.check_schema_drop_statement(drop));
+    }
+
+    fn prepare_search_path_statement(
+        &self,
+        search_path: &SearchPathStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_search_path_statement(search_path));
+    }
+
+    fn prepare_database_create_statement(
+        &self,
+        create: &DatabaseCreateStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_database_create_statement(create));
+    }
+
+    fn prepare_database_drop_statement(&self, drop: &DatabaseDropStatement, _sql: &mut SqlWriter) {
+        check_supported(self.check_database_drop_statement(drop));
+    }
+
+    fn prepare_database_attach_statement(
+        &self,
+        attach: &DatabaseAttachStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_database_attach_statement(attach));
+        write!(sql, "ATTACH DATABASE ").unwrap();
+
+        if let Some(file) = &attach.file {
+            write!(sql, "{}", self.value_to_string(&file.as_str().into())).unwrap();
+        }
+
+        write!(sql, " AS ").unwrap();
+        if let Some(name) = &attach.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+
+    fn prepare_database_detach_statement(
+        &self,
+        detach: &DatabaseDetachStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_database_detach_statement(detach));
+        write!(sql, "DETACH DATABASE ").unwrap();
+
+        if let Some(name) = &detach.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl PrivilegeBuilder for SqliteQueryBuilder {
+    fn check_grant_statement(&self, _grant: &GrantStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Grant is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn check_revoke_statement(&self, _revoke: &RevokeStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Revoke is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn check_role_create_statement(&self, _create: &RoleCreateStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Role is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn check_role_drop_statement(&self, _drop: &RoleDropStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Role is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn prepare_role_create_statement(&self, create: &RoleCreateStatement, _sql: &mut SqlWriter) {
+        check_supported(self.check_role_create_statement(create));
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::error::{Error, Result};
 
 impl QueryBuilder for SqliteQueryBuilder {
     fn char_length_function(&self) -> &str {
@@ -29,4 +30,28 @@ impl QueryBuilder for SqliteQueryBuilder {
             Some(NullOrdering::First) => write!(sql, " NULLS FIRST").unwrap(),
         }
     }
+
+    fn check_explain_statement(&self, explain: &ExplainStatement) -> Result<()> {
+        if explain.analyze || explain.verbose || explain.buffers || explain.format.is_some() {
+            return Err(Error::Unsupported(
+                "Explain options are not available in SQLite.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare_explain_statement(
+        &self,
+        explain: &ExplainStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    ) {
+        check_supported(self.check_explain_statement(explain));
+        write!(sql, "EXPLAIN QUERY PLAN").unwrap();
+
+        if let Some(query) = &explain.query {
+            write!(sql, " ").unwrap();
+            self.prepare_query_statement(query, sql, collector);
+        }
+    }
 }
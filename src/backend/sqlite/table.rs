@@ -1,15 +1,102 @@
 use super::*;
+use crate::error::{Error, Result};
 
 impl TableBuilder for SqliteQueryBuilder {
+    fn check_table_create_statement(&self, create: &TableCreateStatement) -> Result<()> {
+        let is_identity = |column_def: &ColumnDef| {
+            column_def
+                .spec
+                .iter()
+                .any(|s| matches!(s, ColumnSpec::Identity(_)))
+        };
+        let is_primary_key = |column_def: &ColumnDef| {
+            column_def
+                .spec
+                .iter()
+                .any(|s| matches!(s, ColumnSpec::Identity(_) | ColumnSpec::PrimaryKey))
+        };
+        // The identity column is the primary key, as an alias of rowid
+        if create.columns.iter().any(is_identity)
+            && (create.columns.iter().filter(|c| is_primary_key(c)).count() > 1
+                || create.indexes.iter().any(|index| index.primary))
+        {
+            return Err(Error::Unsupported(
+                "Identity column with another primary key is not available in SQLite.".to_owned(),
+            ));
+        }
+        for column_def in create.columns.iter() {
+            self.check_column_def(column_def)?;
+        }
+        for index in create.indexes.iter() {
+            self.check_table_index_expression(index)?;
+        }
+        self.check_table_partitions(&create.partitions)
+    }
+
+    fn check_table_partitions(&self, partitions: &[TablePartition]) -> Result<()> {
+        if !partitions.is_empty() {
+            return Err(Error::Unsupported(
+                "Table partitioning is not available in SQLite.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_column_def(&self, column_def: &ColumnDef) -> Result<()> {
+        if column_def
+            .spec
+            .iter()
+            .any(|column_spec| matches!(column_spec, ColumnSpec::OnUpdate(_)))
+        {
+            return Err(Error::Unsupported(
+                "Column ON UPDATE is not available in SQLite.".to_owned(),
+            ));
+        }
+        let identity = column_def
+            .spec
+            .iter()
+            .find_map(|column_spec| match column_spec {
+                ColumnSpec::Identity(identity) => Some(identity),
+                _ => None,
+            });
+        match identity {
+            // A rowid alias accepts explicit values, as `GENERATED BY DEFAULT` does
+            Some(ColumnIdentity {
+                generation: IdentityGeneration::Always,
+                ..
+            }) => Err(Error::Unsupported(
+                "Identity generated always is not available in SQLite.".to_owned(),
+            )),
+            Some(ColumnIdentity {
+                increment: Some(increment),
+                ..
+            }) if *increment != 1 => Err(Error::Unsupported(
+                "Identity increment is not available in SQLite.".to_owned(),
+            )),
+            Some(ColumnIdentity { start: Some(_), .. }) => Err(Error::Unsupported(
+                "Identity start is not available in SQLite.".to_owned(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
     fn prepare_column_def(&self, column_def: &ColumnDef, sql: &mut SqlWriter) {
         column_def.name.prepare(sql, self.quote());
 
-        if let Some(column_type) = &column_def.types {
+        let is_identity = column_def
+            .spec
+            .iter()
+            .any(|s| matches!(s, ColumnSpec::Identity(_)));
+
+        if is_identity {
+            // Only a column declared exactly as `integer PRIMARY KEY` is an alias of rowid
+            write!(sql, " integer").unwrap();
+        } else if let Some(column_type) = &column_def.types {
             write!(sql, " ").unwrap();
             self.prepare_column_type(column_type, sql);
         }
 
-        let mut is_primary_key = false;
+        let mut is_primary_key = is_identity;
         let mut is_auto_increment = false;
 
         for column_spec in column_def.spec.iter() {
@@ -21,6 +108,11 @@ impl TableBuilder for SqliteQueryBuilder {
                 is_auto_increment = true;
                 continue;
             }
+            if let ColumnSpec::Identity(_) | ColumnSpec::OnUpdate(_) | ColumnSpec::Comment(_) =
+                column_spec
+            {
+                continue;
+            }
             write!(sql, " ").unwrap();
             self.prepare_column_spec(column_spec, sql);
         }
@@ -119,10 +211,15 @@ impl TableBuilder for SqliteQueryBuilder {
         match column_spec {
             ColumnSpec::Null => write!(sql, "NULL"),
             ColumnSpec::NotNull => write!(sql, "NOT NULL"),
-            ColumnSpec::Default(value) => write!(sql, "DEFAULT {}", self.value_to_string(value)),
+            ColumnSpec::Default(value) => {
+                write!(sql, "DEFAULT {}", self.column_expr_to_string(value))
+            }
             ColumnSpec::AutoIncrement => write!(sql, "AUTOINCREMENT"),
             ColumnSpec::UniqueKey => write!(sql, "UNIQUE"),
             ColumnSpec::PrimaryKey => write!(sql, "PRIMARY KEY"),
+            ColumnSpec::Identity(_) => write!(sql, ""),
+            ColumnSpec::OnUpdate(_) => write!(sql, ""),
+            ColumnSpec::Comment(_) => write!(sql, ""),
             ColumnSpec::Extra(string) => write!(sql, "{}", string),
         }
         .unwrap()
@@ -132,7 +229,28 @@ impl TableBuilder for SqliteQueryBuilder {
         // SQLite does not support table drop options
     }
 
+    fn check_table_alter_statement(&self, alter: &TableAlterStatement) -> Result<()> {
+        let unsupported = match &alter.alter_option {
+            Some(TableAlterOption::AddColumn(column_def)) => {
+                return self.check_column_def(column_def)
+            }
+            Some(TableAlterOption::ModifyColumn(_) | TableAlterOption::DropColumnDefault(_)) => {
+                "modifying table column"
+            }
+            Some(TableAlterOption::DropColumn(_)) => "dropping table column",
+            Some(TableAlterOption::AddPrimaryKey(_) | TableAlterOption::DropPrimaryKey(_)) => {
+                "modifying primary key"
+            }
+            _ => return Ok(()),
+        };
+        Err(Error::Unsupported(format!(
+            "Sqlite not support {}",
+            unsupported
+        )))
+    }
+
     fn prepare_table_alter_statement(&self, alter: &TableAlterStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_table_alter_statement(alter));
         let alter_option = match &alter.alter_option {
             Some(alter_option) => alter_option,
             None => panic!("No alter option found"),
@@ -147,18 +265,17 @@ impl TableBuilder for SqliteQueryBuilder {
                 write!(sql, "ADD COLUMN ").unwrap();
                 self.prepare_column_def(column_def, sql);
             }
-            TableAlterOption::ModifyColumn(_) => {
-                panic!("Sqlite not support modifying table column")
-            }
+            TableAlterOption::ModifyColumn(_)
+            | TableAlterOption::DropColumn(_)
+            | TableAlterOption::DropColumnDefault(_)
+            | TableAlterOption::AddPrimaryKey(_)
+            | TableAlterOption::DropPrimaryKey(_) => unreachable!(),
             TableAlterOption::RenameColumn(from_name, to_name) => {
                 write!(sql, "RENAME COLUMN ").unwrap();
                 from_name.prepare(sql, self.quote());
                 write!(sql, " TO ").unwrap();
                 to_name.prepare(sql, self.quote());
             }
-            TableAlterOption::DropColumn(_) => {
-                panic!("Sqlite not support dropping table column")
-            }
         }
     }
 
@@ -0,0 +1,62 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl TransactionBuilder for SqliteQueryBuilder {
+    fn check_transaction_begin_statement(&self, begin: &TransactionBeginStatement) -> Result<()> {
+        if begin.isolation_level.is_some()
+            || begin.access_mode.is_some()
+            || begin.deferrable.is_some()
+        {
+            return Err(Error::Unsupported(
+                "Transaction modes are not available in SQLite.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_transaction_set_statement(&self, _set: &TransactionSetStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Set transaction is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn check_lock_table_statement(&self, _lock: &LockTableStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Lock table is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn prepare_transaction_begin_statement(
+        &self,
+        begin: &TransactionBeginStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_transaction_begin_statement(begin));
+        write!(sql, "BEGIN").unwrap();
+
+        if let Some(behavior) = &begin.behavior {
+            write!(
+                sql,
+                " {}",
+                match behavior {
+                    TransactionBehavior::Deferred => "DEFERRED",
+                    TransactionBehavior::Immediate => "IMMEDIATE",
+                    TransactionBehavior::Exclusive => "EXCLUSIVE",
+                }
+            )
+            .unwrap();
+        }
+    }
+
+    fn prepare_transaction_set_statement(
+        &self,
+        set: &TransactionSetStatement,
+        _sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_transaction_set_statement(set));
+    }
+
+    fn prepare_lock_table_statement(&self, lock: &LockTableStatement, _sql: &mut SqlWriter) {
+        check_supported(self.check_lock_table_statement(lock));
+    }
+}
@@ -0,0 +1,45 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl TriggerBuilder for SqliteQueryBuilder {
+    fn check_trigger_create_statement(&self, create: &TriggerCreateStatement) -> Result<()> {
+        if create.events.len() > 1 {
+            return Err(Error::Unsupported(
+                "Multiple trigger events are not available in SQLite.".to_owned(),
+            ));
+        }
+        if let Some(TriggerEvent::Truncate) = create.events.first() {
+            return Err(Error::Unsupported(
+                "TRUNCATE trigger is not available in SQLite.".to_owned(),
+            ));
+        }
+        if let Some(TriggerAction::ExecuteFunction(_)) = create.action {
+            return Err(Error::Unsupported(
+                "EXECUTE FUNCTION is not available in SQLite.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_trigger_drop_statement(&self, drop: &TriggerDropStatement) -> Result<()> {
+        if drop.table.is_some() {
+            return Err(Error::Unsupported(
+                "Drop trigger ON table is not available in SQLite.".to_owned(),
+            ));
+        }
+        if drop.option.is_some() {
+            return Err(Error::Unsupported(
+                "Drop trigger options are not available in SQLite.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn prepare_trigger_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter) {
+        write!(sql, "{}", self.simple_expr_to_string(expr)).unwrap();
+    }
+
+    fn prepare_trigger_statement(&self, statement: &QueryStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", self.query_statement_to_string(statement)).unwrap();
+    }
+}
@@ -0,0 +1,69 @@
+use super::*;
+use crate::error::{Error, Result};
+
+impl ViewBuilder for SqliteQueryBuilder {
+    fn check_view_create_statement(&self, create: &ViewCreateStatement) -> Result<()> {
+        let unsupported = if create.or_replace {
+            "OR REPLACE"
+        } else if create.materialized {
+            "Materialized view"
+        } else if create.check_option {
+            "WITH CHECK OPTION"
+        } else {
+            return Ok(());
+        };
+        Err(Error::Unsupported(format!(
+            "{} is not available in SQLite.",
+            unsupported
+        )))
+    }
+
+    fn check_view_drop_statement(&self, drop: &ViewDropStatement) -> Result<()> {
+        if drop.materialized {
+            return Err(Error::Unsupported(
+                "Materialized view is not available in SQLite.".to_owned(),
+            ));
+        }
+        if drop.views.len() > 1 {
+            return Err(Error::Unsupported(
+                "Dropping multiple views is not available in SQLite.".to_owned(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn check_view_rename_statement(&self, _rename: &ViewRenameStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "View rename is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn check_view_refresh_statement(&self, _refresh: &ViewRefreshStatement) -> Result<()> {
+        Err(Error::Unsupported(
+            "Materialized view is not available in SQLite.".to_owned(),
+        ))
+    }
+
+    fn prepare_view_drop_statement(&self, drop: &ViewDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_view_drop_statement(drop));
+        write!(sql, "DROP VIEW ").unwrap();
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+        if let Some(view) = drop.views.first() {
+            TableBuilder::prepare_table_ref(self, view, sql);
+        }
+    }
+
+    fn prepare_view_rename_statement(&self, rename: &ViewRenameStatement, _sql: &mut SqlWriter) {
+        check_supported(self.check_view_rename_statement(rename));
+    }
+
+    fn prepare_view_refresh_statement(&self, refresh: &ViewRefreshStatement, _sql: &mut SqlWriter) {
+        check_supported(self.check_view_refresh_statement(refresh));
+    }
+
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter) {
+        write!(sql, "{}", query.to_string(SqliteQueryBuilder)).unwrap();
+    }
+}
@@ -1,8 +1,50 @@
-use crate::*;
+use crate::{error::Result, *};
 
 pub trait TableBuilder: IndexBuilder + ForeignKeyBuilder + QuotedBuilder {
+    /// Check that the backend supports [`TableCreateStatement`].
+    fn check_table_create_statement(&self, create: &TableCreateStatement) -> Result<()> {
+        for column_def in create.columns.iter() {
+            self.check_column_def(column_def)?;
+        }
+        for index in create.indexes.iter() {
+            self.check_table_index_expression(index)?;
+        }
+        self.check_table_partitions(&create.partitions)
+    }
+
+    /// Check that the backend supports [`TableAlterStatement`].
+    fn check_table_alter_statement(&self, alter: &TableAlterStatement) -> Result<()> {
+        match &alter.alter_option {
+            Some(TableAlterOption::AddColumn(column_def))
+            | Some(TableAlterOption::ModifyColumn(column_def)) => self.check_column_def(column_def),
+            Some(TableAlterOption::AddPrimaryKey(index)) => {
+                self.check_table_index_expression(index)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Check that the backend supports [`ColumnDef`].
+    fn check_column_def(&self, _column_def: &ColumnDef) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports the partitioning of a table.
+    fn check_table_partitions(&self, _partitions: &[TablePartition]) -> Result<()> {
+        Ok(())
+    }
+
     /// Translate [`TableCreateStatement`] into SQL statement.
     fn prepare_table_create_statement(&self, create: &TableCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_table_create_statement(create));
+        self.prepare_table_create_statement_common(create, sql);
+    }
+
+    fn prepare_table_create_statement_common(
+        &self,
+        create: &TableCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
         write!(sql, "CREATE TABLE ").unwrap();
 
         if create.if_not_exists {
@@ -46,6 +88,10 @@ pub trait TableBuilder: IndexBuilder + ForeignKeyBuilder + QuotedBuilder {
             write!(sql, " ").unwrap();
             self.prepare_table_opt(table_opt, sql);
         }
+
+        self.prepare_table_identity_opt(create, sql);
+
+        self.prepare_table_comment(create, sql);
     }
 
     /// Translate [`TableRef`] into SQL statement.
@@ -97,8 +143,23 @@ pub trait TableBuilder: IndexBuilder + ForeignKeyBuilder + QuotedBuilder {
         .unwrap()
     }
 
-    /// Translate [`TablePartition`] into SQL statement.
-    fn prepare_table_partition(&self, _table_partition: &TablePartition, _sql: &mut SqlWriter) {}
+    /// Translate identity columns of [`TableCreateStatement`] into table options.
+    fn prepare_table_identity_opt(&self, _create: &TableCreateStatement, _sql: &mut SqlWriter) {}
+
+    /// Translate table and column comments of [`TableCreateStatement`] into SQL statement.
+    fn prepare_table_comment(&self, _create: &TableCreateStatement, _sql: &mut SqlWriter) {}
+
+    /// Translate table and column comments of [`TableCreateStatement`] into statements of their own,
+    /// for backends which cannot set them inline.
+    fn table_comment_statements(&self, _create: &TableCreateStatement) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Translate the column comment of [`TableAlterStatement`] into statements of their own,
+    /// for backends which cannot set it inline.
+    fn table_alter_comment_statements(&self, _alter: &TableAlterStatement) -> Vec<String> {
+        Vec::new()
+    }
 
     /// Translate [`TableDropStatement`] into SQL statement.
     fn prepare_table_drop_statement(&self, drop: &TableDropStatement, sql: &mut SqlWriter) {
@@ -0,0 +1,174 @@
+use crate::{error::Result, *};
+
+pub trait TransactionBuilder: TableBuilder {
+    /// Whether schema statements can be rolled back as part of a transaction.
+    fn transactional_ddl(&self) -> bool {
+        true
+    }
+
+    /// Check that the backend supports [`TransactionBeginStatement`].
+    fn check_transaction_begin_statement(&self, _begin: &TransactionBeginStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`TransactionSetStatement`].
+    fn check_transaction_set_statement(&self, _set: &TransactionSetStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`LockTableStatement`].
+    fn check_lock_table_statement(&self, _lock: &LockTableStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Translate [`TransactionBeginStatement`] into SQL statement.
+    fn prepare_transaction_begin_statement(
+        &self,
+        begin: &TransactionBeginStatement,
+        sql: &mut SqlWriter,
+    );
+
+    /// Translate [`TransactionSetStatement`] into SQL statement.
+    fn prepare_transaction_set_statement(
+        &self,
+        set: &TransactionSetStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_transaction_set_statement(set));
+        write!(sql, "SET TRANSACTION").unwrap();
+        self.prepare_transaction_modes(
+            &set.isolation_level,
+            &set.access_mode,
+            &set.deferrable,
+            sql,
+        );
+    }
+
+    /// Translate transaction modes into SQL statement, each prefixed by a space and separated by commas.
+    fn prepare_transaction_modes(
+        &self,
+        isolation_level: &Option<IsolationLevel>,
+        access_mode: &Option<AccessMode>,
+        deferrable: &Option<bool>,
+        sql: &mut SqlWriter,
+    ) {
+        self.prepare_transaction_modes_common(isolation_level, access_mode, deferrable, sql);
+    }
+
+    fn prepare_transaction_modes_common(
+        &self,
+        isolation_level: &Option<IsolationLevel>,
+        access_mode: &Option<AccessMode>,
+        deferrable: &Option<bool>,
+        sql: &mut SqlWriter,
+    ) {
+        let mut modes = Vec::new();
+        if let Some(level) = isolation_level {
+            modes.push(format!(
+                "ISOLATION LEVEL {}",
+                match level {
+                    IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+                    IsolationLevel::ReadCommitted => "READ COMMITTED",
+                    IsolationLevel::RepeatableRead => "REPEATABLE READ",
+                    IsolationLevel::Serializable => "SERIALIZABLE",
+                }
+            ));
+        }
+        if let Some(mode) = access_mode {
+            modes.push(
+                match mode {
+                    AccessMode::ReadOnly => "READ ONLY",
+                    AccessMode::ReadWrite => "READ WRITE",
+                }
+                .to_owned(),
+            );
+        }
+        if let Some(deferrable) = deferrable {
+            modes.push(
+                if *deferrable {
+                    "DEFERRABLE"
+                } else {
+                    "NOT DEFERRABLE"
+                }
+                .to_owned(),
+            );
+        }
+        if !modes.is_empty() {
+            write!(sql, " {}", modes.join(", ")).unwrap();
+        }
+    }
+
+    /// Translate [`TransactionCommitStatement`] into SQL statement.
+    fn prepare_transaction_commit_statement(
+        &self,
+        _commit: &TransactionCommitStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "COMMIT").unwrap();
+    }
+
+    /// Translate [`TransactionRollbackStatement`] into SQL statement.
+    fn prepare_transaction_rollback_statement(
+        &self,
+        rollback: &TransactionRollbackStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "ROLLBACK").unwrap();
+
+        if let Some(savepoint) = &rollback.savepoint {
+            write!(sql, " TO SAVEPOINT ").unwrap();
+            savepoint.prepare(sql, self.quote());
+        }
+    }
+
+    /// Translate [`SavepointStatement`] into SQL statement.
+    fn prepare_savepoint_statement(&self, savepoint: &SavepointStatement, sql: &mut SqlWriter) {
+        write!(sql, "SAVEPOINT ").unwrap();
+
+        if let Some(name) = &savepoint.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+
+    /// Translate [`SavepointReleaseStatement`] into SQL statement.
+    fn prepare_savepoint_release_statement(
+        &self,
+        release: &SavepointReleaseStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "RELEASE SAVEPOINT ").unwrap();
+
+        if let Some(name) = &release.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+
+    /// Translate [`LockTableStatement`] into SQL statement.
+    fn prepare_lock_table_statement(&self, lock: &LockTableStatement, sql: &mut SqlWriter);
+
+    /// Translate [`LockMode`] into SQL statement.
+    fn prepare_lock_mode(&self, mode: &LockMode, sql: &mut SqlWriter) {
+        self.prepare_lock_mode_common(mode, sql);
+    }
+
+    fn prepare_lock_mode_common(&self, mode: &LockMode, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match mode {
+                LockMode::AccessShare => "ACCESS SHARE",
+                LockMode::RowShare => "ROW SHARE",
+                LockMode::RowExclusive => "ROW EXCLUSIVE",
+                LockMode::ShareUpdateExclusive => "SHARE UPDATE EXCLUSIVE",
+                LockMode::Share => "SHARE",
+                LockMode::ShareRowExclusive => "SHARE ROW EXCLUSIVE",
+                LockMode::Exclusive => "EXCLUSIVE",
+                LockMode::AccessExclusive => "ACCESS EXCLUSIVE",
+                LockMode::Read => "READ",
+                LockMode::ReadLocal => "READ LOCAL",
+                LockMode::Write => "WRITE",
+            }
+        )
+        .unwrap();
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{error::Result, *};
+
+pub trait TriggerBuilder: TableBuilder {
+    /// Check that the backend supports [`TriggerCreateStatement`].
+    fn check_trigger_create_statement(&self, _create: &TriggerCreateStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Translate [`TriggerCreateStatement`] into SQL statement.
+    fn prepare_trigger_create_statement(
+        &self,
+        create: &TriggerCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        check_supported(self.check_trigger_create_statement(create));
+        self.prepare_trigger_create_statement_common(create, sql);
+    }
+
+    fn prepare_trigger_create_statement_common(
+        &self,
+        create: &TriggerCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "CREATE TRIGGER ").unwrap();
+
+        if create.if_not_exists {
+            write!(sql, "IF NOT EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &create.name {
+            name.prepare(sql, self.quote());
+        }
+
+        if let Some(timing) = &create.timing {
+            write!(sql, " ").unwrap();
+            self.prepare_trigger_timing(timing, sql);
+        }
+
+        create.events.iter().fold(true, |first, event| {
+            if first {
+                write!(sql, " ").unwrap();
+            } else {
+                write!(sql, " OR ").unwrap();
+            }
+            self.prepare_trigger_event(event, sql);
+            false
+        });
+
+        write!(sql, " ON ").unwrap();
+        if let Some(table) = &create.table {
+            self.prepare_table_ref(table, sql);
+        }
+
+        if create.for_each_row {
+            write!(sql, " FOR EACH ROW").unwrap();
+        }
+
+        if let Some(condition) = &create.when {
+            write!(sql, " WHEN (").unwrap();
+            self.prepare_trigger_expr(condition, sql);
+            write!(sql, ")").unwrap();
+        }
+
+        if let Some(action) = &create.action {
+            write!(sql, " ").unwrap();
+            self.prepare_trigger_action(action, sql);
+        }
+    }
+
+    /// Check that the backend supports [`TriggerDropStatement`].
+    fn check_trigger_drop_statement(&self, _drop: &TriggerDropStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Translate [`TriggerDropStatement`] into SQL statement.
+    fn prepare_trigger_drop_statement(&self, drop: &TriggerDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_trigger_drop_statement(drop));
+        write!(sql, "DROP TRIGGER ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        if let Some(name) = &drop.name {
+            name.prepare(sql, self.quote());
+        }
+    }
+
+    /// Translate [`TriggerTiming`] into SQL statement.
+    fn prepare_trigger_timing(&self, timing: &TriggerTiming, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match timing {
+                TriggerTiming::Before => "BEFORE",
+                TriggerTiming::After => "AFTER",
+                TriggerTiming::InsteadOf => "INSTEAD OF",
+            }
+        )
+        .unwrap();
+    }
+
+    /// Translate [`TriggerEvent`] into SQL statement.
+    fn prepare_trigger_event(&self, event: &TriggerEvent, sql: &mut SqlWriter) {
+        match event {
+            TriggerEvent::Insert => write!(sql, "INSERT").unwrap(),
+            TriggerEvent::Update(columns) => {
+                write!(sql, "UPDATE").unwrap();
+                columns.iter().fold(true, |first, col| {
+                    if first {
+                        write!(sql, " OF ").unwrap();
+                    } else {
+                        write!(sql, ", ").unwrap();
+                    }
+                    col.prepare(sql, self.quote());
+                    false
+                });
+            }
+            TriggerEvent::Delete => write!(sql, "DELETE").unwrap(),
+            TriggerEvent::Truncate => write!(sql, "TRUNCATE").unwrap(),
+        }
+    }
+
+    /// Translate [`TriggerAction`] into SQL statement.
+    fn prepare_trigger_action(&self, action: &TriggerAction, sql: &mut SqlWriter) {
+        match action {
+            TriggerAction::ExecuteFunction(function) => {
+                write!(sql, "EXECUTE FUNCTION ").unwrap();
+                function.prepare(sql, self.quote());
+                write!(sql, "()").unwrap();
+            }
+            TriggerAction::Statements(statements) => {
+                write!(sql, "BEGIN ").unwrap();
+                for statement in statements.iter() {
+                    self.prepare_trigger_statement(statement, sql);
+                    write!(sql, "; ").unwrap();
+                }
+                write!(sql, "END").unwrap();
+            }
+        }
+    }
+
+    #[doc(hidden)]
+    /// Write a trigger condition, with values inlined.
+    fn prepare_trigger_expr(&self, expr: &SimpleExpr, sql: &mut SqlWriter);
+
+    #[doc(hidden)]
+    /// Write a statement of trigger body, with values inlined.
+    fn prepare_trigger_statement(&self, statement: &QueryStatement, sql: &mut SqlWriter);
+}
@@ -0,0 +1,123 @@
+use crate::{error::Result, *};
+
+pub trait ViewBuilder: TableBuilder {
+    /// Check that the backend supports [`ViewCreateStatement`].
+    fn check_view_create_statement(&self, _create: &ViewCreateStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`ViewDropStatement`].
+    fn check_view_drop_statement(&self, _drop: &ViewDropStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`ViewRenameStatement`].
+    fn check_view_rename_statement(&self, _rename: &ViewRenameStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Check that the backend supports [`ViewRefreshStatement`].
+    fn check_view_refresh_statement(&self, _refresh: &ViewRefreshStatement) -> Result<()> {
+        Ok(())
+    }
+
+    /// Translate [`ViewCreateStatement`] into SQL statement.
+    fn prepare_view_create_statement(&self, create: &ViewCreateStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_view_create_statement(create));
+        self.prepare_view_create_statement_common(create, sql);
+    }
+
+    fn prepare_view_create_statement_common(
+        &self,
+        create: &ViewCreateStatement,
+        sql: &mut SqlWriter,
+    ) {
+        write!(sql, "CREATE ").unwrap();
+
+        if create.or_replace {
+            write!(sql, "OR REPLACE ").unwrap();
+        }
+
+        if create.materialized {
+            write!(sql, "MATERIALIZED ").unwrap();
+        }
+
+        write!(sql, "VIEW ").unwrap();
+
+        if let Some(view) = &create.view {
+            self.prepare_table_ref(view, sql);
+        }
+
+        if !create.columns.is_empty() {
+            write!(sql, " (").unwrap();
+            create.columns.iter().fold(true, |first, col| {
+                if !first {
+                    write!(sql, ", ").unwrap();
+                }
+                col.prepare(sql, self.quote());
+                false
+            });
+            write!(sql, ")").unwrap();
+        }
+
+        if let Some(query) = &create.query {
+            write!(sql, " AS ").unwrap();
+            self.prepare_view_query(query, sql);
+        }
+
+        if create.check_option {
+            write!(sql, " WITH CHECK OPTION").unwrap();
+        }
+    }
+
+    /// Translate [`ViewDropStatement`] into SQL statement.
+    fn prepare_view_drop_statement(&self, drop: &ViewDropStatement, sql: &mut SqlWriter) {
+        check_supported(self.check_view_drop_statement(drop));
+        write!(sql, "DROP ").unwrap();
+
+        if drop.materialized {
+            write!(sql, "MATERIALIZED ").unwrap();
+        }
+
+        write!(sql, "VIEW ").unwrap();
+
+        if drop.if_exists {
+            write!(sql, "IF EXISTS ").unwrap();
+        }
+
+        drop.views.iter().fold(true, |first, view| {
+            if !first {
+                write!(sql, ", ").unwrap();
+            }
+            self.prepare_table_ref(view, sql);
+            false
+        });
+
+        for drop_opt in drop.options.iter() {
+            self.prepare_view_drop_opt(drop_opt, sql);
+        }
+    }
+
+    /// Translate [`ViewDropOpt`] into SQL statement.
+    fn prepare_view_drop_opt(&self, drop_opt: &ViewDropOpt, sql: &mut SqlWriter) {
+        write!(
+            sql,
+            " {}",
+            match drop_opt {
+                ViewDropOpt::Restrict => "RESTRICT",
+                ViewDropOpt::Cascade => "CASCADE",
+            }
+        )
+        .unwrap();
+    }
+
+    /// Translate [`ViewRenameStatement`] into SQL statement.
+    fn prepare_view_rename_statement(&self, rename: &ViewRenameStatement, sql: &mut SqlWriter);
+
+    /// Translate [`ViewRefreshStatement`] into SQL statement.
+    fn prepare_view_refresh_statement(&self, refresh: &ViewRefreshStatement, sql: &mut SqlWriter);
+
+    #[doc(hidden)]
+    /// Write the select statement of a view, with values inlined.
+    fn prepare_view_query(&self, query: &SelectStatement, sql: &mut SqlWriter);
+}
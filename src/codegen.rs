@@ -0,0 +1,613 @@
+//! Rust source generation from table definitions.
+//!
+//! # Usage
+//!
+//! - Add declared tables, or tables converted from a discovered schema, to a [`Codegen`]
+//! - Call [`Codegen::generate`] and write the tokens into a source file, formatted with e.g. `rustfmt`
+//!
+//! Each table becomes an enum deriving [`Iden`](crate::Iden) with a `Table` variant and one variant per column,
+//! along with a `create_table` method rebuilding the [`TableCreateStatement`].
+//! The generated code refers to the `sea_query` crate by absolute paths and needs the `derive` feature.
+
+use crate::{
+    schema::table_name, ColumnDef, ColumnSpec, ColumnType, CommonSqlQueryBuilder, ForeignKeyAction,
+    ForeignKeyCreateStatement, IdentityGeneration, IndexColumn, IndexCreateStatement, IndexOrder,
+    IndexType, Keyword, NullOrdering, QueryBuilder, SimpleExpr, TableCreateStatement, TableOpt,
+    TableRef, Value,
+};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+
+/// Generates Rust source recreating a set of tables.
+///
+/// Foreign keys referencing tables of the set refer to their generated enums,
+/// other identifiers are written as [`Alias`](crate::Alias).
+/// Defaults other than values and keywords are kept as custom SQL.
+/// Table partitions are not generated.
+/// Tables or columns whose names convert to the same identifier are told apart by a numeric suffix.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{codegen::Codegen, *};
+///
+/// let tokens = Codegen::new()
+///     .table(
+///         Table::create()
+///             .table(Alias::new("font"))
+///             .col(
+///                 ColumnDef::new(Alias::new("id"))
+///                     .integer()
+///                     .not_null()
+///                     .primary_key(),
+///             )
+///             .col(ColumnDef::new(Alias::new("name")).string_len(255)),
+///     )
+///     .generate();
+///
+/// assert_eq!(
+///     tokens.to_string(),
+///     [
+///         "# [derive (Copy , Clone , Debug , sea_query :: Iden)]",
+///         "pub enum Font { Table , Id , Name }",
+///         "impl Font {",
+///         "pub fn create_table () -> sea_query :: TableCreateStatement {",
+///         "sea_query :: Table :: create ()",
+///         ". table (Self :: Table)",
+///         ". col (sea_query :: ColumnDef :: new (Self :: Id) . integer () . not_null () . primary_key ())",
+///         ". col (sea_query :: ColumnDef :: new (Self :: Name) . string_len (255))",
+///         ". to_owned ()",
+///         "} }",
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Codegen {
+    pub(crate) tables: Vec<TableCreateStatement>,
+}
+
+/// Names of a table and its columns in the generated enum
+struct TableEnum {
+    name: String,
+    ident: Ident,
+    columns: Vec<(String, Ident)>,
+}
+
+impl Codegen {
+    /// Construct a new [`Codegen`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a table
+    pub fn table(&mut self, table: &mut TableCreateStatement) -> &mut Self {
+        self.tables.push(table.take());
+        self
+    }
+
+    pub fn get_tables(&self) -> &Vec<TableCreateStatement> {
+        &self.tables
+    }
+
+    /// Generate the enums and `create_table` methods of all named tables
+    pub fn generate(&self) -> TokenStream {
+        let mut tables: Vec<(&TableCreateStatement, TableEnum)> = Vec::new();
+        for table in self.tables.iter() {
+            let taken: Vec<&Ident> = tables
+                .iter()
+                .map(|(_, table_enum)| &table_enum.ident)
+                .collect();
+            if let Some(table_enum) = TableEnum::new(table, &taken) {
+                tables.push((table, table_enum));
+            }
+        }
+        let enums: Vec<&TableEnum> = tables.iter().map(|(_, table_enum)| table_enum).collect();
+
+        let mut tokens = TokenStream::new();
+        for (table, table_enum) in tables.iter() {
+            tokens.extend(table_enum.declaration());
+            tokens.extend(table_enum.create_table(table, &enums));
+        }
+        tokens
+    }
+}
+
+impl TableEnum {
+    /// Names distinct from the enums of `taken`, and columns named distinctly from each other
+    fn new(table: &TableCreateStatement, taken: &[&Ident]) -> Option<Self> {
+        let name = table.table.as_ref().and_then(table_name)?.to_string();
+        let ident = unique_ident(pascal_ident(&name, &["Self"], "Table"), taken);
+        let mut columns: Vec<(String, Ident)> = Vec::new();
+        for column in table.columns.iter() {
+            let column = column.name.to_string();
+            if columns.iter().all(|(name, _)| name != &column) {
+                let taken: Vec<&Ident> = columns.iter().map(|(_, ident)| ident).collect();
+                let ident = pascal_ident(&column, &["Self", "Table"], "Column");
+                columns.push((column, unique_ident(ident, &taken)));
+            }
+        }
+        Some(Self {
+            name,
+            ident,
+            columns,
+        })
+    }
+
+    fn declaration(&self) -> TokenStream {
+        let ident = &self.ident;
+        let rename = rename_attr(&self.name, ident);
+        let variants = self.columns.iter().map(|(name, ident)| {
+            let rename = rename_attr(name, ident);
+            quote! { #rename #ident }
+        });
+        quote! {
+            #[derive(Copy, Clone, Debug, sea_query::Iden)]
+            #rename
+            pub enum #ident {
+                Table,
+                #(#variants),*
+            }
+        }
+    }
+
+    fn create_table(&self, table: &TableCreateStatement, enums: &[&TableEnum]) -> TokenStream {
+        let ident = &self.ident;
+        let table_ref = match &table.table {
+            Some(TableRef::SchemaTable(schema, _)) => {
+                let schema = schema.to_string();
+                quote! { (sea_query::Alias::new(#schema), Self::Table) }
+            }
+            _ => quote! { Self::Table },
+        };
+
+        let mut calls = Vec::new();
+        if table.if_not_exists {
+            calls.push(quote! { .if_not_exists() });
+        }
+        for column in table.columns.iter() {
+            let column = self.column_def(column);
+            calls.push(quote! { .col(#column) });
+        }
+        for index in table.indexes.iter() {
+            let method = if index.primary {
+                quote! { primary_key }
+            } else {
+                quote! { index }
+            };
+            let index = self.index(index);
+            calls.push(quote! { .#method(#index) });
+        }
+        for foreign_key in table.foreign_keys.iter() {
+            let foreign_key = self.foreign_key(foreign_key, enums);
+            calls.push(quote! { .foreign_key(#foreign_key) });
+        }
+        for option in table.options.iter() {
+            calls.push(match option {
+                TableOpt::Engine(s) => quote! { .engine(#s) },
+                TableOpt::Collate(s) => quote! { .collate(#s) },
+                TableOpt::CharacterSet(s) => quote! { .character_set(#s) },
+            });
+        }
+        if let Some(comment) = &table.comment {
+            calls.push(quote! { .comment(#comment) });
+        }
+
+        quote! {
+            impl #ident {
+                pub fn create_table() -> sea_query::TableCreateStatement {
+                    sea_query::Table::create()
+                        .table(#table_ref)
+                        #(#calls)*
+                        .to_owned()
+                }
+            }
+        }
+    }
+
+    fn column_def(&self, column: &ColumnDef) -> TokenStream {
+        let name = self.col(&column.name.to_string());
+        let mut calls = Vec::new();
+        let constructor = match &column.types {
+            Some(ColumnType::Array(None)) => {
+                quote! { new_with_type(#name, sea_query::ColumnType::Array(None)) }
+            }
+            Some(types) => {
+                calls.push(column_type(types));
+                quote! { new(#name) }
+            }
+            None => quote! { new(#name) },
+        };
+        for spec in column.spec.iter() {
+            calls.push(match spec {
+                // Columns are nullable unless specified otherwise
+                ColumnSpec::Null => continue,
+                ColumnSpec::NotNull => quote! { .not_null() },
+                ColumnSpec::Default(expr) => {
+                    let expr = simple_expr(expr);
+                    quote! { .default(#expr) }
+                }
+                ColumnSpec::AutoIncrement => quote! { .auto_increment() },
+                ColumnSpec::UniqueKey => quote! { .unique_key() },
+                ColumnSpec::PrimaryKey => quote! { .primary_key() },
+                ColumnSpec::Identity(identity) => {
+                    let mut identity_tokens = match identity.generation {
+                        IdentityGeneration::Always => {
+                            quote! { sea_query::ColumnIdentity::always() }
+                        }
+                        IdentityGeneration::ByDefault => {
+                            quote! { sea_query::ColumnIdentity::by_default() }
+                        }
+                    };
+                    if let Some(start) = identity.start {
+                        identity_tokens.extend(quote! { .start_with(#start) });
+                    }
+                    if let Some(increment) = identity.increment {
+                        identity_tokens.extend(quote! { .increment_by(#increment) });
+                    }
+                    quote! { .identity(#identity_tokens) }
+                }
+                ColumnSpec::OnUpdate(expr) => {
+                    let expr = simple_expr(expr);
+                    quote! { .on_update(#expr) }
+                }
+                ColumnSpec::Comment(s) => quote! { .comment(#s) },
+                ColumnSpec::Extra(s) => quote! { .extra(#s.to_owned()) },
+            });
+        }
+        quote! { sea_query::ColumnDef::#constructor #(#calls)* }
+    }
+
+    fn index(&self, index: &IndexCreateStatement) -> TokenStream {
+        let mut calls = Vec::new();
+        if let Some(name) = &index.index.name {
+            calls.push(quote! { .name(#name) });
+        }
+        for column in index.index.columns.iter() {
+            let column = self.index_column(column);
+            calls.push(quote! { .col(#column) });
+        }
+        if index.unique {
+            calls.push(quote! { .unique() });
+        }
+        match &index.index_type {
+            Some(IndexType::FullText) => calls.push(quote! { .full_text() }),
+            Some(IndexType::Custom(iden)) => {
+                let iden = iden.to_string();
+                calls.push(quote! {
+                    .index_type(sea_query::IndexType::Custom(
+                        sea_query::IntoIden::into_iden(sea_query::Alias::new(#iden))
+                    ))
+                });
+            }
+            Some(index_type) => {
+                let index_type = debug_ident(index_type);
+                calls.push(quote! { .index_type(sea_query::IndexType::#index_type) });
+            }
+            None => (),
+        }
+        for column in index.include_columns.iter() {
+            let column = self.col(&column.to_string());
+            calls.push(quote! { .include(#column) });
+        }
+        if let Some(condition) = &index.r#where {
+            let condition = simple_expr(condition);
+            calls.push(quote! { .and_where(#condition) });
+        }
+        quote! { sea_query::Index::create() #(#calls)* }
+    }
+
+    fn index_column(&self, column: &IndexColumn) -> TokenStream {
+        let mut tokens = match &column.expr {
+            Some(expr) => {
+                let expr = simple_expr(expr);
+                quote! { sea_query::IndexColumn::expr(#expr) }
+            }
+            None => {
+                let name = self.col(&column.name.to_string());
+                if column.prefix.is_none()
+                    && column.order.is_none()
+                    && column.collate.is_none()
+                    && column.nulls.is_none()
+                {
+                    return name;
+                }
+                quote! { sea_query::IndexColumn::new(#name) }
+            }
+        };
+        if let Some(prefix) = column.prefix {
+            let prefix = Literal::u32_unsuffixed(prefix);
+            tokens.extend(quote! { .prefix(#prefix) });
+        }
+        if let Some(order) = &column.order {
+            let order = match order {
+                IndexOrder::Asc => quote! { Asc },
+                IndexOrder::Desc => quote! { Desc },
+            };
+            tokens.extend(quote! { .order(sea_query::IndexOrder::#order) });
+        }
+        if let Some(collate) = &column.collate {
+            tokens.extend(quote! { .collate(#collate) });
+        }
+        if let Some(nulls) = &column.nulls {
+            let nulls = match nulls {
+                NullOrdering::First => quote! { First },
+                NullOrdering::Last => quote! { Last },
+            };
+            tokens.extend(quote! { .nulls(sea_query::NullOrdering::#nulls) });
+        }
+        tokens
+    }
+
+    fn foreign_key(
+        &self,
+        foreign_key: &ForeignKeyCreateStatement,
+        enums: &[&TableEnum],
+    ) -> TokenStream {
+        let foreign_key = &foreign_key.foreign_key;
+        let ref_table = foreign_key
+            .ref_table
+            .as_ref()
+            .map(|table| table.to_string())
+            .and_then(|table| enums.iter().find(|table_enum| table_enum.name == table));
+
+        let mut calls = Vec::new();
+        if let Some(name) = &foreign_key.name {
+            calls.push(quote! { .name(#name) });
+        }
+        if let Some(table) = &foreign_key.table {
+            let table = self.table_iden(&table.to_string(), enums);
+            calls.push(quote! { .from_tbl(#table) });
+        }
+        for column in foreign_key.columns.iter() {
+            let column = self.col(&column.to_string());
+            calls.push(quote! { .from_col(#column) });
+        }
+        if let Some(table) = &foreign_key.ref_table {
+            let table = self.table_iden(&table.to_string(), enums);
+            calls.push(quote! { .to_tbl(#table) });
+        }
+        for column in foreign_key.ref_columns.iter() {
+            let column = column.to_string();
+            let column = match ref_table {
+                Some(ref_table) if ref_table.name == self.name => self.col(&column),
+                Some(ref_table) => match ref_table.column(&column) {
+                    Some(variant) => {
+                        let ident = &ref_table.ident;
+                        quote! { #ident::#variant }
+                    }
+                    None => alias(&column),
+                },
+                None => alias(&column),
+            };
+            calls.push(quote! { .to_col(#column) });
+        }
+        if let Some(action) = &foreign_key.on_delete {
+            let action = foreign_key_action(action);
+            calls.push(quote! { .on_delete(#action) });
+        }
+        if let Some(action) = &foreign_key.on_update {
+            let action = foreign_key_action(action);
+            calls.push(quote! { .on_update(#action) });
+        }
+        quote! { sea_query::ForeignKey::create() #(#calls)* }
+    }
+
+    /// A table by the `Table` variant of its enum, if it is this table or another table of the set
+    fn table_iden(&self, name: &str, enums: &[&TableEnum]) -> TokenStream {
+        if self.name == name {
+            return quote! { Self::Table };
+        }
+        match enums.iter().find(|table_enum| table_enum.name == name) {
+            Some(table_enum) => {
+                let ident = &table_enum.ident;
+                quote! { #ident::Table }
+            }
+            None => alias(name),
+        }
+    }
+
+    fn column(&self, name: &str) -> Option<&Ident> {
+        self.columns
+            .iter()
+            .find(|(column, _)| column == name)
+            .map(|(_, ident)| ident)
+    }
+
+    /// A column of this table, by its variant if it has one
+    fn col(&self, name: &str) -> TokenStream {
+        match self.column(name) {
+            Some(variant) => quote! { Self::#variant },
+            None => alias(name),
+        }
+    }
+}
+
+fn alias(name: &str) -> TokenStream {
+    quote! { sea_query::Alias::new(#name) }
+}
+
+fn column_type(types: &ColumnType) -> TokenStream {
+    fn len(method: &str, len: &Option<u32>) -> TokenStream {
+        match len {
+            Some(len) => {
+                let method = Ident::new(&format!("{}_len", method), Span::call_site());
+                let len = Literal::u32_unsuffixed(*len);
+                quote! { .#method(#len) }
+            }
+            None => {
+                let method = Ident::new(method, Span::call_site());
+                quote! { .#method() }
+            }
+        }
+    }
+
+    fn precision_scale(method: &str, precision_scale: &Option<(u32, u32)>) -> TokenStream {
+        match precision_scale {
+            Some((precision, scale)) => {
+                let method = Ident::new(&format!("{}_len", method), Span::call_site());
+                let precision = Literal::u32_unsuffixed(*precision);
+                let scale = Literal::u32_unsuffixed(*scale);
+                quote! { .#method(#precision, #scale) }
+            }
+            None => {
+                let method = Ident::new(method, Span::call_site());
+                quote! { .#method() }
+            }
+        }
+    }
+
+    match types {
+        ColumnType::Char(length) => len("char", length),
+        ColumnType::String(length) => len("string", length),
+        ColumnType::Text => quote! { .text() },
+        ColumnType::TinyInteger(length) => len("tiny_integer", length),
+        ColumnType::SmallInteger(length) => len("small_integer", length),
+        ColumnType::Integer(length) => len("integer", length),
+        ColumnType::BigInteger(length) => len("big_integer", length),
+        ColumnType::Float(precision) => len("float", precision),
+        ColumnType::Double(precision) => len("double", precision),
+        ColumnType::Decimal(precision_scale_) => precision_scale("decimal", precision_scale_),
+        ColumnType::DateTime(precision) => len("date_time", precision),
+        ColumnType::Timestamp(precision) => len("timestamp", precision),
+        ColumnType::TimestampWithTimeZone(precision) => len("timestamp_with_time_zone", precision),
+        ColumnType::Time(precision) => len("time", precision),
+        ColumnType::Date => quote! { .date() },
+        ColumnType::Interval(fields, precision) => {
+            let fields = match fields {
+                Some(fields) => {
+                    let fields = debug_ident(fields);
+                    quote! { Some(sea_query::PgInterval::#fields) }
+                }
+                None => quote! { None },
+            };
+            let precision = match precision {
+                Some(precision) => {
+                    let precision = Literal::u32_unsuffixed(*precision);
+                    quote! { Some(#precision) }
+                }
+                None => quote! { None },
+            };
+            quote! { .interval(#fields, #precision) }
+        }
+        ColumnType::Binary(length) => len("binary", length),
+        ColumnType::Boolean => quote! { .boolean() },
+        ColumnType::Money(precision_scale_) => precision_scale("money", precision_scale_),
+        ColumnType::Json => quote! { .json() },
+        ColumnType::JsonBinary => quote! { .json_binary() },
+        ColumnType::Uuid => quote! { .uuid() },
+        ColumnType::Custom(iden) => {
+            let iden = alias(&iden.to_string());
+            quote! { .custom(#iden) }
+        }
+        ColumnType::Enum(name, variants) => quote! { .enumeration(#name, [#(#variants),*]) },
+        ColumnType::Array(Some(elem_type)) => quote! { .array(#elem_type.to_owned()) },
+        ColumnType::Array(None) => unreachable!("constructed with the type"),
+    }
+}
+
+/// Values and keywords are written as such, any other expression as custom SQL
+fn simple_expr(expr: &SimpleExpr) -> TokenStream {
+    match expr {
+        SimpleExpr::Value(value) => match value_literal(value) {
+            Some(literal) => literal,
+            None => custom_sql(CommonSqlQueryBuilder.value_to_string(value)),
+        },
+        SimpleExpr::Keyword(Keyword::CurrentDate) => quote! { sea_query::Expr::current_date() },
+        SimpleExpr::Keyword(Keyword::CurrentTime) => quote! { sea_query::Expr::current_time() },
+        SimpleExpr::Keyword(Keyword::CurrentTimestamp) => {
+            quote! { sea_query::Expr::current_timestamp() }
+        }
+        SimpleExpr::Keyword(_) | SimpleExpr::Custom(_) => {
+            custom_sql(CommonSqlQueryBuilder.simple_expr_to_string(expr))
+        }
+        // Parenthesized, as expressions in column definitions must be on MySQL
+        _ => custom_sql(format!(
+            "({})",
+            CommonSqlQueryBuilder.simple_expr_to_string(expr)
+        )),
+    }
+}
+
+fn custom_sql(sql: String) -> TokenStream {
+    quote! { sea_query::Expr::cust(#sql) }
+}
+
+fn value_literal(value: &Value) -> Option<TokenStream> {
+    Some(match value {
+        Value::Bool(Some(v)) => quote! { #v },
+        Value::TinyInt(Some(v)) => quote! { #v },
+        Value::SmallInt(Some(v)) => quote! { #v },
+        Value::Int(Some(v)) => quote! { #v },
+        Value::BigInt(Some(v)) => quote! { #v },
+        Value::TinyUnsigned(Some(v)) => quote! { #v },
+        Value::SmallUnsigned(Some(v)) => quote! { #v },
+        Value::Unsigned(Some(v)) => quote! { #v },
+        Value::BigUnsigned(Some(v)) => quote! { #v },
+        Value::Float(Some(v)) if v.is_finite() => quote! { #v },
+        Value::Double(Some(v)) if v.is_finite() => quote! { #v },
+        Value::String(Some(v)) => {
+            let v = v.as_str();
+            quote! { #v }
+        }
+        _ => return None,
+    })
+}
+
+fn foreign_key_action(action: &ForeignKeyAction) -> TokenStream {
+    let action = debug_ident(action);
+    quote! { sea_query::ForeignKeyAction::#action }
+}
+
+/// Identifier of a unit variant, named as in its `Debug` output
+fn debug_ident<T: std::fmt::Debug>(variant: &T) -> Ident {
+    Ident::new(&format!("{:?}", variant), Span::call_site())
+}
+
+/// `PascalCase` identifier of a name, suffixed if it is reserved
+fn pascal_ident(name: &str, reserved: &[&str], suffix: &str) -> Ident {
+    let mut ident: String = name
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, 'N');
+    }
+    if reserved.contains(&ident.as_str()) {
+        ident.push_str(suffix);
+    }
+    Ident::new(&ident, Span::call_site())
+}
+
+/// The identifier itself, or suffixed with the first number making it distinct from `taken`.
+/// The suffixed identifier no longer derives its name, which is kept by [`rename_attr`].
+fn unique_ident(ident: Ident, taken: &[&Ident]) -> Ident {
+    if !taken.contains(&&ident) {
+        return ident;
+    }
+    (2..)
+        .map(|n| Ident::new(&format!("{}{}", ident, n), Span::call_site()))
+        .find(|ident| !taken.contains(&ident))
+        .expect("finitely many identifiers are taken")
+}
+
+/// `#[iden = "..."]` unless the derived `snake_case` name of the identifier is the name itself.
+/// Only names of lowercase words with at least two letters each are known to convert back unchanged.
+fn rename_attr(name: &str, ident: &Ident) -> TokenStream {
+    let round_trips = name
+        .split('_')
+        .all(|word| word.len() >= 2 && word.chars().all(|c| c.is_ascii_lowercase()))
+        && pascal_ident(name, &[], "") == *ident;
+    if round_trips {
+        quote! {}
+    } else {
+        quote! { #[iden = #name] }
+    }
+}
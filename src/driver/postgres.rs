@@ -92,3 +92,42 @@ impl<'a> PostgresDriver<'a> for Values {
             .collect()
     }
 }
+
+#[macro_export]
+macro_rules! sea_query_driver_postgres_client {
+    () => {
+        mod sea_query_driver_postgres_client {
+            use $crate::{migration::AppliedMigration, PostgresDriver, Values};
+
+            /// Runs [`sea_query::migration::Migrator`] on a postgres client
+            pub struct PostgresMigrationExecutor<'a>(pub &'a mut postgres::Client);
+
+            impl $crate::migration::MigrationExecutor for PostgresMigrationExecutor<'_> {
+                type Error = postgres::Error;
+
+                fn execute(&mut self, sql: &str, values: Values) -> Result<(), postgres::Error> {
+                    self.0.execute(sql, &values.as_params())?;
+                    Ok(())
+                }
+
+                fn query_history(
+                    &mut self,
+                    sql: &str,
+                    values: Values,
+                ) -> Result<Vec<AppliedMigration>, postgres::Error> {
+                    self.0
+                        .query(sql, &values.as_params())?
+                        .iter()
+                        .map(|row| {
+                            Ok(AppliedMigration {
+                                version: row.try_get(0)?,
+                                name: row.try_get(1)?,
+                                checksum: row.try_get(2)?,
+                            })
+                        })
+                        .collect()
+                }
+            }
+        }
+    };
+}
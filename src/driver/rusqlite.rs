@@ -87,6 +87,36 @@ macro_rules! sea_query_driver_rusqlite {
                     }
                 }
             }
+
+            /// Runs [`sea_query::migration::Migrator`] on a rusqlite connection
+            pub struct RusqliteMigrationExecutor<'a>(pub &'a rusqlite::Connection);
+
+            impl sea_query::migration::MigrationExecutor for RusqliteMigrationExecutor<'_> {
+                type Error = rusqlite::Error;
+
+                fn execute(&mut self, sql: &str, values: Values) -> Result<()> {
+                    let values = RusqliteValues::from(values);
+                    self.0.execute(sql, values.as_params().as_slice())?;
+                    Ok(())
+                }
+
+                fn query_history(
+                    &mut self,
+                    sql: &str,
+                    values: Values,
+                ) -> Result<Vec<sea_query::migration::AppliedMigration>> {
+                    let values = RusqliteValues::from(values);
+                    let mut stmt = self.0.prepare(sql)?;
+                    let rows = stmt.query_map(values.as_params().as_slice(), |row| {
+                        Ok(sea_query::migration::AppliedMigration {
+                            version: row.get(0)?,
+                            name: row.get(1)?,
+                            checksum: row.get(2)?,
+                        })
+                    })?;
+                    rows.collect()
+                }
+            }
         }
     };
 }
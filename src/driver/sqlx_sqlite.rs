@@ -82,6 +82,41 @@ macro_rules! sea_query_driver_sqlite {
             ) -> SqlxQueryAs<'a, T> {
                 $crate::bind_params_sqlx_sqlite!(query, params.0)
             }
+
+            /// Runs [`sea_query::migration::Migrator`] on a sqlx connection
+            pub struct SqlxSqliteMigrationExecutor<'a>(pub &'a mut sqlx::SqliteConnection);
+
+            impl $crate::migration::AsyncMigrationExecutor for SqlxSqliteMigrationExecutor<'_> {
+                type Error = sqlx::Error;
+
+                async fn execute(&mut self, sql: &str, values: Values) -> Result<(), sqlx::Error> {
+                    bind_query(sqlx::query(sql), &values)
+                        .execute(&mut *self.0)
+                        .await?;
+                    Ok(())
+                }
+
+                async fn query_history(
+                    &mut self,
+                    sql: &str,
+                    values: Values,
+                ) -> Result<Vec<$crate::migration::AppliedMigration>, sqlx::Error> {
+                    let rows: Vec<(i64, String, String)> =
+                        bind_query_as(sqlx::query_as(sql), &values)
+                            .fetch_all(&mut *self.0)
+                            .await?;
+                    Ok(rows
+                        .into_iter()
+                        .map(
+                            |(version, name, checksum)| $crate::migration::AppliedMigration {
+                                version,
+                                name,
+                                checksum,
+                            },
+                        )
+                        .collect())
+                }
+            }
         }
     };
 }
@@ -7,6 +7,10 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     /// Column and value vector having different length
     ColValNumMismatch { col_len: usize, val_len: usize },
+    /// Foreign keys referencing each other in a cycle, which the backend cannot defer
+    ForeignKeyCycle(Vec<String>),
+    /// Statement or feature which the database backend does not support
+    Unsupported(String),
 }
 
 impl std::error::Error for Error {}
@@ -19,6 +23,12 @@ impl std::fmt::Display for Error {
                 "Columns and values length mismatch: {} != {}",
                 col_len, val_len
             ),
+            Self::ForeignKeyCycle(tables) => write!(
+                f,
+                "Foreign keys form a cycle between tables: {}",
+                tables.join(", ")
+            ),
+            Self::Unsupported(message) => write!(f, "{}", message),
         }
     }
 }
@@ -8,6 +8,7 @@ use crate::{func::*, query::*, types::*, value::*};
 
 /// Helper to build a [`SimpleExpr`].
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Expr {
     pub(crate) left: Option<SimpleExpr>,
     pub(crate) right: Option<SimpleExpr>,
@@ -22,6 +23,7 @@ pub struct Expr {
 /// [`SimpleExpr`] is a node in the expression tree and can represent identifiers, function calls,
 /// various operators and sub-queries.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SimpleExpr {
     Column(ColumnRef),
     Tuple(Vec<SimpleExpr>),
@@ -490,6 +492,42 @@ impl Expr {
         SimpleExpr::CustomWithValues(s.to_owned(), v.into_iter().map(|v| v.into()).collect())
     }
 
+    /// Express the `CURRENT_TIMESTAMP` keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::*;
+    ///
+    /// let query = Query::select().expr(Expr::current_timestamp()).to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT CURRENT_TIMESTAMP"#
+    /// );
+    /// ```
+    pub fn current_timestamp() -> Self {
+        Self::new_with_left(SimpleExpr::Keyword(Keyword::CurrentTimestamp))
+    }
+
+    /// Express the `CURRENT_DATE` keyword.
+    pub fn current_date() -> Self {
+        Self::new_with_left(SimpleExpr::Keyword(Keyword::CurrentDate))
+    }
+
+    /// Express the `CURRENT_TIME` keyword.
+    pub fn current_time() -> Self {
+        Self::new_with_left(SimpleExpr::Keyword(Keyword::CurrentTime))
+    }
+
     /// Express an equal (`=`) expression.
     ///
     /// # Examples
@@ -1844,6 +1882,12 @@ impl From<Expr> for SimpleExpr {
     }
 }
 
+impl From<Value> for SimpleExpr {
+    fn from(v: Value) -> Self {
+        SimpleExpr::Value(v)
+    }
+}
+
 impl From<Expr> for SelectExpr {
     fn from(src: Expr) -> Self {
         src.into_simple_expr().into()
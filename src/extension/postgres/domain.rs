@@ -0,0 +1,268 @@
+use crate::{backend::QueryBuilder, expr::*, prepare::*, types::*, value::*, ColumnType};
+
+use super::TypeDropOpt;
+
+/// Helper for constructing any domain statement
+#[derive(Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Domain;
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DomainCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) as_type: Option<ColumnType>,
+    pub(crate) default: Option<SimpleExpr>,
+    pub(crate) constraints: Vec<DomainConstraint>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DomainDropStatement {
+    pub(crate) names: Vec<DynIden>,
+    pub(crate) option: Option<TypeDropOpt>,
+    pub(crate) if_exists: bool,
+}
+
+/// All available constraints of a domain
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DomainConstraint {
+    NotNull,
+    Null,
+    /// `[CONSTRAINT name] CHECK (expr)`
+    Check(Option<DynIden>, SimpleExpr),
+}
+
+pub trait DomainBuilder {
+    /// Translate [`DomainCreateStatement`] into database specific SQL statement.
+    fn prepare_domain_create_statement(
+        &self,
+        create: &DomainCreateStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    );
+
+    /// Translate [`DomainDropStatement`] into database specific SQL statement.
+    fn prepare_domain_drop_statement(
+        &self,
+        drop: &DomainDropStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    );
+}
+
+impl Domain {
+    /// Construct domain [`DomainCreateStatement`]
+    pub fn create() -> DomainCreateStatement {
+        DomainCreateStatement::new()
+    }
+
+    /// Construct domain [`DomainDropStatement`]
+    pub fn drop() -> DomainDropStatement {
+        DomainDropStatement::new()
+    }
+}
+
+impl DomainCreateStatement {
+    pub fn new() -> Self {
+        <Self as Default>::default()
+    }
+
+    /// Create a domain, a data type with constraints
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Domain, *};
+    ///
+    /// assert_eq!(
+    ///     Domain::create()
+    ///         .name(Alias::new("positive_int"))
+    ///         .as_type(ColumnType::Integer(None))
+    ///         .default(1)
+    ///         .not_null()
+    ///         .constraint(
+    ///             Alias::new("positive_int_check"),
+    ///             Expr::cust("VALUE > 0")
+    ///         )
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"CREATE DOMAIN "positive_int" AS integer DEFAULT 1 NOT NULL CONSTRAINT "positive_int_check" CHECK (VALUE > 0)"#
+    /// );
+    /// ```
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the underlying data type
+    pub fn as_type(&mut self, column_type: ColumnType) -> &mut Self {
+        self.as_type = Some(column_type);
+        self
+    }
+
+    /// Set the default value
+    pub fn default<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// Add `NOT NULL` constraint
+    pub fn not_null(&mut self) -> &mut Self {
+        self.constraints.push(DomainConstraint::NotNull);
+        self
+    }
+
+    /// Add `NULL` constraint
+    pub fn null(&mut self) -> &mut Self {
+        self.constraints.push(DomainConstraint::Null);
+        self
+    }
+
+    /// Add a `CHECK` constraint, referring to the checked value as `VALUE`
+    pub fn check(&mut self, condition: SimpleExpr) -> &mut Self {
+        self.constraints
+            .push(DomainConstraint::Check(None, condition));
+        self
+    }
+
+    /// Add a named `CHECK` constraint, referring to the checked value as `VALUE`
+    pub fn constraint<T>(&mut self, name: T, condition: SimpleExpr) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.constraints
+            .push(DomainConstraint::Check(Some(name.into_iden()), condition));
+        self
+    }
+
+    // below are boiler plates
+
+    pub fn build<T: DomainBuilder>(&self, domain_builder: T) -> (String, Vec<Value>) {
+        self.build_ref(&domain_builder)
+    }
+
+    pub fn build_ref<T: DomainBuilder>(&self, domain_builder: &T) -> (String, Vec<Value>) {
+        let mut params = Vec::new();
+        let mut collector = |v| params.push(v);
+        let sql = self.build_collect_ref(domain_builder, &mut collector);
+        (sql, params)
+    }
+
+    pub fn build_collect<T: DomainBuilder>(
+        &self,
+        domain_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        self.build_collect_ref(&domain_builder, collector)
+    }
+
+    pub fn build_collect_ref<T: DomainBuilder>(
+        &self,
+        domain_builder: &T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        domain_builder.prepare_domain_create_statement(self, &mut sql, collector);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T>(&self, domain_builder: T) -> String
+    where
+        T: DomainBuilder + QueryBuilder,
+    {
+        let (sql, values) = self.build_ref(&domain_builder);
+        inject_parameters(&sql, values, &domain_builder)
+    }
+}
+
+impl DomainDropStatement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop a domain
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Domain, *};
+    ///
+    /// assert_eq!(
+    ///     Domain::drop()
+    ///         .if_exists()
+    ///         .name(Alias::new("positive_int"))
+    ///         .cascade()
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"DROP DOMAIN IF EXISTS "positive_int" CASCADE"#
+    /// );
+    /// ```
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.names.push(name.into_iden());
+        self
+    }
+
+    /// Set `IF EXISTS`
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Set `CASCADE`
+    pub fn cascade(&mut self) -> &mut Self {
+        self.option = Some(TypeDropOpt::Cascade);
+        self
+    }
+
+    /// Set `RESTRICT`
+    pub fn restrict(&mut self) -> &mut Self {
+        self.option = Some(TypeDropOpt::Restrict);
+        self
+    }
+
+    // below are boiler plates
+
+    pub fn build<T: DomainBuilder>(&self, domain_builder: T) -> (String, Vec<Value>) {
+        self.build_ref(&domain_builder)
+    }
+
+    pub fn build_ref<T: DomainBuilder>(&self, domain_builder: &T) -> (String, Vec<Value>) {
+        let mut params = Vec::new();
+        let mut collector = |v| params.push(v);
+        let sql = self.build_collect_ref(domain_builder, &mut collector);
+        (sql, params)
+    }
+
+    pub fn build_collect<T: DomainBuilder>(
+        &self,
+        domain_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        self.build_collect_ref(&domain_builder, collector)
+    }
+
+    pub fn build_collect_ref<T: DomainBuilder>(
+        &self,
+        domain_builder: &T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        domain_builder.prepare_domain_drop_statement(self, &mut sql, collector);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T>(&self, domain_builder: T) -> String
+    where
+        T: DomainBuilder + QueryBuilder,
+    {
+        let (sql, values) = self.build_ref(&domain_builder);
+        inject_parameters(&sql, values, &domain_builder)
+    }
+}
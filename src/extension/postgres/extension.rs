@@ -0,0 +1,238 @@
+use crate::{backend::QueryBuilder, prepare::*, types::*, value::*};
+
+/// Helper for constructing any extension statement
+#[derive(Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Extension;
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtensionCreateStatement {
+    pub(crate) name: Option<String>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) schema: Option<DynIden>,
+    pub(crate) version: Option<String>,
+    pub(crate) cascade: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtensionDropStatement {
+    pub(crate) names: Vec<String>,
+    pub(crate) if_exists: bool,
+    pub(crate) option: Option<ExtensionDropOpt>,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExtensionDropOpt {
+    Cascade,
+    Restrict,
+}
+
+pub trait ExtensionBuilder {
+    /// Translate [`ExtensionCreateStatement`] into database specific SQL statement.
+    fn prepare_extension_create_statement(
+        &self,
+        create: &ExtensionCreateStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    );
+
+    /// Translate [`ExtensionDropStatement`] into database specific SQL statement.
+    fn prepare_extension_drop_statement(
+        &self,
+        drop: &ExtensionDropStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    );
+}
+
+impl Extension {
+    /// Construct extension [`ExtensionCreateStatement`]
+    pub fn create() -> ExtensionCreateStatement {
+        ExtensionCreateStatement::new()
+    }
+
+    /// Construct extension [`ExtensionDropStatement`]
+    pub fn drop() -> ExtensionDropStatement {
+        ExtensionDropStatement::new()
+    }
+}
+
+impl ExtensionCreateStatement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Install an extension
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Extension, *};
+    ///
+    /// assert_eq!(
+    ///     Extension::create()
+    ///         .name("pgcrypto")
+    ///         .if_not_exists()
+    ///         .schema(Alias::new("public"))
+    ///         .version("1.3")
+    ///         .cascade()
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"CREATE EXTENSION IF NOT EXISTS "pgcrypto" SCHEMA "public" VERSION '1.3' CASCADE"#
+    /// );
+    /// ```
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_owned());
+        self
+    }
+
+    /// Set `IF NOT EXISTS`
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set the schema to install the extension objects in
+    pub fn schema<T>(&mut self, schema: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.schema = Some(schema.into_iden());
+        self
+    }
+
+    /// Set the version to install
+    pub fn version(&mut self, version: &str) -> &mut Self {
+        self.version = Some(version.to_owned());
+        self
+    }
+
+    /// Also install the extensions it depends on
+    pub fn cascade(&mut self) -> &mut Self {
+        self.cascade = true;
+        self
+    }
+
+    // below are boiler plates
+
+    pub fn build<T: ExtensionBuilder>(&self, extension_builder: T) -> (String, Vec<Value>) {
+        self.build_ref(&extension_builder)
+    }
+
+    pub fn build_ref<T: ExtensionBuilder>(&self, extension_builder: &T) -> (String, Vec<Value>) {
+        let mut params = Vec::new();
+        let mut collector = |v| params.push(v);
+        let sql = self.build_collect_ref(extension_builder, &mut collector);
+        (sql, params)
+    }
+
+    pub fn build_collect<T: ExtensionBuilder>(
+        &self,
+        extension_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        self.build_collect_ref(&extension_builder, collector)
+    }
+
+    pub fn build_collect_ref<T: ExtensionBuilder>(
+        &self,
+        extension_builder: &T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        extension_builder.prepare_extension_create_statement(self, &mut sql, collector);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T>(&self, extension_builder: T) -> String
+    where
+        T: ExtensionBuilder + QueryBuilder,
+    {
+        let (sql, values) = self.build_ref(&extension_builder);
+        inject_parameters(&sql, values, &extension_builder)
+    }
+}
+
+impl ExtensionDropStatement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remove an extension
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Extension, *};
+    ///
+    /// assert_eq!(
+    ///     Extension::drop()
+    ///         .if_exists()
+    ///         .name("citext")
+    ///         .restrict()
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"DROP EXTENSION IF EXISTS "citext" RESTRICT"#
+    /// );
+    /// ```
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.names.push(name.to_owned());
+        self
+    }
+
+    /// Set `IF EXISTS`
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Set `CASCADE`
+    pub fn cascade(&mut self) -> &mut Self {
+        self.option = Some(ExtensionDropOpt::Cascade);
+        self
+    }
+
+    /// Set `RESTRICT`
+    pub fn restrict(&mut self) -> &mut Self {
+        self.option = Some(ExtensionDropOpt::Restrict);
+        self
+    }
+
+    // below are boiler plates
+
+    pub fn build<T: ExtensionBuilder>(&self, extension_builder: T) -> (String, Vec<Value>) {
+        self.build_ref(&extension_builder)
+    }
+
+    pub fn build_ref<T: ExtensionBuilder>(&self, extension_builder: &T) -> (String, Vec<Value>) {
+        let mut params = Vec::new();
+        let mut collector = |v| params.push(v);
+        let sql = self.build_collect_ref(extension_builder, &mut collector);
+        (sql, params)
+    }
+
+    pub fn build_collect<T: ExtensionBuilder>(
+        &self,
+        extension_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        self.build_collect_ref(&extension_builder, collector)
+    }
+
+    pub fn build_collect_ref<T: ExtensionBuilder>(
+        &self,
+        extension_builder: &T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        extension_builder.prepare_extension_drop_statement(self, &mut sql, collector);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T>(&self, extension_builder: T) -> String
+    where
+        T: ExtensionBuilder + QueryBuilder,
+    {
+        let (sql, values) = self.build_ref(&extension_builder);
+        inject_parameters(&sql, values, &extension_builder)
+    }
+}
@@ -4,6 +4,7 @@ use crate::{expr::*, func::Function};
 
 /// Functions
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PgFunction {
     ToTsquery,
     ToTsvector,
@@ -12,10 +13,14 @@ pub enum PgFunction {
     WebsearchToTsquery,
     TsRank,
     TsRankCd,
+    Nextval,
+    Currval,
+    Setval,
 }
 
 /// Function call helper.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PgFunc;
 
 impl PgFunc {
@@ -0,0 +1,144 @@
+use crate::{backend::QueryBuilder, prepare::*, types::*, value::*};
+
+/// Create a function, e.g. to be executed by a trigger
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{extension::postgres::FunctionCreateStatement, *};
+///
+/// assert_eq!(
+///     FunctionCreateStatement::new()
+///         .or_replace()
+///         .name(Alias::new("touch_glyph"))
+///         .returns("trigger")
+///         .language("plpgsql")
+///         .body("BEGIN NEW.updated_at = now(); RETURN NEW; END;")
+///         .to_string(PostgresQueryBuilder),
+///     r#"CREATE OR REPLACE FUNCTION "touch_glyph"() RETURNS trigger LANGUAGE plpgsql AS $$BEGIN NEW.updated_at = now(); RETURN NEW; END;$$"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FunctionCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) or_replace: bool,
+    pub(crate) args: Vec<(DynIden, String)>,
+    pub(crate) returns: Option<String>,
+    pub(crate) language: Option<String>,
+    pub(crate) body: Option<String>,
+}
+
+pub trait FunctionBuilder {
+    /// Translate [`FunctionCreateStatement`] into database specific SQL statement.
+    fn prepare_function_create_statement(
+        &self,
+        create: &FunctionCreateStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    );
+}
+
+impl FunctionCreateStatement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set function name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set `OR REPLACE`
+    pub fn or_replace(&mut self) -> &mut Self {
+        self.or_replace = true;
+        self
+    }
+
+    /// Add an argument with its SQL type
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::FunctionCreateStatement, *};
+    ///
+    /// assert_eq!(
+    ///     FunctionCreateStatement::new()
+    ///         .name(Alias::new("add"))
+    ///         .arg(Alias::new("a"), "integer")
+    ///         .arg(Alias::new("b"), "integer")
+    ///         .returns("integer")
+    ///         .language("sql")
+    ///         .body("SELECT a + b")
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"CREATE FUNCTION "add"("a" integer, "b" integer) RETURNS integer LANGUAGE sql AS $$SELECT a + b$$"#
+    /// );
+    /// ```
+    pub fn arg<T>(&mut self, name: T, data_type: &str) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.args.push((name.into_iden(), data_type.to_owned()));
+        self
+    }
+
+    /// Set the return type, e.g. `trigger`
+    pub fn returns(&mut self, data_type: &str) -> &mut Self {
+        self.returns = Some(data_type.to_owned());
+        self
+    }
+
+    /// Set the language of function body, e.g. `plpgsql`
+    pub fn language(&mut self, language: &str) -> &mut Self {
+        self.language = Some(language.to_owned());
+        self
+    }
+
+    /// Set the function body, written in a dollar-quoted string
+    pub fn body(&mut self, body: &str) -> &mut Self {
+        self.body = Some(body.to_owned());
+        self
+    }
+
+    // below are boiler plates
+
+    pub fn build<T: FunctionBuilder>(&self, function_builder: T) -> (String, Vec<Value>) {
+        self.build_ref(&function_builder)
+    }
+
+    pub fn build_ref<T: FunctionBuilder>(&self, function_builder: &T) -> (String, Vec<Value>) {
+        let mut params = Vec::new();
+        let mut collector = |v| params.push(v);
+        let sql = self.build_collect_ref(function_builder, &mut collector);
+        (sql, params)
+    }
+
+    pub fn build_collect<T: FunctionBuilder>(
+        &self,
+        function_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        self.build_collect_ref(&function_builder, collector)
+    }
+
+    pub fn build_collect_ref<T: FunctionBuilder>(
+        &self,
+        function_builder: &T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        function_builder.prepare_function_create_statement(self, &mut sql, collector);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T>(&self, function_builder: T) -> String
+    where
+        T: FunctionBuilder + QueryBuilder,
+    {
+        let (sql, values) = self.build_ref(&function_builder);
+        inject_parameters(&sql, values, &function_builder)
+    }
+}
@@ -1,7 +1,15 @@
+pub(crate) mod domain;
+pub(crate) mod extension;
 pub(crate) mod func;
+pub(crate) mod function;
 pub(crate) mod interval;
+pub(crate) mod sequence;
 pub(crate) mod types;
 
+pub use domain::*;
+pub use extension::*;
 pub use func::*;
+pub use function::*;
 pub use interval::*;
+pub use sequence::*;
 pub use types::*;
@@ -0,0 +1,468 @@
+use crate::{backend::QueryBuilder, prepare::*, types::*, value::*};
+
+/// Helper for constructing any sequence statement
+#[derive(Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sequence;
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) options: Vec<SequenceOption>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceAlterStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_exists: bool,
+    pub(crate) options: Vec<SequenceOption>,
+    pub(crate) rename_to: Option<DynIden>,
+}
+
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SequenceDropStatement {
+    pub(crate) names: Vec<DynIden>,
+    pub(crate) option: Option<SequenceDropOpt>,
+    pub(crate) if_exists: bool,
+}
+
+/// All available options of a sequence definition
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SequenceOption {
+    IncrementBy(i64),
+    /// `None` renders `NO MINVALUE`
+    MinValue(Option<i64>),
+    /// `None` renders `NO MAXVALUE`
+    MaxValue(Option<i64>),
+    StartWith(i64),
+    /// `None` restarts from the start value, only in [`SequenceAlterStatement`]
+    Restart(Option<i64>),
+    Cache(i64),
+    /// `false` renders `NO CYCLE`
+    Cycle(bool),
+    /// `None` renders `OWNED BY NONE`
+    OwnedBy(Option<(DynIden, DynIden)>),
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SequenceDropOpt {
+    Cascade,
+    Restrict,
+}
+
+pub trait SequenceBuilder {
+    /// Translate [`SequenceCreateStatement`] into database specific SQL statement.
+    fn prepare_sequence_create_statement(
+        &self,
+        create: &SequenceCreateStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    );
+
+    /// Translate [`SequenceAlterStatement`] into database specific SQL statement.
+    fn prepare_sequence_alter_statement(
+        &self,
+        alter: &SequenceAlterStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    );
+
+    /// Translate [`SequenceDropStatement`] into database specific SQL statement.
+    fn prepare_sequence_drop_statement(
+        &self,
+        drop: &SequenceDropStatement,
+        sql: &mut SqlWriter,
+        collector: &mut dyn FnMut(Value),
+    );
+}
+
+impl Sequence {
+    /// Construct sequence [`SequenceCreateStatement`]
+    pub fn create() -> SequenceCreateStatement {
+        SequenceCreateStatement::new()
+    }
+
+    /// Construct sequence [`SequenceAlterStatement`]
+    pub fn alter() -> SequenceAlterStatement {
+        SequenceAlterStatement::new()
+    }
+
+    /// Construct sequence [`SequenceDropStatement`]
+    pub fn drop() -> SequenceDropStatement {
+        SequenceDropStatement::new()
+    }
+}
+
+macro_rules! impl_sequence_options {
+    ( $struct_name: ident ) => {
+        impl $struct_name {
+            /// Set `INCREMENT BY`
+            pub fn increment_by(&mut self, increment: i64) -> &mut Self {
+                self.options.push(SequenceOption::IncrementBy(increment));
+                self
+            }
+
+            /// Set `MINVALUE`
+            pub fn min_value(&mut self, value: i64) -> &mut Self {
+                self.options.push(SequenceOption::MinValue(Some(value)));
+                self
+            }
+
+            /// Set `NO MINVALUE`
+            pub fn no_min_value(&mut self) -> &mut Self {
+                self.options.push(SequenceOption::MinValue(None));
+                self
+            }
+
+            /// Set `MAXVALUE`
+            pub fn max_value(&mut self, value: i64) -> &mut Self {
+                self.options.push(SequenceOption::MaxValue(Some(value)));
+                self
+            }
+
+            /// Set `NO MAXVALUE`
+            pub fn no_max_value(&mut self) -> &mut Self {
+                self.options.push(SequenceOption::MaxValue(None));
+                self
+            }
+
+            /// Set `START WITH`
+            pub fn start_with(&mut self, start: i64) -> &mut Self {
+                self.options.push(SequenceOption::StartWith(start));
+                self
+            }
+
+            /// Set `CACHE`
+            pub fn cache(&mut self, cache: i64) -> &mut Self {
+                self.options.push(SequenceOption::Cache(cache));
+                self
+            }
+
+            /// Set `CYCLE`
+            pub fn cycle(&mut self) -> &mut Self {
+                self.options.push(SequenceOption::Cycle(true));
+                self
+            }
+
+            /// Set `NO CYCLE`
+            pub fn no_cycle(&mut self) -> &mut Self {
+                self.options.push(SequenceOption::Cycle(false));
+                self
+            }
+
+            /// Set `OWNED BY table.column`
+            pub fn owned_by<T, C>(&mut self, table: T, column: C) -> &mut Self
+            where
+                T: IntoIden,
+                C: IntoIden,
+            {
+                self.options.push(SequenceOption::OwnedBy(Some((
+                    table.into_iden(),
+                    column.into_iden(),
+                ))));
+                self
+            }
+
+            /// Set `OWNED BY NONE`
+            pub fn owned_by_none(&mut self) -> &mut Self {
+                self.options.push(SequenceOption::OwnedBy(None));
+                self
+            }
+        }
+    };
+}
+
+impl_sequence_options!(SequenceCreateStatement);
+impl_sequence_options!(SequenceAlterStatement);
+
+impl SequenceCreateStatement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a sequence
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Sequence, tests_cfg::*, *};
+    ///
+    /// assert_eq!(
+    ///     Sequence::create()
+    ///         .name(Alias::new("font_id_seq"))
+    ///         .if_not_exists()
+    ///         .increment_by(2)
+    ///         .min_value(1)
+    ///         .no_max_value()
+    ///         .start_with(10)
+    ///         .cache(5)
+    ///         .no_cycle()
+    ///         .owned_by(Font::Table, Font::Id)
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"CREATE SEQUENCE IF NOT EXISTS "font_id_seq" INCREMENT BY 2 MINVALUE 1 NO MAXVALUE START WITH 10 CACHE 5 NO CYCLE OWNED BY "font"."id""#
+    /// );
+    /// ```
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set `IF NOT EXISTS`
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    // below are boiler plates
+
+    pub fn build<T: SequenceBuilder>(&self, sequence_builder: T) -> (String, Vec<Value>) {
+        self.build_ref(&sequence_builder)
+    }
+
+    pub fn build_ref<T: SequenceBuilder>(&self, sequence_builder: &T) -> (String, Vec<Value>) {
+        let mut params = Vec::new();
+        let mut collector = |v| params.push(v);
+        let sql = self.build_collect_ref(sequence_builder, &mut collector);
+        (sql, params)
+    }
+
+    pub fn build_collect<T: SequenceBuilder>(
+        &self,
+        sequence_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        self.build_collect_ref(&sequence_builder, collector)
+    }
+
+    pub fn build_collect_ref<T: SequenceBuilder>(
+        &self,
+        sequence_builder: &T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        sequence_builder.prepare_sequence_create_statement(self, &mut sql, collector);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T>(&self, sequence_builder: T) -> String
+    where
+        T: SequenceBuilder + QueryBuilder,
+    {
+        let (sql, values) = self.build_ref(&sequence_builder);
+        inject_parameters(&sql, values, &sequence_builder)
+    }
+}
+
+impl SequenceAlterStatement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Change the definition of a sequence
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Sequence, *};
+    ///
+    /// assert_eq!(
+    ///     Sequence::alter()
+    ///         .name(Alias::new("font_id_seq"))
+    ///         .increment_by(1)
+    ///         .restart_with(100)
+    ///         .cycle()
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"ALTER SEQUENCE "font_id_seq" INCREMENT BY 1 RESTART WITH 100 CYCLE"#
+    /// );
+    /// ```
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set `IF EXISTS`
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Set `RESTART`, restarting from the start value
+    pub fn restart(&mut self) -> &mut Self {
+        self.options.push(SequenceOption::Restart(None));
+        self
+    }
+
+    /// Set `RESTART WITH`
+    pub fn restart_with(&mut self, value: i64) -> &mut Self {
+        self.options.push(SequenceOption::Restart(Some(value)));
+        self
+    }
+
+    /// Rename the sequence, any other option is ignored
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Sequence, *};
+    ///
+    /// assert_eq!(
+    ///     Sequence::alter()
+    ///         .name(Alias::new("font_id_seq"))
+    ///         .if_exists()
+    ///         .rename_to(Alias::new("font_seq"))
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"ALTER SEQUENCE IF EXISTS "font_id_seq" RENAME TO "font_seq""#
+    /// );
+    /// ```
+    pub fn rename_to<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.rename_to = Some(name.into_iden());
+        self
+    }
+
+    // below are boiler plates
+
+    pub fn build<T: SequenceBuilder>(&self, sequence_builder: T) -> (String, Vec<Value>) {
+        self.build_ref(&sequence_builder)
+    }
+
+    pub fn build_ref<T: SequenceBuilder>(&self, sequence_builder: &T) -> (String, Vec<Value>) {
+        let mut params = Vec::new();
+        let mut collector = |v| params.push(v);
+        let sql = self.build_collect_ref(sequence_builder, &mut collector);
+        (sql, params)
+    }
+
+    pub fn build_collect<T: SequenceBuilder>(
+        &self,
+        sequence_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        self.build_collect_ref(&sequence_builder, collector)
+    }
+
+    pub fn build_collect_ref<T: SequenceBuilder>(
+        &self,
+        sequence_builder: &T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        sequence_builder.prepare_sequence_alter_statement(self, &mut sql, collector);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T>(&self, sequence_builder: T) -> String
+    where
+        T: SequenceBuilder + QueryBuilder,
+    {
+        let (sql, values) = self.build_ref(&sequence_builder);
+        inject_parameters(&sql, values, &sequence_builder)
+    }
+}
+
+impl SequenceDropStatement {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop a sequence
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Sequence, *};
+    ///
+    /// assert_eq!(
+    ///     Sequence::drop()
+    ///         .if_exists()
+    ///         .name(Alias::new("font_id_seq"))
+    ///         .cascade()
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"DROP SEQUENCE IF EXISTS "font_id_seq" CASCADE"#
+    /// );
+    /// ```
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.names.push(name.into_iden());
+        self
+    }
+
+    pub fn names<T, I>(&mut self, names: I) -> &mut Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        for n in names.into_iter() {
+            self.names.push(n.into_iden());
+        }
+        self
+    }
+
+    /// Set `IF EXISTS`
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Set `CASCADE`
+    pub fn cascade(&mut self) -> &mut Self {
+        self.option = Some(SequenceDropOpt::Cascade);
+        self
+    }
+
+    /// Set `RESTRICT`
+    pub fn restrict(&mut self) -> &mut Self {
+        self.option = Some(SequenceDropOpt::Restrict);
+        self
+    }
+
+    // below are boiler plates
+
+    pub fn build<T: SequenceBuilder>(&self, sequence_builder: T) -> (String, Vec<Value>) {
+        self.build_ref(&sequence_builder)
+    }
+
+    pub fn build_ref<T: SequenceBuilder>(&self, sequence_builder: &T) -> (String, Vec<Value>) {
+        let mut params = Vec::new();
+        let mut collector = |v| params.push(v);
+        let sql = self.build_collect_ref(sequence_builder, &mut collector);
+        (sql, params)
+    }
+
+    pub fn build_collect<T: SequenceBuilder>(
+        &self,
+        sequence_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        self.build_collect_ref(&sequence_builder, collector)
+    }
+
+    pub fn build_collect_ref<T: SequenceBuilder>(
+        &self,
+        sequence_builder: &T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        sequence_builder.prepare_sequence_drop_statement(self, &mut sql, collector);
+        sql.result()
+    }
+
+    /// Build corresponding SQL statement and return SQL string
+    pub fn to_string<T>(&self, sequence_builder: T) -> String
+    where
+        T: SequenceBuilder + QueryBuilder,
+    {
+        let (sql, values) = self.build_ref(&sequence_builder);
+        inject_parameters(&sql, values, &sequence_builder)
+    }
+}
@@ -1,26 +1,33 @@
-use crate::{backend::QueryBuilder, prepare::*, types::*, value::*};
+use crate::{backend::QueryBuilder, prepare::*, types::*, value::*, ColumnType};
 
 /// Helper for constructing any type statement
 #[derive(Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Type;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeCreateStatement {
     pub(crate) name: Option<DynIden>,
     pub(crate) as_type: Option<TypeAs>,
     pub(crate) values: Vec<DynIden>,
+    pub(crate) fields: Vec<(DynIden, ColumnType)>,
+    pub(crate) subtype: Option<ColumnType>,
+    pub(crate) subtype_diff: Option<DynIden>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TypeAs {
-    // Composite,
+    Composite,
     Enum,
-    /* Range,
-     * Base,
+    Range,
+    /* Base,
      * Array, */
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeDropStatement {
     pub(crate) names: Vec<DynIden>,
     pub(crate) option: Option<TypeDropOpt>,
@@ -28,18 +35,21 @@ pub struct TypeDropStatement {
 }
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypeAlterStatement {
     pub(crate) name: Option<DynIden>,
     pub(crate) option: Option<TypeAlterOpt>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TypeDropOpt {
     Cascade,
     Restrict,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TypeAlterOpt {
     Add(DynIden, Option<TypeAlterAddOpt>),
     Rename(DynIden),
@@ -47,6 +57,7 @@ pub enum TypeAlterOpt {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TypeAlterAddOpt {
     Before(DynIden),
     After(DynIden),
@@ -93,6 +104,59 @@ impl Type {
     pub fn alter() -> TypeAlterStatement {
         TypeAlterStatement::new()
     }
+
+    /// Construct the [`TypeAlterStatement`]s adding the values missing from an existing enum,
+    /// each placed after the value preceding it.
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Type, *};
+    ///
+    /// let alters = Type::add_enum_values(
+    ///     Alias::new("font_family"),
+    ///     ["sans", "monospace"],
+    ///     ["serif", "sans", "cursive", "monospace"],
+    /// );
+    ///
+    /// assert_eq!(
+    ///     alters
+    ///         .iter()
+    ///         .map(|alter| alter.to_string(PostgresQueryBuilder))
+    ///         .collect::<Vec<_>>(),
+    ///     vec![
+    ///         r#"ALTER TYPE "font_family" ADD VALUE 'serif' BEFORE 'sans'"#,
+    ///         r#"ALTER TYPE "font_family" ADD VALUE 'cursive' AFTER 'sans'"#,
+    ///     ]
+    /// );
+    /// ```
+    pub fn add_enum_values<T, S, E, V>(name: T, existing: E, values: V) -> Vec<TypeAlterStatement>
+    where
+        T: IntoIden,
+        S: ToString,
+        E: IntoIterator<Item = S>,
+        V: IntoIterator<Item = S>,
+    {
+        let name = name.into_iden();
+        let existing: Vec<String> = existing.into_iter().map(|v| v.to_string()).collect();
+        let values: Vec<String> = values.into_iter().map(|v| v.to_string()).collect();
+        values
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| !existing.contains(value))
+            .map(|(i, value)| {
+                let alter = Self::alter()
+                    .name(name.clone())
+                    .add_value(Alias::new(value));
+                match i.checked_sub(1) {
+                    Some(prev) => alter.after(Alias::new(&values[prev])),
+                    // A new first value goes before the first value already there
+                    None => match values.iter().find(|value| existing.contains(value)) {
+                        Some(next) => alter.before(Alias::new(next)),
+                        None => alter,
+                    },
+                }
+            })
+            .collect()
+    }
 }
 
 impl TypeCreateStatement {
@@ -160,6 +224,76 @@ impl TypeCreateStatement {
         self
     }
 
+    /// Create composite type as custom type
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Type, *};
+    ///
+    /// assert_eq!(
+    ///     Type::create()
+    ///         .as_composite(Alias::new("address"))
+    ///         .field(Alias::new("street"), ColumnType::Text)
+    ///         .field(Alias::new("zip"), ColumnType::String(Some(10)))
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TYPE "address" AS ("street" text, "zip" varchar(10))"#
+    /// );
+    /// ```
+    pub fn as_composite<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self.as_type = Some(TypeAs::Composite);
+        self
+    }
+
+    /// Add a field to composite type
+    pub fn field<T>(&mut self, name: T, column_type: ColumnType) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.fields.push((name.into_iden(), column_type));
+        self
+    }
+
+    /// Create range type as custom type
+    ///
+    /// ```
+    /// use sea_query::{extension::postgres::Type, *};
+    ///
+    /// assert_eq!(
+    ///     Type::create()
+    ///         .as_range(Alias::new("float_range"))
+    ///         .subtype(ColumnType::Double(None))
+    ///         .subtype_diff(Alias::new("float8mi"))
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TYPE "float_range" AS RANGE (SUBTYPE = double precision, SUBTYPE_DIFF = "float8mi")"#
+    /// );
+    /// ```
+    pub fn as_range<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self.as_type = Some(TypeAs::Range);
+        self
+    }
+
+    /// Set the element type of range type
+    pub fn subtype(&mut self, column_type: ColumnType) -> &mut Self {
+        self.subtype = Some(column_type);
+        self
+    }
+
+    /// Set the function computing the difference of two subtype values
+    pub fn subtype_diff<T>(&mut self, function: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.subtype_diff = Some(function.into_iden());
+        self
+    }
+
     // below are boiler plates
 
     pub fn build<T: TypeBuilder>(&self, type_builder: T) -> (String, Vec<Value>) {
@@ -2,6 +2,7 @@ use crate::types::*;
 
 /// Specification of a foreign key
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableForeignKey {
     pub(crate) name: Option<String>,
     pub(crate) table: Option<DynIden>,
@@ -14,6 +15,7 @@ pub struct TableForeignKey {
 
 /// Foreign key on update & on delete actions
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ForeignKeyAction {
     Restrict,
     Cascade,
@@ -1,6 +1,6 @@
 use crate::{
-    backend::SchemaBuilder, prepare::*, types::*, ForeignKeyAction, SchemaStatementBuilder,
-    TableForeignKey,
+    backend::SchemaBuilder, error::Result, prepare::*, types::*, ForeignKeyAction,
+    SchemaStatementBuilder, TableForeignKey,
 };
 
 /// Create a foreign key constraint for an existing table. Unsupported by Sqlite
@@ -72,6 +72,7 @@ use crate::{
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForeignKeyCreateStatement {
     pub(crate) foreign_key: TableForeignKey,
 }
@@ -223,4 +224,8 @@ impl SchemaStatementBuilder for ForeignKeyCreateStatement {
         schema_builder.prepare_foreign_key_create_statement(self, &mut sql);
         sql.result()
     }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_foreign_key_create_statement(self)
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
-    backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder, TableForeignKey,
+    backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder,
+    TableForeignKey,
 };
 
 /// Drop a foreign key constraint for an existing table
@@ -25,6 +26,7 @@ use crate::{
 /// // Sqlite does not support modification of foreign key constraints to existing tables
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForeignKeyDropStatement {
     pub(crate) foreign_key: TableForeignKey,
     pub(crate) table: Option<DynIden>,
@@ -73,4 +75,8 @@ impl SchemaStatementBuilder for ForeignKeyDropStatement {
         schema_builder.prepare_foreign_key_drop_statement(self, &mut sql);
         sql.result()
     }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_foreign_key_drop_statement(self)
+    }
 }
@@ -14,12 +14,16 @@ pub use common::*;
 pub use create::*;
 pub use drop::*;
 
+use crate::{backend::SchemaBuilder, error::Result, SchemaStatementBuilder};
+
 /// Shorthand for constructing any foreign key statement
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ForeignKey;
 
 /// All available types of foreign key statement
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ForeignKeyStatement {
     Create(ForeignKeyCreateStatement),
     Drop(ForeignKeyDropStatement),
@@ -36,3 +40,37 @@ impl ForeignKey {
         ForeignKeyDropStatement::new()
     }
 }
+
+impl ForeignKeyStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: SchemaBuilder>(&self, foreign_key_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.build(foreign_key_builder),
+            Self::Drop(stat) => stat.build(foreign_key_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, foreign_key_builder: &dyn SchemaBuilder) -> String {
+        match self {
+            Self::Create(stat) => stat.build_any(foreign_key_builder),
+            Self::Drop(stat) => stat.build_any(foreign_key_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: SchemaBuilder>(&self, foreign_key_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.to_string(foreign_key_builder),
+            Self::Drop(stat) => stat.to_string(foreign_key_builder),
+        }
+    }
+
+    /// Check that the database backend supports the statement, see [`SchemaStatementBuilder::check`]
+    pub fn check(&self, foreign_key_builder: &dyn SchemaBuilder) -> Result<()> {
+        match self {
+            Self::Create(stat) => stat.check(foreign_key_builder),
+            Self::Drop(stat) => stat.check(foreign_key_builder),
+        }
+    }
+}
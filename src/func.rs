@@ -7,6 +7,7 @@ pub use crate::extension::postgres::{PgFunc, PgFunction};
 
 /// Functions
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Function {
     Max,
     Min,
@@ -23,6 +24,7 @@ pub enum Function {
 
 /// Function call helper.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Func;
 
 impl Func {
@@ -327,4 +329,94 @@ impl Func {
             Expr::cust(iden.into_iden().to_string().as_str()),
         ))
     }
+
+    /// Call `NEXTVAL` function, advancing a sequence. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Font::Table)
+    ///     .columns(vec![Font::Id, Font::Name])
+    ///     .exprs_panic(vec![Func::nextval(Alias::new("font_id_seq")), "Arial".into()])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"INSERT INTO "font" ("id", "name") VALUES (NEXTVAL('"font_id_seq"'), 'Arial')"#
+    /// );
+    /// ```
+    #[cfg(feature = "backend-postgres")]
+    pub fn nextval<T>(sequence: T) -> SimpleExpr
+    where
+        T: IntoIden,
+    {
+        Expr::func(Function::PgFunction(PgFunction::Nextval)).arg(sequence_name(sequence))
+    }
+
+    /// Call `CURRVAL` function, reading the last value of a sequence in this session. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::currval(Alias::new("font_id_seq")))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT CURRVAL('"font_id_seq"')"#
+    /// );
+    /// ```
+    #[cfg(feature = "backend-postgres")]
+    pub fn currval<T>(sequence: T) -> SimpleExpr
+    where
+        T: IntoIden,
+    {
+        Expr::func(Function::PgFunction(PgFunction::Currval)).arg(sequence_name(sequence))
+    }
+
+    /// Call `SETVAL` function, setting the current value of a sequence. Postgres only.
+    ///
+    /// If `is_called` is `Some(false)`, the next `NEXTVAL` returns `value` itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::setval(Alias::new("font_id_seq"), 42, Some(false)))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT SETVAL('"font_id_seq"', 42, FALSE)"#
+    /// );
+    /// ```
+    #[cfg(feature = "backend-postgres")]
+    pub fn setval<T>(sequence: T, value: i64, is_called: Option<bool>) -> SimpleExpr
+    where
+        T: IntoIden,
+    {
+        let mut args: Vec<SimpleExpr> = vec![sequence_name(sequence), Expr::val(value).into()];
+        if let Some(is_called) = is_called {
+            args.push(Expr::val(is_called).into());
+        }
+        Expr::func(Function::PgFunction(PgFunction::Setval)).args(args)
+    }
+}
+
+/// The sequence name as a string literal holding the quoted identifier, so that its case is kept
+#[cfg(feature = "backend-postgres")]
+fn sequence_name<T>(sequence: T) -> SimpleExpr
+where
+    T: IntoIden,
+{
+    let name = sequence.into_iden().quoted('"').replace('\'', "''");
+    Expr::cust(&format!("'\"{}\"'", name))
 }
@@ -1,20 +1,27 @@
-use crate::types::*;
+use crate::{expr::*, types::*};
 
 /// Specification of a table index
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableIndex {
     pub(crate) name: Option<String>,
     pub(crate) columns: Vec<IndexColumn>,
 }
 
+/// Specification of an index column, either a table column or an expression
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndexColumn {
     pub(crate) name: DynIden,
+    pub(crate) expr: Option<SimpleExpr>,
     pub(crate) prefix: Option<u32>,
     pub(crate) order: Option<IndexOrder>,
+    pub(crate) collate: Option<String>,
+    pub(crate) nulls: Option<NullOrdering>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IndexOrder {
     Asc,
     Desc,
@@ -41,11 +48,7 @@ where
     I: IntoIden,
 {
     fn into_index_column(self) -> IndexColumn {
-        IndexColumn {
-            name: self.into_iden(),
-            prefix: None,
-            order: None,
-        }
+        IndexColumn::new(self)
     }
 }
 
@@ -54,11 +57,7 @@ where
     I: IntoIden,
 {
     fn into_index_column(self) -> IndexColumn {
-        IndexColumn {
-            name: self.0.into_iden(),
-            prefix: Some(self.1),
-            order: None,
-        }
+        IndexColumn::new(self.0).prefix(self.1)
     }
 }
 
@@ -67,11 +66,7 @@ where
     I: IntoIden,
 {
     fn into_index_column(self) -> IndexColumn {
-        IndexColumn {
-            name: self.0.into_iden(),
-            prefix: None,
-            order: Some(self.1),
-        }
+        IndexColumn::new(self.0).order(self.1)
     }
 }
 
@@ -80,12 +75,129 @@ where
     I: IntoIden,
 {
     fn into_index_column(self) -> IndexColumn {
-        IndexColumn {
-            name: self.0.into_iden(),
-            prefix: Some(self.1),
-            order: Some(self.2),
+        IndexColumn::new(self.0).prefix(self.1).order(self.2)
+    }
+}
+
+impl IndexColumn {
+    /// Construct an index column of a table column
+    pub fn new<T>(name: T) -> Self
+    where
+        T: IntoIden,
+    {
+        Self {
+            name: name.into_iden(),
+            expr: None,
+            prefix: None,
+            order: None,
+            collate: None,
+            nulls: None,
         }
     }
+
+    /// Construct an index column of an expression
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::create()
+    ///     .name("idx-font-name")
+    ///     .table(Font::Table)
+    ///     .col(IndexColumn::expr(
+    ///         Func::cust(Alias::new("lower")).arg(Expr::col(Font::Name)),
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE INDEX `idx-font-name` ON `font` ((lower(`name`)))"#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE INDEX "idx-font-name" ON "font" ((lower("name")))"#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE INDEX "idx-font-name" ON "font" ((lower("name")))"#
+    /// );
+    /// ```
+    pub fn expr<T>(expr: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        Self {
+            name: SeaRc::new(NullAlias::new()),
+            expr: Some(expr.into()),
+            prefix: None,
+            order: None,
+            collate: None,
+            nulls: None,
+        }
+    }
+
+    /// Set the length of the indexed column prefix. MySQL only
+    pub fn prefix(mut self, prefix: u32) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+
+    /// Set the order of the index column
+    pub fn order(mut self, order: IndexOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Set the collation of the index column. Not available on MySQL
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::create()
+    ///     .name("idx-font-name")
+    ///     .table(Font::Table)
+    ///     .col(
+    ///         IndexColumn::new(Font::Name)
+    ///             .collate("C")
+    ///             .order(IndexOrder::Desc)
+    ///             .nulls(NullOrdering::Last),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.try_to_string(MysqlQueryBuilder),
+    ///     Err(error::Error::Unsupported(
+    ///         "Index column collation is not available in MySQL.".to_owned()
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE INDEX "idx-font-name" ON "font" ("name" COLLATE "C" DESC NULLS LAST)"#
+    /// );
+    /// assert_eq!(
+    ///     index.try_to_string(SqliteQueryBuilder),
+    ///     Err(error::Error::Unsupported(
+    ///         "Index column nulls ordering is not available in SQLite.".to_owned()
+    ///     ))
+    /// );
+    /// ```
+    pub fn collate(mut self, collate: &str) -> Self {
+        self.collate = Some(collate.into());
+        self
+    }
+
+    /// Set the ordering of nulls in the index column. Postgres only
+    pub fn nulls(mut self, nulls: NullOrdering) -> Self {
+        self.nulls = Some(nulls);
+        self
+    }
+
+    pub fn get_expr(&self) -> Option<&SimpleExpr> {
+        self.expr.as_ref()
+    }
 }
 
 impl TableIndex {
@@ -1,5 +1,7 @@
 use super::common::*;
-use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder};
+use crate::{
+    backend::SchemaBuilder, error::Result, expr::*, prepare::*, types::*, SchemaStatementBuilder,
+};
 
 /// Create an index for an existing table
 ///
@@ -97,20 +99,34 @@ use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndexCreateStatement {
     pub(crate) table: Option<DynIden>,
     pub(crate) index: TableIndex,
     pub(crate) primary: bool,
     pub(crate) unique: bool,
     pub(crate) index_type: Option<IndexType>,
+    pub(crate) include_columns: Vec<DynIden>,
+    pub(crate) r#where: Option<SimpleExpr>,
+    pub(crate) concurrently: bool,
+    pub(crate) if_not_exists: bool,
 }
 
 /// Specification of a table index
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IndexType {
     BTree,
     FullText,
     Hash,
+    /// Postgres only
+    Gin,
+    /// Postgres only
+    Gist,
+    /// Postgres only
+    Brin,
+    /// Postgres only
+    SpGist,
     Custom(DynIden),
 }
 
@@ -129,6 +145,10 @@ impl IndexCreateStatement {
             primary: false,
             unique: false,
             index_type: None,
+            include_columns: Vec::new(),
+            r#where: None,
+            concurrently: false,
+            if_not_exists: false,
         }
     }
 
@@ -181,6 +201,98 @@ impl IndexCreateStatement {
         self
     }
 
+    /// Add a non-key column to a covering index, `INCLUDE (...)`. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::create()
+    ///     .unique()
+    ///     .name("idx-glyph-id")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Id)
+    ///     .include(Glyph::Aspect)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE UNIQUE INDEX "idx-glyph-id" ON "glyph" ("id") INCLUDE ("aspect")"#
+    /// );
+    /// ```
+    pub fn include<T>(&mut self, col: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.include_columns.push(col.into_iden());
+        self
+    }
+
+    /// Add a predicate to a partial index, `WHERE ...`. Multiple predicates are joined by `AND`.
+    /// Not available on MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::create()
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Aspect)
+    ///     .and_where(Expr::col(Glyph::Aspect).gt(2))
+    ///     .and_where(Expr::col(Glyph::Image).is_not_null())
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE INDEX "idx-glyph-aspect" ON "glyph" ("aspect") WHERE ("aspect" > 2) AND ("image" IS NOT NULL)"#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE INDEX "idx-glyph-aspect" ON "glyph" ("aspect") WHERE ("aspect" > 2) AND ("image" IS NOT NULL)"#
+    /// );
+    /// ```
+    pub fn and_where(&mut self, condition: SimpleExpr) -> &mut Self {
+        self.r#where = Some(match self.r#where.take() {
+            Some(expr) => expr.and(condition),
+            None => condition,
+        });
+        self
+    }
+
+    /// Build the index without locking out writes, `CONCURRENTLY`. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::create()
+    ///     .concurrently()
+    ///     .if_not_exists()
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Aspect)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE INDEX CONCURRENTLY IF NOT EXISTS "idx-glyph-aspect" ON "glyph" ("aspect")"#
+    /// );
+    /// ```
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// Create index if index not exists. Not available on MySQL.
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
     pub fn is_primary_key(&self) -> bool {
         self.primary
     }
@@ -193,6 +305,10 @@ impl IndexCreateStatement {
         &self.index
     }
 
+    pub fn get_index_type(&self) -> Option<&IndexType> {
+        self.index_type.as_ref()
+    }
+
     pub fn take(&mut self) -> Self {
         Self {
             table: self.table.take(),
@@ -200,6 +316,10 @@ impl IndexCreateStatement {
             primary: self.primary,
             unique: self.unique,
             index_type: self.index_type.take(),
+            include_columns: std::mem::take(&mut self.include_columns),
+            r#where: self.r#where.take(),
+            concurrently: self.concurrently,
+            if_not_exists: self.if_not_exists,
         }
     }
 }
@@ -216,4 +336,8 @@ impl SchemaStatementBuilder for IndexCreateStatement {
         schema_builder.prepare_index_create_statement(self, &mut sql);
         sql.result()
     }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_index_create_statement(self)
+    }
 }
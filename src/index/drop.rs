@@ -1,4 +1,6 @@
-use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder, TableIndex};
+use crate::{
+    backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder, TableIndex,
+};
 
 /// Drop an index for an existing table
 ///
@@ -22,13 +24,26 @@ use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder
 /// );
 /// assert_eq!(
 ///     index.to_string(SqliteQueryBuilder),
-///     r#"DROP INDEX "idx-glyph-aspect" ON "glyph""#
+///     r#"DROP INDEX "idx-glyph-aspect""#
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct IndexDropStatement {
     pub(crate) table: Option<DynIden>,
+    pub(crate) schema: Option<DynIden>,
     pub(crate) index: TableIndex,
+    pub(crate) if_exists: bool,
+    pub(crate) concurrently: bool,
+    pub(crate) option: Option<IndexDropOpt>,
+}
+
+/// All available options for dropping an index
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IndexDropOpt {
+    Cascade,
+    Restrict,
 }
 
 impl Default for IndexDropStatement {
@@ -42,7 +57,11 @@ impl IndexDropStatement {
     pub fn new() -> Self {
         Self {
             table: None,
+            schema: None,
             index: Default::default(),
+            if_exists: false,
+            concurrently: false,
+            option: None,
         }
     }
 
@@ -52,14 +71,89 @@ impl IndexDropStatement {
         self
     }
 
-    /// Set target table
-    pub fn table<T: 'static>(&mut self, table: T) -> &mut Self
+    /// Set target table, required by MySQL
+    pub fn table<T>(&mut self, table: T) -> &mut Self
     where
-        T: Iden,
+        T: Iden + 'static,
     {
         self.table = Some(SeaRc::new(table));
         self
     }
+
+    /// Set the schema of the index. On MySQL the schema qualifies the table instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::drop()
+    ///     .schema(Alias::new("public"))
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(MysqlQueryBuilder),
+    ///     r#"DROP INDEX `idx-glyph-aspect` ON `public`.`glyph`"#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"DROP INDEX "public"."idx-glyph-aspect""#
+    /// );
+    /// assert_eq!(
+    ///     index.to_string(SqliteQueryBuilder),
+    ///     r#"DROP INDEX "public"."idx-glyph-aspect""#
+    /// );
+    /// ```
+    pub fn schema<T>(&mut self, schema: T) -> &mut Self
+    where
+        T: Iden + 'static,
+    {
+        self.schema = Some(SeaRc::new(schema));
+        self
+    }
+
+    /// Drop index if exists. Not available in MySQL.
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Drop index without locking out concurrent writes. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::drop()
+    ///     .concurrently()
+    ///     .name("idx-glyph-aspect")
+    ///     .cascade()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"DROP INDEX CONCURRENTLY "idx-glyph-aspect" CASCADE"#
+    /// );
+    /// ```
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// Drop option cascade. Postgres only.
+    pub fn cascade(&mut self) -> &mut Self {
+        self.option = Some(IndexDropOpt::Cascade);
+        self
+    }
+
+    /// Drop option restrict. Postgres only.
+    pub fn restrict(&mut self) -> &mut Self {
+        self.option = Some(IndexDropOpt::Restrict);
+        self
+    }
 }
 
 impl SchemaStatementBuilder for IndexDropStatement {
@@ -74,4 +168,8 @@ impl SchemaStatementBuilder for IndexDropStatement {
         schema_builder.prepare_index_drop_statement(self, &mut sql);
         sql.result()
     }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_index_drop_statement(self)
+    }
 }
@@ -4,25 +4,37 @@
 //!
 //! - Table Index Create, see [`IndexCreateStatement`]
 //! - Table Index Drop, see [`IndexDropStatement`]
+//! - Table Index Rename, see [`IndexRenameStatement`]
+//! - Table Index Reindex, see [`IndexReindexStatement`]
 
 mod common;
 mod create;
 mod drop;
+mod reindex;
+mod rename;
 mod shim;
 
 pub use common::*;
 pub use create::*;
 pub use drop::*;
+pub use reindex::*;
+pub use rename::*;
+
+use crate::{error::Result, SchemaBuilder, SchemaStatementBuilder};
 
 /// Shorthand for constructing any index statement
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Index;
 
 /// All available types of index statement
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum IndexStatement {
     Create(IndexCreateStatement),
     Drop(IndexDropStatement),
+    Rename(IndexRenameStatement),
+    Reindex(IndexReindexStatement),
 }
 
 impl Index {
@@ -35,4 +47,56 @@ impl Index {
     pub fn drop() -> IndexDropStatement {
         IndexDropStatement::new()
     }
+
+    /// Construct index [`IndexRenameStatement`]
+    pub fn rename() -> IndexRenameStatement {
+        IndexRenameStatement::new()
+    }
+
+    /// Construct index [`IndexReindexStatement`]
+    pub fn reindex() -> IndexReindexStatement {
+        IndexReindexStatement::new()
+    }
+}
+
+impl IndexStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: SchemaBuilder>(&self, index_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.build(index_builder),
+            Self::Drop(stat) => stat.build(index_builder),
+            Self::Rename(stat) => stat.build(index_builder),
+            Self::Reindex(stat) => stat.build(index_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, index_builder: &dyn SchemaBuilder) -> String {
+        match self {
+            Self::Create(stat) => stat.build_any(index_builder),
+            Self::Drop(stat) => stat.build_any(index_builder),
+            Self::Rename(stat) => stat.build_any(index_builder),
+            Self::Reindex(stat) => stat.build_any(index_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: SchemaBuilder>(&self, index_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.to_string(index_builder),
+            Self::Drop(stat) => stat.to_string(index_builder),
+            Self::Rename(stat) => stat.to_string(index_builder),
+            Self::Reindex(stat) => stat.to_string(index_builder),
+        }
+    }
+
+    /// Check that the database backend supports the statement, see [`SchemaStatementBuilder::check`]
+    pub fn check(&self, index_builder: &dyn SchemaBuilder) -> Result<()> {
+        match self {
+            Self::Create(stat) => stat.check(index_builder),
+            Self::Drop(stat) => stat.check(index_builder),
+            Self::Rename(stat) => stat.check(index_builder),
+            Self::Reindex(stat) => stat.check(index_builder),
+        }
+    }
 }
@@ -0,0 +1,131 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Rebuild an index, or all indexes of a table
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let index = Index::reindex().name("idx-glyph-aspect").to_owned();
+///
+/// assert_eq!(
+///     index.to_string(PostgresQueryBuilder),
+///     r#"REINDEX INDEX "idx-glyph-aspect""#
+/// );
+/// assert_eq!(
+///     index.to_string(SqliteQueryBuilder),
+///     r#"REINDEX "idx-glyph-aspect""#
+/// );
+///
+/// let index = Index::reindex().table(Glyph::Table).to_owned();
+///
+/// assert_eq!(
+///     index.to_string(PostgresQueryBuilder),
+///     r#"REINDEX TABLE "glyph""#
+/// );
+/// assert_eq!(
+///     index.to_string(SqliteQueryBuilder),
+///     r#"REINDEX "glyph""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexReindexStatement {
+    pub(crate) name: Option<String>,
+    pub(crate) table: Option<DynIden>,
+    pub(crate) schema: Option<DynIden>,
+    pub(crate) concurrently: bool,
+}
+
+impl Default for IndexReindexStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IndexReindexStatement {
+    /// Construct a new [`IndexReindexStatement`]
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            table: None,
+            schema: None,
+            concurrently: false,
+        }
+    }
+
+    /// Set the index to rebuild
+    pub fn name(&mut self, name: &str) -> &mut Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Rebuild all indexes of a table, used when no index name is given
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: Iden + 'static,
+    {
+        self.table = Some(SeaRc::new(table));
+        self
+    }
+
+    /// Set the schema of the index or table
+    pub fn schema<T>(&mut self, schema: T) -> &mut Self
+    where
+        T: Iden + 'static,
+    {
+        self.schema = Some(SeaRc::new(schema));
+        self
+    }
+
+    /// Rebuild without locking out concurrent writes. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::reindex()
+    ///     .concurrently()
+    ///     .schema(Alias::new("public"))
+    ///     .name("idx-glyph-aspect")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"REINDEX INDEX CONCURRENTLY "public"."idx-glyph-aspect""#
+    /// );
+    /// ```
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            table: self.table.take(),
+            schema: self.schema.take(),
+            concurrently: self.concurrently,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for IndexReindexStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_index_reindex_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_index_reindex_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_index_reindex_statement(self)
+    }
+}
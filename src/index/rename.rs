@@ -0,0 +1,127 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Rename an existing index
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let index = Index::rename()
+///     .index("idx-glyph-aspect", "idx-glyph-aspect-new")
+///     .table(Glyph::Table)
+///     .to_owned();
+///
+/// assert_eq!(
+///     index.to_string(MysqlQueryBuilder),
+///     r#"ALTER TABLE `glyph` RENAME INDEX `idx-glyph-aspect` TO `idx-glyph-aspect-new`"#
+/// );
+/// assert_eq!(
+///     index.to_string(PostgresQueryBuilder),
+///     r#"ALTER INDEX "idx-glyph-aspect" RENAME TO "idx-glyph-aspect-new""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IndexRenameStatement {
+    pub(crate) table: Option<DynIden>,
+    pub(crate) schema: Option<DynIden>,
+    pub(crate) from_name: Option<String>,
+    pub(crate) to_name: Option<String>,
+    pub(crate) if_exists: bool,
+}
+
+impl Default for IndexRenameStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IndexRenameStatement {
+    /// Construct a new [`IndexRenameStatement`]
+    pub fn new() -> Self {
+        Self {
+            table: None,
+            schema: None,
+            from_name: None,
+            to_name: None,
+            if_exists: false,
+        }
+    }
+
+    /// Set old and new index name
+    pub fn index(&mut self, from_name: &str, to_name: &str) -> &mut Self {
+        self.from_name = Some(from_name.to_string());
+        self.to_name = Some(to_name.to_string());
+        self
+    }
+
+    /// Set target table, required by MySQL
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: Iden + 'static,
+    {
+        self.table = Some(SeaRc::new(table));
+        self
+    }
+
+    /// Set the schema of the index. On MySQL the schema qualifies the table instead.
+    pub fn schema<T>(&mut self, schema: T) -> &mut Self
+    where
+        T: Iden + 'static,
+    {
+        self.schema = Some(SeaRc::new(schema));
+        self
+    }
+
+    /// Rename index if exists. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let index = Index::rename()
+    ///     .if_exists()
+    ///     .schema(Alias::new("public"))
+    ///     .index("idx-glyph-aspect", "idx-glyph-aspect-new")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER INDEX IF EXISTS "public"."idx-glyph-aspect" RENAME TO "idx-glyph-aspect-new""#
+    /// );
+    /// ```
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            table: self.table.take(),
+            schema: self.schema.take(),
+            from_name: self.from_name.take(),
+            to_name: self.to_name.take(),
+            if_exists: self.if_exists,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for IndexRenameStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_index_rename_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_index_rename_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_index_rename_statement(self)
+    }
+}
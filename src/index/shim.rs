@@ -2,3 +2,5 @@ use crate::impl_schema_statement_builder;
 
 impl_schema_statement_builder!(index_create_statement_builder, IndexCreateStatement);
 impl_schema_statement_builder!(index_drop_statement_builder, IndexDropStatement);
+impl_schema_statement_builder!(index_rename_statement_builder, IndexRenameStatement);
+impl_schema_statement_builder!(index_reindex_statement_builder, IndexReindexStatement);
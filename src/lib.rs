@@ -49,6 +49,10 @@
 //!
 //! SQL dialect: `backend-mysql`, `backend-postgres`, `backend-sqlite`
 //!
+//! Code generation: `codegen` (generate Rust source of tables)
+//!
+//! Serialization: `with-serde` (serialize statements with serde, identifiers deserialize into `Alias`)
+//!
 //! Type support: `with-chrono`, `with-json`, `with-rust_decimal`, `with-bigdecimal`, `with-uuid`,
 //! `postgres-array`
 //!
@@ -198,6 +202,74 @@
 //! #[derive(Iden)]
 //! struct Glyph;
 //! assert_eq!(Glyph.to_string(), "glyph");
+//!
+//! // Deriving a struct with named fields generates a companion enum of its columns
+//! #[derive(Iden)]
+//! struct Font {
+//!     id: i32,
+//!     #[iden = "font_name"]
+//!     name: String,
+//! }
+//! assert_eq!(FontIden::Table.to_string(), "font");
+//! assert_eq!(FontIden::Name.to_string(), "font_name");
+//! ```
+//!
+//! The `Table` derive builds the table of a struct, inferring column types from the field types.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! use sea_query::{PostgresQueryBuilder, Table};
+//!
+//! #[derive(Table)]
+//! struct Glyph {
+//!     #[column(primary_key, auto_increment)]
+//!     id: i32,
+//!     #[foreign_key(table = "font", column = "id", on_delete = "Cascade")]
+//!     font_id: Option<i32>,
+//! }
+//! assert_eq!(
+//!     Glyph::create_table().to_string(PostgresQueryBuilder),
+//!     r#"CREATE TABLE "glyph" ( "id" serial NOT NULL PRIMARY KEY, "font_id" integer, FOREIGN KEY ("font_id") REFERENCES "font" ("id") ON DELETE CASCADE )"#
+//! );
+//! ```
+//!
+//! The `IntoRow` derive lists the columns and values of a struct, to insert or update it.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! use sea_query::{Alias, IntoRow, PostgresQueryBuilder, Query};
+//!
+//! #[derive(IntoRow)]
+//! struct Font {
+//!     #[skip_insert]
+//!     id: i32,
+//!     name: String,
+//! }
+//! let font = Font { id: 1, name: "Sans".to_owned() };
+//! assert_eq!(
+//!     Query::insert()
+//!         .into_table(Alias::new("font"))
+//!         .from_rows([&font])
+//!         .to_string(PostgresQueryBuilder),
+//!     r#"INSERT INTO "font" ("name") VALUES ('Sans')"#
+//! );
+//! ```
+//!
+//! The `DbEnum` derive builds the Postgres enum type of a Rust enum.
+//!
+//! ```rust
+//! # #[cfg(feature = "derive")]
+//! use sea_query::{DbEnum, PostgresQueryBuilder};
+//!
+//! #[derive(DbEnum)]
+//! enum FontFamily {
+//!     Serif,
+//!     Sans,
+//! }
+//! assert_eq!(
+//!     FontFamily::create_type().to_string(PostgresQueryBuilder),
+//!     r#"CREATE TYPE "font_family" AS ENUM ('serif', 'sans')"#
+//! );
 //! ```
 //!
 //! ### Expression
@@ -685,7 +757,7 @@
 //! );
 //! assert_eq!(
 //!     index.to_string(SqliteQueryBuilder),
-//!     r#"DROP INDEX "idx-glyph-aspect" ON "glyph""#
+//!     r#"DROP INDEX "idx-glyph-aspect""#
 //! );
 //! ```
 //!
@@ -709,6 +781,9 @@
     html_logo_url = "https://raw.githubusercontent.com/SeaQL/sea-query/master/docs/SeaQL icon dark.png"
 )]
 pub mod backend;
+#[cfg(feature = "codegen")]
+#[cfg_attr(docsrs, doc(cfg(feature = "codegen")))]
+pub mod codegen;
 pub mod driver;
 pub mod error;
 pub mod expr;
@@ -716,33 +791,44 @@ pub mod extension;
 pub mod foreign_key;
 pub mod func;
 pub mod index;
+pub mod migration;
+pub mod namespace;
 pub mod prepare;
+pub mod privilege;
 pub mod query;
 pub mod schema;
 mod shim;
 pub mod table;
 pub mod tests_cfg;
 pub mod token;
+pub mod transaction;
+pub mod trigger;
 pub mod types;
 pub mod value;
+pub mod view;
 
 pub use backend::*;
 pub use driver::*;
 //pub use extension::*;
 pub use foreign_key::*;
 pub use index::*;
+pub use namespace::*;
 pub use query::*;
 pub use table::*;
 // pub use error::*;
 pub use expr::*;
 pub use func::*;
 pub use prepare::*;
+pub use privilege::*;
 pub use schema::*;
 //pub use shim::*;
 //pub use tests_cfg::*;
 pub use token::*;
+pub use transaction::*;
+pub use trigger::*;
 pub use types::*;
 pub use value::*;
+pub use view::*;
 
 #[cfg(feature = "derive")]
-pub use sea_query_derive::Iden;
+pub use sea_query_derive::{DbEnum, Iden, IntoRow, Table};
@@ -0,0 +1,88 @@
+use super::AppliedMigration;
+use crate::Values;
+use std::future::Future;
+
+/// Runs the SQL produced by a [`Migrator`](super::Migrator) on a database connection.
+///
+/// The drivers come with executors:
+///
+/// - `rusqlite`: `sea_query_driver_rusqlite!()` defines `RusqliteMigrationExecutor`
+/// - `postgres`: `sea_query_driver_postgres_client!()` defines `PostgresMigrationExecutor`
+///
+/// For other drivers, wrap the connection and bind the values as you would for any other query.
+/// The async sqlx drivers implement [`AsyncMigrationExecutor`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{migration::*, tests_cfg::*, *};
+///
+/// /// Records the statements instead of running them
+/// #[derive(Default)]
+/// struct Recorder(Vec<String>);
+///
+/// impl MigrationExecutor for Recorder {
+///     type Error = ();
+///
+///     fn execute(&mut self, sql: &str, _: Values) -> Result<(), ()> {
+///         self.0.push(sql.to_owned());
+///         Ok(())
+///     }
+///
+///     fn query_history(&mut self, _: &str, _: Values) -> Result<Vec<AppliedMigration>, ()> {
+///         Ok(Vec::new())
+///     }
+/// }
+///
+/// let migrator = Migrator::new()
+///     .migration(
+///         Migration::new(1, "drop_glyph").up(Table::drop().table(Glyph::Table).to_owned()),
+///     )
+///     .to_owned();
+///
+/// let mut recorder = Recorder::default();
+/// assert_eq!(migrator.up(PostgresQueryBuilder, &mut recorder), Ok(vec![1]));
+/// assert_eq!(recorder.0[1], "BEGIN");
+/// assert_eq!(recorder.0[2], r#"DROP TABLE "glyph""#);
+/// assert_eq!(recorder.0[4], "COMMIT");
+/// ```
+pub trait MigrationExecutor {
+    type Error;
+
+    /// Execute a statement that returns no rows
+    fn execute(&mut self, sql: &str, values: Values) -> Result<(), Self::Error>;
+
+    /// Run the history query, whose rows are `version`, `name` and `checksum` in that order
+    fn query_history(
+        &mut self,
+        sql: &str,
+        values: Values,
+    ) -> Result<Vec<AppliedMigration>, Self::Error>;
+}
+
+/// Async counterpart of [`MigrationExecutor`], run by the `*_async` methods of [`Migrator`](super::Migrator).
+///
+/// With the `sqlx-mysql`, `sqlx-postgres` and `sqlx-sqlite` features,
+/// `sea_query_driver_mysql!()`, `sea_query_driver_postgres!()` and `sea_query_driver_sqlite!()`
+/// define `SqlxMySqlMigrationExecutor`, `SqlxPostgresMigrationExecutor` and `SqlxSqliteMigrationExecutor`.
+/// They wrap a single connection rather than a pool, so that a migration and its transaction
+/// run on the same connection.
+///
+/// The returned futures are `Send`, so that migrations can run on a multi-threaded runtime.
+pub trait AsyncMigrationExecutor {
+    type Error;
+
+    /// Execute a statement that returns no rows
+    fn execute(
+        &mut self,
+        sql: &str,
+        values: Values,
+    ) -> impl Future<Output = Result<(), Self::Error>> + Send;
+
+    /// Run the history query, whose rows are `version`, `name` and `checksum` in that order
+    fn query_history(
+        &mut self,
+        sql: &str,
+        values: Values,
+    ) -> impl Future<Output = Result<Vec<AppliedMigration>, Self::Error>> + Send;
+}
@@ -0,0 +1,564 @@
+use super::{
+    AppliedMigration, AsyncMigrationExecutor, Migration, MigrationError, MigrationExecutor,
+    MigrationStatement,
+};
+use crate::{
+    backend::GenericBuilder, Alias, ColumnDef, DynIden, Expr, Iden, IntoIden, Order, Query, Table,
+    TableCreateStatement, Transaction, Values,
+};
+
+/// Applies and reverts [`Migration`]s, recording them in a history table.
+///
+/// Each migration runs inside a transaction together with its history record,
+/// unless the backend commits schema statements implicitly, as MySQL does.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{migration::*, tests_cfg::*, *};
+///
+/// let migrator = Migrator::new()
+///     .migration(
+///         Migration::new(1, "create_glyph")
+///             .up(Table::create()
+///                 .table(Glyph::Table)
+///                 .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+///                 .to_owned())
+///             .down(Table::drop().table(Glyph::Table).to_owned()),
+///     )
+///     .to_owned();
+///
+/// assert_eq!(
+///     migrator.history_table().to_string(SqliteQueryBuilder),
+///     [
+///         r#"CREATE TABLE IF NOT EXISTS "seaql_migrations" ("#,
+///         r#""version" integer NOT NULL PRIMARY KEY,"#,
+///         r#""name" text NOT NULL,"#,
+///         r#""checksum" text NOT NULL,"#,
+///         r#""applied_at" text DEFAULT CURRENT_TIMESTAMP"#,
+///         r#")"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Migrator {
+    pub(crate) table: DynIden,
+    pub(crate) migrations: Vec<Migration>,
+}
+
+/// Migrations to run, each with the SQL of its statements and history record
+type Plan = Vec<(i64, Vec<(String, Values)>)>;
+
+enum History {
+    Version,
+    Name,
+    Checksum,
+    AppliedAt,
+}
+
+impl Iden for History {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        write!(
+            s,
+            "{}",
+            match self {
+                Self::Version => "version",
+                Self::Name => "name",
+                Self::Checksum => "checksum",
+                Self::AppliedAt => "applied_at",
+            }
+        )
+        .unwrap();
+    }
+}
+
+impl Default for Migrator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Migrator {
+    /// Construct a new [`Migrator`] recording into the `seaql_migrations` table
+    pub fn new() -> Self {
+        Self {
+            table: Alias::new("seaql_migrations").into_iden(),
+            migrations: Vec::new(),
+        }
+    }
+
+    /// Set the name of the history table
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.table = table.into_iden();
+        self
+    }
+
+    /// Register a migration, keeping migrations ordered by version
+    ///
+    /// # Panics
+    ///
+    /// Panics if a migration of the same version was already registered.
+    pub fn migration(&mut self, migration: &mut Migration) -> &mut Self {
+        let migration = migration.take();
+        match self
+            .migrations
+            .binary_search_by_key(&migration.version, |m| m.version)
+        {
+            Ok(_) => panic!("Duplicated migration version {}", migration.version),
+            Err(i) => self.migrations.insert(i, migration),
+        }
+        self
+    }
+
+    pub fn get_migrations(&self) -> &Vec<Migration> {
+        &self.migrations
+    }
+
+    /// Statement creating the history table if it does not exist yet
+    pub fn history_table(&self) -> TableCreateStatement {
+        Table::create()
+            .table(self.table.clone())
+            .if_not_exists()
+            .col(
+                ColumnDef::new(History::Version)
+                    .big_integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(History::Name).text().not_null())
+            .col(ColumnDef::new(History::Checksum).text().not_null())
+            .col(
+                ColumnDef::new(History::AppliedAt)
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .to_owned()
+    }
+
+    /// Create the history table if needed and return the applied migrations,
+    /// failing if any of them was modified or is unknown to this migrator
+    pub fn check<B, E>(
+        &self,
+        backend: B,
+        executor: &mut E,
+    ) -> Result<Vec<AppliedMigration>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: MigrationExecutor,
+    {
+        self.check_ref(&backend, executor)
+    }
+
+    /// Apply all pending migrations, returning their versions
+    pub fn up<B, E>(
+        &self,
+        backend: B,
+        executor: &mut E,
+    ) -> Result<Vec<i64>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: MigrationExecutor,
+    {
+        self.up_to(backend, executor, i64::MAX)
+    }
+
+    /// Apply pending migrations up to and including `version`, returning their versions
+    pub fn up_to<B, E>(
+        &self,
+        backend: B,
+        executor: &mut E,
+        version: i64,
+    ) -> Result<Vec<i64>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: MigrationExecutor,
+    {
+        let applied = self.check_ref(&backend, executor)?;
+        let mut versions = Vec::new();
+        for (migration, statements) in self.plan_up(&backend, &applied, version)? {
+            run(&backend, executor, statements).map_err(MigrationError::Executor)?;
+            versions.push(migration);
+        }
+        Ok(versions)
+    }
+
+    /// Revert the latest `steps` applied migrations, returning their versions
+    pub fn down<B, E>(
+        &self,
+        backend: B,
+        executor: &mut E,
+        steps: usize,
+    ) -> Result<Vec<i64>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: MigrationExecutor,
+    {
+        let applied = self.check_ref(&backend, executor)?;
+        let mut versions = Vec::new();
+        for (migration, statements) in self.plan_down(&backend, &applied, steps)? {
+            run(&backend, executor, statements).map_err(MigrationError::Executor)?;
+            versions.push(migration);
+        }
+        Ok(versions)
+    }
+
+    /// Same as [`Migrator::check`], on an [`AsyncMigrationExecutor`]
+    pub async fn check_async<B, E>(
+        &self,
+        backend: B,
+        executor: &mut E,
+    ) -> Result<Vec<AppliedMigration>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: AsyncMigrationExecutor,
+    {
+        self.check_ref_async(&backend, executor).await
+    }
+
+    /// Same as [`Migrator::up`], on an [`AsyncMigrationExecutor`]
+    pub async fn up_async<B, E>(
+        &self,
+        backend: B,
+        executor: &mut E,
+    ) -> Result<Vec<i64>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: AsyncMigrationExecutor,
+    {
+        self.up_to_async(backend, executor, i64::MAX).await
+    }
+
+    /// Same as [`Migrator::up_to`], on an [`AsyncMigrationExecutor`]
+    pub async fn up_to_async<B, E>(
+        &self,
+        backend: B,
+        executor: &mut E,
+        version: i64,
+    ) -> Result<Vec<i64>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: AsyncMigrationExecutor,
+    {
+        let applied = self.check_ref_async(&backend, executor).await?;
+        let mut versions = Vec::new();
+        for (migration, statements) in self.plan_up(&backend, &applied, version)? {
+            run_async(&backend, executor, statements)
+                .await
+                .map_err(MigrationError::Executor)?;
+            versions.push(migration);
+        }
+        Ok(versions)
+    }
+
+    /// Same as [`Migrator::down`], on an [`AsyncMigrationExecutor`]
+    pub async fn down_async<B, E>(
+        &self,
+        backend: B,
+        executor: &mut E,
+        steps: usize,
+    ) -> Result<Vec<i64>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: AsyncMigrationExecutor,
+    {
+        let applied = self.check_ref_async(&backend, executor).await?;
+        let mut versions = Vec::new();
+        for (migration, statements) in self.plan_down(&backend, &applied, steps)? {
+            run_async(&backend, executor, statements)
+                .await
+                .map_err(MigrationError::Executor)?;
+            versions.push(migration);
+        }
+        Ok(versions)
+    }
+
+    fn check_ref<B, E>(
+        &self,
+        backend: &B,
+        executor: &mut E,
+    ) -> Result<Vec<AppliedMigration>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: MigrationExecutor,
+    {
+        executor
+            .execute(&self.history_table().build_any(backend), Values(Vec::new()))
+            .map_err(MigrationError::Executor)?;
+        let (sql, values) = self.history_query(backend);
+        let applied = executor
+            .query_history(&sql, values)
+            .map_err(MigrationError::Executor)?;
+        self.verify(backend, &applied)?;
+        Ok(applied)
+    }
+
+    async fn check_ref_async<B, E>(
+        &self,
+        backend: &B,
+        executor: &mut E,
+    ) -> Result<Vec<AppliedMigration>, MigrationError<E::Error>>
+    where
+        B: GenericBuilder,
+        E: AsyncMigrationExecutor,
+    {
+        executor
+            .execute(&self.history_table().build_any(backend), Values(Vec::new()))
+            .await
+            .map_err(MigrationError::Executor)?;
+        let (sql, values) = self.history_query(backend);
+        let applied = executor
+            .query_history(&sql, values)
+            .await
+            .map_err(MigrationError::Executor)?;
+        self.verify(backend, &applied)?;
+        Ok(applied)
+    }
+
+    fn history_query<B>(&self, backend: &B) -> (String, Values)
+    where
+        B: GenericBuilder,
+    {
+        let select = Query::select()
+            .columns([History::Version, History::Name, History::Checksum])
+            .from(self.table.clone())
+            .order_by(History::Version, Order::Asc)
+            .to_owned();
+        MigrationStatement::from(select).build_ref(backend)
+    }
+
+    /// Fail if an applied migration was modified or is unknown to this migrator
+    fn verify<B, Err>(
+        &self,
+        backend: &B,
+        applied: &[AppliedMigration],
+    ) -> Result<(), MigrationError<Err>>
+    where
+        B: GenericBuilder,
+    {
+        for applied in applied.iter() {
+            match self
+                .migrations
+                .iter()
+                .find(|m| m.version == applied.version)
+            {
+                None => {
+                    return Err(MigrationError::Unknown {
+                        version: applied.version,
+                        name: applied.name.clone(),
+                    })
+                }
+                Some(migration) if migration.checksum_ref(backend) != applied.checksum => {
+                    return Err(MigrationError::Drift {
+                        version: applied.version,
+                        name: applied.name.clone(),
+                    })
+                }
+                Some(_) => (),
+            }
+        }
+        Ok(())
+    }
+
+    /// Pending migrations up to `version`
+    fn plan_up<B, Err>(
+        &self,
+        backend: &B,
+        applied: &[AppliedMigration],
+        version: i64,
+    ) -> Result<Plan, MigrationError<Err>>
+    where
+        B: GenericBuilder,
+    {
+        let latest = applied.iter().map(|a| a.version).max();
+
+        let mut plan = Vec::new();
+        for migration in self.migrations.iter() {
+            if migration.version > version || applied.iter().any(|a| a.version == migration.version)
+            {
+                continue;
+            }
+            if matches!(latest, Some(latest) if migration.version < latest) {
+                return Err(MigrationError::OutOfOrder {
+                    version: migration.version,
+                    name: migration.name.clone(),
+                });
+            }
+            check_statements(backend, migration, &migration.up)?;
+            let record = Query::insert()
+                .into_table(self.table.clone())
+                .columns([History::Version, History::Name, History::Checksum])
+                .values_panic([
+                    migration.version.into(),
+                    migration.name.as_str().into(),
+                    migration.checksum_ref(backend).into(),
+                ])
+                .to_owned();
+            plan.push((
+                migration.version,
+                build_statements(backend, &migration.up, record.into()),
+            ));
+        }
+        Ok(plan)
+    }
+
+    /// Latest `steps` applied migrations, to revert
+    fn plan_down<B, Err>(
+        &self,
+        backend: &B,
+        applied: &[AppliedMigration],
+        steps: usize,
+    ) -> Result<Plan, MigrationError<Err>>
+    where
+        B: GenericBuilder,
+    {
+        applied
+            .iter()
+            .rev()
+            .take(steps)
+            .map(|applied| {
+                let migration = self
+                    .migrations
+                    .iter()
+                    .find(|m| m.version == applied.version)
+                    .expect("checked to be registered");
+                check_statements(backend, migration, &migration.down)?;
+                let record = Query::delete()
+                    .from_table(self.table.clone())
+                    .and_where(Expr::col(History::Version).eq(migration.version))
+                    .to_owned();
+                Ok((
+                    migration.version,
+                    build_statements(backend, &migration.down, record.into()),
+                ))
+            })
+            .collect()
+    }
+}
+
+/// Fail if the backend cannot build a statement of the migration, which would otherwise panic mid-run
+fn check_statements<B, Err>(
+    backend: &B,
+    migration: &Migration,
+    statements: &[MigrationStatement],
+) -> Result<(), MigrationError<Err>>
+where
+    B: GenericBuilder,
+{
+    statements
+        .iter()
+        .try_for_each(|statement| statement.check_ref(backend))
+        .map_err(|error| MigrationError::Unsupported {
+            version: migration.version,
+            name: migration.name.clone(),
+            error,
+        })
+}
+
+fn build_statements<B>(
+    backend: &B,
+    statements: &[MigrationStatement],
+    record: MigrationStatement,
+) -> Vec<(String, Values)>
+where
+    B: GenericBuilder,
+{
+    let mut built = Vec::new();
+    for statement in statements.iter().chain(std::iter::once(&record)) {
+        built.push(statement.build_ref(backend));
+        if let MigrationStatement::Schema(statement) = statement {
+            built.extend(
+                statement
+                    .comment_statements(backend)
+                    .into_iter()
+                    .map(|sql| (sql, Values(Vec::new()))),
+            );
+        }
+    }
+    built
+}
+
+/// Run the statements, in a transaction if the backend allows it
+fn run<B, E>(
+    backend: &B,
+    executor: &mut E,
+    statements: Vec<(String, Values)>,
+) -> Result<(), E::Error>
+where
+    B: GenericBuilder,
+    E: MigrationExecutor,
+{
+    let transactional = backend.transactional_ddl();
+    if transactional {
+        executor.execute(&Transaction::begin().build_any(backend), Values(Vec::new()))?;
+    }
+    let result = statements
+        .into_iter()
+        .try_for_each(|(sql, values)| executor.execute(&sql, values));
+    if transactional {
+        match result {
+            Ok(()) => executor.execute(
+                &Transaction::commit().build_any(backend),
+                Values(Vec::new()),
+            )?,
+            Err(_) => {
+                // The original error is more useful than a failed rollback
+                let _ = executor.execute(
+                    &Transaction::rollback().build_any(backend),
+                    Values(Vec::new()),
+                );
+            }
+        }
+    }
+    result
+}
+
+/// Same as [`run`], on an [`AsyncMigrationExecutor`]
+async fn run_async<B, E>(
+    backend: &B,
+    executor: &mut E,
+    statements: Vec<(String, Values)>,
+) -> Result<(), E::Error>
+where
+    B: GenericBuilder,
+    E: AsyncMigrationExecutor,
+{
+    let transactional = backend.transactional_ddl();
+    if transactional {
+        executor
+            .execute(&Transaction::begin().build_any(backend), Values(Vec::new()))
+            .await?;
+    }
+    let mut result = Ok(());
+    for (sql, values) in statements {
+        result = executor.execute(&sql, values).await;
+        if result.is_err() {
+            break;
+        }
+    }
+    if transactional {
+        match result {
+            Ok(()) => {
+                executor
+                    .execute(
+                        &Transaction::commit().build_any(backend),
+                        Values(Vec::new()),
+                    )
+                    .await?
+            }
+            Err(_) => {
+                // The original error is more useful than a failed rollback
+                let _ = executor
+                    .execute(
+                        &Transaction::rollback().build_any(backend),
+                        Values(Vec::new()),
+                    )
+                    .await;
+            }
+        }
+    }
+    result
+}
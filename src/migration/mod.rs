@@ -0,0 +1,268 @@
+//! Versioned migrations with a history table.
+//!
+//! # Usage
+//!
+//! - Declare each step as a [`Migration`] with up and down statements
+//! - Register the migrations on a [`Migrator`]
+//! - Run them through a [`MigrationExecutor`] wrapping your database connection
+//!
+//! Applied versions are recorded together with a checksum of their up statements,
+//! so that a migration edited after being applied is reported as drift.
+
+mod executor;
+mod migrator;
+
+pub use executor::*;
+pub use migrator::*;
+
+use crate::{
+    backend::GenericBuilder, error::Error, prepare::SqlWriter, DeleteStatement,
+    ForeignKeyCreateStatement, ForeignKeyDropStatement, ForeignKeyStatement, IndexCreateStatement,
+    IndexDropStatement, IndexStatement, InsertStatement, QueryStatement, SchemaStatement,
+    SelectStatement, TableAlterStatement, TableCreateStatement, TableDropStatement,
+    TableRenameStatement, TableStatement, TableTruncateStatement, UpdateStatement, Values,
+};
+
+/// A versioned migration step
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{migration::Migration, tests_cfg::*, *};
+///
+/// let migration = Migration::new(1, "create_glyph")
+///     .up(Table::create()
+///         .table(Glyph::Table)
+///         .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+///         .to_owned())
+///     .down(Table::drop().table(Glyph::Table).to_owned())
+///     .to_owned();
+///
+/// assert_eq!(migration.get_version(), 1);
+/// assert_eq!(
+///     migration.get_up()[0].to_string(PostgresQueryBuilder),
+///     r#"CREATE TABLE "glyph" ( "id" integer NOT NULL PRIMARY KEY )"#
+/// );
+/// assert_eq!(
+///     migration.get_down()[0].to_string(MysqlQueryBuilder),
+///     "DROP TABLE `glyph`"
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Migration {
+    pub(crate) version: i64,
+    pub(crate) name: String,
+    pub(crate) up: Vec<MigrationStatement>,
+    pub(crate) down: Vec<MigrationStatement>,
+}
+
+/// A statement run by a migration, either schema or data
+#[derive(Debug, Clone)]
+pub enum MigrationStatement {
+    Schema(SchemaStatement),
+    Query(QueryStatement),
+}
+
+/// A migration recorded in the history table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub name: String,
+    pub checksum: String,
+}
+
+/// Error raised while migrating
+#[derive(Debug, PartialEq, Eq)]
+pub enum MigrationError<E> {
+    /// Error raised by the executor
+    Executor(E),
+    /// An applied migration was modified since
+    Drift { version: i64, name: String },
+    /// An applied migration is not registered on the migrator
+    Unknown { version: i64, name: String },
+    /// A pending migration is older than the latest applied one
+    OutOfOrder { version: i64, name: String },
+    /// A statement of the migration is not supported by the backend, nothing was run
+    Unsupported {
+        version: i64,
+        name: String,
+        error: Error,
+    },
+}
+
+impl Migration {
+    /// Construct a new [`Migration`]
+    pub fn new(version: i64, name: &str) -> Self {
+        Self {
+            version,
+            name: name.to_owned(),
+            up: Vec::new(),
+            down: Vec::new(),
+        }
+    }
+
+    /// Add a statement to run when applying the migration
+    pub fn up<S>(&mut self, statement: S) -> &mut Self
+    where
+        S: Into<MigrationStatement>,
+    {
+        self.up.push(statement.into());
+        self
+    }
+
+    /// Add a statement to run when reverting the migration
+    pub fn down<S>(&mut self, statement: S) -> &mut Self
+    where
+        S: Into<MigrationStatement>,
+    {
+        self.down.push(statement.into());
+        self
+    }
+
+    pub fn get_version(&self) -> i64 {
+        self.version
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_up(&self) -> &Vec<MigrationStatement> {
+        &self.up
+    }
+
+    pub fn get_down(&self) -> &Vec<MigrationStatement> {
+        &self.down
+    }
+
+    /// Checksum of the up statements as rendered by the given backend
+    pub fn checksum<T: GenericBuilder>(&self, builder: T) -> String {
+        self.checksum_ref(&builder)
+    }
+
+    pub(crate) fn checksum_ref<T: GenericBuilder>(&self, builder: &T) -> String {
+        // 64-bit FNV-1a, stable across Rust releases unlike `DefaultHasher`
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for statement in self.up.iter() {
+            for byte in statement.to_string_ref(builder).bytes().chain([b';']) {
+                hash ^= byte as u64;
+                hash = hash.wrapping_mul(0x0100_0000_01b3);
+            }
+        }
+        format!("{:016x}", hash)
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            version: self.version,
+            name: std::mem::take(&mut self.name),
+            up: std::mem::take(&mut self.up),
+            down: std::mem::take(&mut self.down),
+        }
+    }
+}
+
+impl MigrationStatement {
+    /// Build corresponding SQL statement for certain database backend and collect query parameters
+    pub fn build<T: GenericBuilder>(&self, builder: T) -> (String, Values) {
+        self.build_ref(&builder)
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: GenericBuilder>(&self, builder: T) -> String {
+        self.to_string_ref(&builder)
+    }
+
+    /// Check that the database backend supports the statement, see [`SchemaStatementBuilder::check`](crate::SchemaStatementBuilder::check)
+    pub fn check<T: GenericBuilder>(&self, builder: T) -> crate::error::Result<()> {
+        self.check_ref(&builder)
+    }
+
+    pub(crate) fn check_ref<T: GenericBuilder>(&self, builder: &T) -> crate::error::Result<()> {
+        match self {
+            Self::Schema(stat) => stat.check(builder),
+            Self::Query(_) => Ok(()),
+        }
+    }
+
+    pub(crate) fn build_ref<T: GenericBuilder>(&self, builder: &T) -> (String, Values) {
+        match self {
+            Self::Schema(stat) => (stat.build_any(builder), Values(Vec::new())),
+            Self::Query(stat) => {
+                let mut sql = SqlWriter::new();
+                let mut values = Vec::new();
+                builder.prepare_query_statement(stat, &mut sql, &mut |v| values.push(v));
+                (sql.result(), Values(values))
+            }
+        }
+    }
+
+    pub(crate) fn to_string_ref<T: GenericBuilder>(&self, builder: &T) -> String {
+        match self {
+            Self::Schema(stat) => stat.build_any(builder),
+            Self::Query(stat) => builder.query_statement_to_string(stat),
+        }
+    }
+}
+
+macro_rules! impl_into_migration_statement {
+    ( $stat: ident; $( $ty: ty => $conv: expr ),* $(,)? ) => {
+        $(
+            impl From<$ty> for MigrationStatement {
+                fn from($stat: $ty) -> Self {
+                    $conv
+                }
+            }
+        )*
+    };
+}
+
+impl_into_migration_statement!(
+    stat;
+    SchemaStatement => Self::Schema(stat),
+    TableStatement => Self::Schema(SchemaStatement::TableStatement(stat)),
+    IndexStatement => Self::Schema(SchemaStatement::IndexStatement(stat)),
+    ForeignKeyStatement => Self::Schema(SchemaStatement::ForeignKeyStatement(stat)),
+    TableCreateStatement => TableStatement::Create(stat).into(),
+    TableAlterStatement => TableStatement::Alter(stat).into(),
+    TableDropStatement => TableStatement::Drop(stat).into(),
+    TableRenameStatement => TableStatement::Rename(stat).into(),
+    TableTruncateStatement => TableStatement::Truncate(stat).into(),
+    IndexCreateStatement => IndexStatement::Create(stat).into(),
+    IndexDropStatement => IndexStatement::Drop(stat).into(),
+    ForeignKeyCreateStatement => ForeignKeyStatement::Create(stat).into(),
+    ForeignKeyDropStatement => ForeignKeyStatement::Drop(stat).into(),
+    QueryStatement => Self::Query(stat),
+    SelectStatement => QueryStatement::Select(stat).into(),
+    InsertStatement => QueryStatement::Insert(stat).into(),
+    UpdateStatement => QueryStatement::Update(stat).into(),
+    DeleteStatement => QueryStatement::Delete(stat).into(),
+);
+
+impl<E: std::fmt::Display> std::fmt::Display for MigrationError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Executor(err) => write!(f, "{}", err),
+            Self::Drift { version, name } => write!(
+                f,
+                "Migration {} {} was modified after being applied",
+                version, name
+            ),
+            Self::Unknown { version, name } => {
+                write!(f, "Applied migration {} {} is unknown", version, name)
+            }
+            Self::OutOfOrder { version, name } => write!(
+                f,
+                "Pending migration {} {} is older than the latest applied migration",
+                version, name
+            ),
+            Self::Unsupported {
+                version,
+                name,
+                error,
+            } => write!(f, "Migration {} {} cannot be run: {}", version, name, error),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug + std::fmt::Display> std::error::Error for MigrationError<E> {}
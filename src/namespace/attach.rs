@@ -0,0 +1,81 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Attach a database file to the connection, SQLite only
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let database = Database::attach()
+///     .file("tenant_1.db")
+///     .name(Alias::new("tenant_1"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     database.to_string(SqliteQueryBuilder),
+///     r#"ATTACH DATABASE 'tenant_1.db' AS "tenant_1""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatabaseAttachStatement {
+    pub(crate) file: Option<String>,
+    pub(crate) name: Option<DynIden>,
+}
+
+impl Default for DatabaseAttachStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatabaseAttachStatement {
+    /// Construct attach database statement
+    pub fn new() -> Self {
+        Self {
+            file: None,
+            name: None,
+        }
+    }
+
+    /// Set the path of database file
+    pub fn file(&mut self, file: &str) -> &mut Self {
+        self.file = Some(file.to_owned());
+        self
+    }
+
+    /// Set the schema name the database is attached as
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            file: self.file.take(),
+            name: self.name.take(),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for DatabaseAttachStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_attach_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_attach_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_database_attach_statement(self)
+    }
+}
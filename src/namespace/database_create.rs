@@ -0,0 +1,118 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Create a database
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let database = Database::create()
+///     .name(Alias::new("tenant_1"))
+///     .if_not_exists()
+///     .charset("utf8mb4")
+///     .collate("utf8mb4_unicode_ci")
+///     .to_owned();
+///
+/// assert_eq!(
+///     database.to_string(MysqlQueryBuilder),
+///     r#"CREATE DATABASE IF NOT EXISTS `tenant_1` CHARACTER SET utf8mb4 COLLATE utf8mb4_unicode_ci"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatabaseCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) charset: Option<String>,
+    pub(crate) collate: Option<String>,
+}
+
+impl Default for DatabaseCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatabaseCreateStatement {
+    /// Construct create database statement
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            if_not_exists: false,
+            charset: None,
+            collate: None,
+        }
+    }
+
+    /// Set database name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Create database if not exists. Not available in Postgres.
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set the default character set, `ENCODING` in Postgres
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::*;
+    ///
+    /// let database = Database::create()
+    ///     .name(Alias::new("tenant_1"))
+    ///     .charset("UTF8")
+    ///     .collate("en_US.UTF-8")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     database.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE DATABASE "tenant_1" ENCODING 'UTF8' LC_COLLATE 'en_US.UTF-8'"#
+    /// );
+    /// ```
+    pub fn charset(&mut self, charset: &str) -> &mut Self {
+        self.charset = Some(charset.to_owned());
+        self
+    }
+
+    /// Set the default collation, `LC_COLLATE` in Postgres
+    pub fn collate(&mut self, collate: &str) -> &mut Self {
+        self.collate = Some(collate.to_owned());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            if_not_exists: self.if_not_exists,
+            charset: self.charset.take(),
+            collate: self.collate.take(),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for DatabaseCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_database_create_statement(self)
+    }
+}
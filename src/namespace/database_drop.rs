@@ -0,0 +1,85 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Drop a database
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let database = Database::drop()
+///     .name(Alias::new("tenant_1"))
+///     .if_exists()
+///     .to_owned();
+///
+/// assert_eq!(
+///     database.to_string(MysqlQueryBuilder),
+///     r#"DROP DATABASE IF EXISTS `tenant_1`"#
+/// );
+/// assert_eq!(
+///     database.to_string(PostgresQueryBuilder),
+///     r#"DROP DATABASE IF EXISTS "tenant_1""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatabaseDropStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_exists: bool,
+}
+
+impl Default for DatabaseDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatabaseDropStatement {
+    /// Construct drop database statement
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            if_exists: false,
+        }
+    }
+
+    /// Set database name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Drop database if exists
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            if_exists: self.if_exists,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for DatabaseDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_database_drop_statement(self)
+    }
+}
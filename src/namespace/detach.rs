@@ -0,0 +1,67 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Detach an attached database, SQLite only
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let database = Database::detach().name(Alias::new("tenant_1")).to_owned();
+///
+/// assert_eq!(
+///     database.to_string(SqliteQueryBuilder),
+///     r#"DETACH DATABASE "tenant_1""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DatabaseDetachStatement {
+    pub(crate) name: Option<DynIden>,
+}
+
+impl Default for DatabaseDetachStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DatabaseDetachStatement {
+    /// Construct detach database statement
+    pub fn new() -> Self {
+        Self { name: None }
+    }
+
+    /// Set the schema name of the attached database
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for DatabaseDetachStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_detach_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_database_detach_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_database_detach_statement(self)
+    }
+}
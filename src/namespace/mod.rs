@@ -0,0 +1,146 @@
+//! Schema & database namespace statements.
+//!
+//! # Usage
+//!
+//! - Schema Create, see [`SchemaCreateStatement`]
+//! - Schema Drop, see [`SchemaDropStatement`]
+//! - Schema Search Path, see [`SearchPathStatement`]
+//! - Database Create, see [`DatabaseCreateStatement`]
+//! - Database Drop, see [`DatabaseDropStatement`]
+//! - Database Attach, see [`DatabaseAttachStatement`]
+//! - Database Detach, see [`DatabaseDetachStatement`]
+
+use crate::{error::Result, SchemaBuilder, SchemaStatementBuilder};
+
+mod attach;
+mod database_create;
+mod database_drop;
+mod detach;
+mod schema_create;
+mod schema_drop;
+mod search_path;
+mod shim;
+
+pub use attach::*;
+pub use database_create::*;
+pub use database_drop::*;
+pub use detach::*;
+pub use schema_create::*;
+pub use schema_drop::*;
+pub use search_path::*;
+
+/// Shorthand for constructing any schema namespace statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Schema;
+
+/// Shorthand for constructing any database namespace statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Database;
+
+/// All available types of namespace statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NamespaceStatement {
+    SchemaCreate(SchemaCreateStatement),
+    SchemaDrop(SchemaDropStatement),
+    SearchPath(SearchPathStatement),
+    DatabaseCreate(DatabaseCreateStatement),
+    DatabaseDrop(DatabaseDropStatement),
+    DatabaseAttach(DatabaseAttachStatement),
+    DatabaseDetach(DatabaseDetachStatement),
+}
+
+impl Schema {
+    /// Construct [`SchemaCreateStatement`]
+    pub fn create() -> SchemaCreateStatement {
+        SchemaCreateStatement::new()
+    }
+
+    /// Construct [`SchemaDropStatement`]
+    pub fn drop() -> SchemaDropStatement {
+        SchemaDropStatement::new()
+    }
+
+    /// Construct [`SearchPathStatement`]
+    pub fn search_path() -> SearchPathStatement {
+        SearchPathStatement::new()
+    }
+}
+
+impl Database {
+    /// Construct [`DatabaseCreateStatement`]
+    pub fn create() -> DatabaseCreateStatement {
+        DatabaseCreateStatement::new()
+    }
+
+    /// Construct [`DatabaseDropStatement`]
+    pub fn drop() -> DatabaseDropStatement {
+        DatabaseDropStatement::new()
+    }
+
+    /// Construct [`DatabaseAttachStatement`]
+    pub fn attach() -> DatabaseAttachStatement {
+        DatabaseAttachStatement::new()
+    }
+
+    /// Construct [`DatabaseDetachStatement`]
+    pub fn detach() -> DatabaseDetachStatement {
+        DatabaseDetachStatement::new()
+    }
+}
+
+impl NamespaceStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: SchemaBuilder>(&self, namespace_builder: T) -> String {
+        match self {
+            Self::SchemaCreate(stat) => stat.build(namespace_builder),
+            Self::SchemaDrop(stat) => stat.build(namespace_builder),
+            Self::SearchPath(stat) => stat.build(namespace_builder),
+            Self::DatabaseCreate(stat) => stat.build(namespace_builder),
+            Self::DatabaseDrop(stat) => stat.build(namespace_builder),
+            Self::DatabaseAttach(stat) => stat.build(namespace_builder),
+            Self::DatabaseDetach(stat) => stat.build(namespace_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, namespace_builder: &dyn SchemaBuilder) -> String {
+        match self {
+            Self::SchemaCreate(stat) => stat.build_any(namespace_builder),
+            Self::SchemaDrop(stat) => stat.build_any(namespace_builder),
+            Self::SearchPath(stat) => stat.build_any(namespace_builder),
+            Self::DatabaseCreate(stat) => stat.build_any(namespace_builder),
+            Self::DatabaseDrop(stat) => stat.build_any(namespace_builder),
+            Self::DatabaseAttach(stat) => stat.build_any(namespace_builder),
+            Self::DatabaseDetach(stat) => stat.build_any(namespace_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: SchemaBuilder>(&self, namespace_builder: T) -> String {
+        match self {
+            Self::SchemaCreate(stat) => stat.to_string(namespace_builder),
+            Self::SchemaDrop(stat) => stat.to_string(namespace_builder),
+            Self::SearchPath(stat) => stat.to_string(namespace_builder),
+            Self::DatabaseCreate(stat) => stat.to_string(namespace_builder),
+            Self::DatabaseDrop(stat) => stat.to_string(namespace_builder),
+            Self::DatabaseAttach(stat) => stat.to_string(namespace_builder),
+            Self::DatabaseDetach(stat) => stat.to_string(namespace_builder),
+        }
+    }
+
+    /// Check that the database backend supports the statement, see [`SchemaStatementBuilder::check`]
+    pub fn check(&self, namespace_builder: &dyn SchemaBuilder) -> Result<()> {
+        match self {
+            Self::SchemaCreate(stat) => stat.check(namespace_builder),
+            Self::SchemaDrop(stat) => stat.check(namespace_builder),
+            Self::SearchPath(stat) => stat.check(namespace_builder),
+            Self::DatabaseCreate(stat) => stat.check(namespace_builder),
+            Self::DatabaseDrop(stat) => stat.check(namespace_builder),
+            Self::DatabaseAttach(stat) => stat.check(namespace_builder),
+            Self::DatabaseDetach(stat) => stat.check(namespace_builder),
+        }
+    }
+}
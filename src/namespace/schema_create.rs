@@ -0,0 +1,113 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Create a schema
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let schema = Schema::create()
+///     .name(Alias::new("tenant_1"))
+///     .if_not_exists()
+///     .to_owned();
+///
+/// assert_eq!(
+///     schema.to_string(MysqlQueryBuilder),
+///     r#"CREATE SCHEMA IF NOT EXISTS `tenant_1`"#
+/// );
+/// assert_eq!(
+///     schema.to_string(PostgresQueryBuilder),
+///     r#"CREATE SCHEMA IF NOT EXISTS "tenant_1""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) authorization: Option<DynIden>,
+}
+
+impl Default for SchemaCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SchemaCreateStatement {
+    /// Construct create schema statement
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            if_not_exists: false,
+            authorization: None,
+        }
+    }
+
+    /// Set schema name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Create schema if not exists
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set the role owning the schema. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::*;
+    ///
+    /// let schema = Schema::create()
+    ///     .name(Alias::new("tenant_1"))
+    ///     .authorization(Alias::new("tenant_1_owner"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     schema.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE SCHEMA "tenant_1" AUTHORIZATION "tenant_1_owner""#
+    /// );
+    /// ```
+    pub fn authorization<T>(&mut self, role: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.authorization = Some(role.into_iden());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            if_not_exists: self.if_not_exists,
+            authorization: self.authorization.take(),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for SchemaCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_schema_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_schema_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_schema_create_statement(self)
+    }
+}
@@ -0,0 +1,121 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Drop schemas
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let schema = Schema::drop()
+///     .name(Alias::new("tenant_1"))
+///     .if_exists()
+///     .to_owned();
+///
+/// assert_eq!(
+///     schema.to_string(MysqlQueryBuilder),
+///     r#"DROP SCHEMA IF EXISTS `tenant_1`"#
+/// );
+///
+/// let schema = Schema::drop()
+///     .name(Alias::new("tenant_1"))
+///     .if_exists()
+///     .cascade()
+///     .to_owned();
+///
+/// assert_eq!(
+///     schema.to_string(PostgresQueryBuilder),
+///     r#"DROP SCHEMA IF EXISTS "tenant_1" CASCADE"#
+/// );
+/// assert_eq!(
+///     schema.try_to_string(MysqlQueryBuilder),
+///     Err(error::Error::Unsupported(
+///         "Drop schema option is not available in MySQL.".to_owned()
+///     ))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SchemaDropStatement {
+    pub(crate) names: Vec<DynIden>,
+    pub(crate) if_exists: bool,
+    pub(crate) option: Option<SchemaDropOpt>,
+}
+
+/// All available schema drop options
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchemaDropOpt {
+    Cascade,
+    Restrict,
+}
+
+impl Default for SchemaDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SchemaDropStatement {
+    /// Construct drop schema statement
+    pub fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            if_exists: false,
+            option: None,
+        }
+    }
+
+    /// Add a schema to drop. Only Postgres accepts more than one schema.
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.names.push(name.into_iden());
+        self
+    }
+
+    /// Drop schema if exists
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Drop option cascade. Postgres only, MySQL always drops the contained tables.
+    pub fn cascade(&mut self) -> &mut Self {
+        self.option = Some(SchemaDropOpt::Cascade);
+        self
+    }
+
+    /// Drop option restrict. Postgres only.
+    pub fn restrict(&mut self) -> &mut Self {
+        self.option = Some(SchemaDropOpt::Restrict);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            names: std::mem::take(&mut self.names),
+            if_exists: self.if_exists,
+            option: self.option.take(),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for SchemaDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_schema_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_schema_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_schema_drop_statement(self)
+    }
+}
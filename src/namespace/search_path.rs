@@ -0,0 +1,97 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Set the schema search path of the session, Postgres only
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let search_path = Schema::search_path()
+///     .schema(Alias::new("tenant_1"))
+///     .schema(Alias::new("public"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     search_path.to_string(PostgresQueryBuilder),
+///     r#"SET search_path TO "tenant_1", "public""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchPathStatement {
+    pub(crate) schemas: Vec<DynIden>,
+    pub(crate) local: bool,
+}
+
+impl Default for SearchPathStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SearchPathStatement {
+    /// Construct set search path statement
+    pub fn new() -> Self {
+        Self {
+            schemas: Vec::new(),
+            local: false,
+        }
+    }
+
+    /// Append a schema to the search path
+    pub fn schema<T>(&mut self, schema: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.schemas.push(schema.into_iden());
+        self
+    }
+
+    /// Only set the search path for the current transaction, `SET LOCAL`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::*;
+    ///
+    /// let search_path = Schema::search_path()
+    ///     .local()
+    ///     .schema(Alias::new("tenant_1"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     search_path.to_string(PostgresQueryBuilder),
+    ///     r#"SET LOCAL search_path TO "tenant_1""#
+    /// );
+    /// ```
+    pub fn local(&mut self) -> &mut Self {
+        self.local = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            schemas: std::mem::take(&mut self.schemas),
+            local: self.local,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for SearchPathStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_search_path_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_search_path_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_search_path_statement(self)
+    }
+}
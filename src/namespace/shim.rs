@@ -0,0 +1,9 @@
+use crate::impl_schema_statement_builder;
+
+impl_schema_statement_builder!(schema_create_statement_builder, SchemaCreateStatement);
+impl_schema_statement_builder!(schema_drop_statement_builder, SchemaDropStatement);
+impl_schema_statement_builder!(search_path_statement_builder, SearchPathStatement);
+impl_schema_statement_builder!(database_create_statement_builder, DatabaseCreateStatement);
+impl_schema_statement_builder!(database_drop_statement_builder, DatabaseDropStatement);
+impl_schema_statement_builder!(database_attach_statement_builder, DatabaseAttachStatement);
+impl_schema_statement_builder!(database_detach_statement_builder, DatabaseDetachStatement);
@@ -0,0 +1,97 @@
+use super::{impl_privilege_target, Privilege, PrivilegeObject};
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Grant privileges to roles
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let grant = GrantStatement::new()
+///     .privilege(Privilege::Select)
+///     .column_privilege(Privilege::Update, [Glyph::Aspect, Glyph::Image])
+///     .on_table(Glyph::Table)
+///     .to(Alias::new("reporting"))
+///     .with_grant_option()
+///     .to_owned();
+///
+/// assert_eq!(
+///     grant.to_string(MysqlQueryBuilder),
+///     r#"GRANT SELECT, UPDATE (`aspect`, `image`) ON `glyph` TO `reporting` WITH GRANT OPTION"#
+/// );
+/// assert_eq!(
+///     grant.to_string(PostgresQueryBuilder),
+///     r#"GRANT SELECT, UPDATE ("aspect", "image") ON TABLE "glyph" TO "reporting" WITH GRANT OPTION"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GrantStatement {
+    pub(crate) privileges: Vec<(Privilege, Vec<DynIden>)>,
+    pub(crate) object: Option<PrivilegeObject>,
+    pub(crate) grantees: Vec<DynIden>,
+    pub(crate) with_grant_option: bool,
+}
+
+impl Default for GrantStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GrantStatement {
+    /// Construct grant statement
+    pub fn new() -> Self {
+        Self {
+            privileges: Vec::new(),
+            object: None,
+            grantees: Vec::new(),
+            with_grant_option: false,
+        }
+    }
+
+    /// Add a role to grant the privileges to
+    pub fn to<T>(&mut self, role: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.grantees.push(role.into_iden());
+        self
+    }
+
+    /// Allow the roles to grant the privileges to others
+    pub fn with_grant_option(&mut self) -> &mut Self {
+        self.with_grant_option = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            privileges: std::mem::take(&mut self.privileges),
+            object: self.object.take(),
+            grantees: std::mem::take(&mut self.grantees),
+            with_grant_option: self.with_grant_option,
+        }
+    }
+}
+
+impl_privilege_target!(GrantStatement);
+
+impl SchemaStatementBuilder for GrantStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_grant_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_grant_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_grant_statement(self)
+    }
+}
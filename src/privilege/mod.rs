@@ -0,0 +1,212 @@
+//! Privilege & role management statements.
+//!
+//! # Usage
+//!
+//! - Grant, see [`GrantStatement`]
+//! - Revoke, see [`RevokeStatement`]
+//! - Role Create, see [`RoleCreateStatement`]
+//! - Role Drop, see [`RoleDropStatement`]
+
+use crate::{error::Result, types::*, SchemaBuilder, SchemaStatementBuilder};
+
+mod grant;
+mod revoke;
+mod role_create;
+mod role_drop;
+mod shim;
+
+pub use grant::*;
+pub use revoke::*;
+pub use role_create::*;
+pub use role_drop::*;
+
+/// Shorthand for constructing any role statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Role;
+
+/// All available privileges
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Privilege {
+    All,
+    Select,
+    Insert,
+    Update,
+    Delete,
+    Truncate,
+    References,
+    Trigger,
+    Usage,
+    Create,
+    Connect,
+    Temporary,
+    Execute,
+}
+
+/// All available objects a privilege can be granted on
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrivilegeObject {
+    Table(Vec<TableRef>),
+    Schema(Vec<DynIden>),
+    Sequence(Vec<DynIden>),
+    AllTablesInSchema(Vec<DynIden>),
+}
+
+/// All available types of privilege statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PrivilegeStatement {
+    Grant(GrantStatement),
+    Revoke(RevokeStatement),
+    RoleCreate(RoleCreateStatement),
+    RoleDrop(RoleDropStatement),
+}
+
+impl Role {
+    /// Construct [`RoleCreateStatement`]
+    pub fn create() -> RoleCreateStatement {
+        RoleCreateStatement::new()
+    }
+
+    /// Construct [`RoleDropStatement`]
+    pub fn drop() -> RoleDropStatement {
+        RoleDropStatement::new()
+    }
+}
+
+macro_rules! impl_privilege_target {
+    ( $struct_name: ident ) => {
+        impl $struct_name {
+            /// Add a privilege
+            pub fn privilege(&mut self, privilege: Privilege) -> &mut Self {
+                self.privileges.push((privilege, Vec::new()));
+                self
+            }
+
+            /// Add privileges
+            pub fn privileges<I>(&mut self, privileges: I) -> &mut Self
+            where
+                I: IntoIterator<Item = Privilege>,
+            {
+                for privilege in privileges.into_iter() {
+                    self.privilege(privilege);
+                }
+                self
+            }
+
+            /// Add a privilege restricted to some columns of the table
+            pub fn column_privilege<T, I>(&mut self, privilege: Privilege, columns: I) -> &mut Self
+            where
+                T: IntoIden,
+                I: IntoIterator<Item = T>,
+            {
+                self.privileges.push((
+                    privilege,
+                    columns.into_iter().map(|c| c.into_iden()).collect(),
+                ));
+                self
+            }
+
+            /// Set the privileges on a table, call multiple times to add more tables
+            pub fn on_table<T>(&mut self, table: T) -> &mut Self
+            where
+                T: IntoTableRef,
+            {
+                match &mut self.object {
+                    Some(PrivilegeObject::Table(tables)) => tables.push(table.into_table_ref()),
+                    _ => self.object = Some(PrivilegeObject::Table(vec![table.into_table_ref()])),
+                }
+                self
+            }
+
+            /// Set the privileges on a schema, call multiple times to add more schemas
+            pub fn on_schema<T>(&mut self, schema: T) -> &mut Self
+            where
+                T: IntoIden,
+            {
+                match &mut self.object {
+                    Some(PrivilegeObject::Schema(schemas)) => schemas.push(schema.into_iden()),
+                    _ => self.object = Some(PrivilegeObject::Schema(vec![schema.into_iden()])),
+                }
+                self
+            }
+
+            /// Set the privileges on a sequence, call multiple times to add more sequences. Postgres only.
+            pub fn on_sequence<T>(&mut self, sequence: T) -> &mut Self
+            where
+                T: IntoIden,
+            {
+                match &mut self.object {
+                    Some(PrivilegeObject::Sequence(sequences)) => {
+                        sequences.push(sequence.into_iden())
+                    }
+                    _ => self.object = Some(PrivilegeObject::Sequence(vec![sequence.into_iden()])),
+                }
+                self
+            }
+
+            /// Set the privileges on all tables of a schema, call multiple times to add more schemas
+            pub fn on_all_tables_in_schema<T>(&mut self, schema: T) -> &mut Self
+            where
+                T: IntoIden,
+            {
+                match &mut self.object {
+                    Some(PrivilegeObject::AllTablesInSchema(schemas)) => {
+                        schemas.push(schema.into_iden())
+                    }
+                    _ => {
+                        self.object =
+                            Some(PrivilegeObject::AllTablesInSchema(vec![schema.into_iden()]))
+                    }
+                }
+                self
+            }
+        }
+    };
+}
+
+pub(crate) use impl_privilege_target;
+
+impl PrivilegeStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: SchemaBuilder>(&self, privilege_builder: T) -> String {
+        match self {
+            Self::Grant(stat) => stat.build(privilege_builder),
+            Self::Revoke(stat) => stat.build(privilege_builder),
+            Self::RoleCreate(stat) => stat.build(privilege_builder),
+            Self::RoleDrop(stat) => stat.build(privilege_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, privilege_builder: &dyn SchemaBuilder) -> String {
+        match self {
+            Self::Grant(stat) => stat.build_any(privilege_builder),
+            Self::Revoke(stat) => stat.build_any(privilege_builder),
+            Self::RoleCreate(stat) => stat.build_any(privilege_builder),
+            Self::RoleDrop(stat) => stat.build_any(privilege_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: SchemaBuilder>(&self, privilege_builder: T) -> String {
+        match self {
+            Self::Grant(stat) => stat.to_string(privilege_builder),
+            Self::Revoke(stat) => stat.to_string(privilege_builder),
+            Self::RoleCreate(stat) => stat.to_string(privilege_builder),
+            Self::RoleDrop(stat) => stat.to_string(privilege_builder),
+        }
+    }
+
+    /// Check that the database backend supports the statement, see [`SchemaStatementBuilder::check`]
+    pub fn check(&self, privilege_builder: &dyn SchemaBuilder) -> Result<()> {
+        match self {
+            Self::Grant(stat) => stat.check(privilege_builder),
+            Self::Revoke(stat) => stat.check(privilege_builder),
+            Self::RoleCreate(stat) => stat.check(privilege_builder),
+            Self::RoleDrop(stat) => stat.check(privilege_builder),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use super::{impl_privilege_target, Privilege, PrivilegeObject};
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Revoke privileges from roles
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let revoke = RevokeStatement::new()
+///     .privileges([Privilege::Insert, Privilege::Delete])
+///     .on_all_tables_in_schema(Alias::new("sales"))
+///     .from(Alias::new("reporting"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     revoke.to_string(MysqlQueryBuilder),
+///     r#"REVOKE INSERT, DELETE ON `sales`.* FROM `reporting`"#
+/// );
+/// assert_eq!(
+///     revoke.to_string(PostgresQueryBuilder),
+///     r#"REVOKE INSERT, DELETE ON ALL TABLES IN SCHEMA "sales" FROM "reporting""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RevokeStatement {
+    pub(crate) privileges: Vec<(Privilege, Vec<DynIden>)>,
+    pub(crate) object: Option<PrivilegeObject>,
+    pub(crate) grantees: Vec<DynIden>,
+    pub(crate) grant_option_for: bool,
+    pub(crate) option: Option<RevokeOpt>,
+}
+
+/// All available revoke options
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RevokeOpt {
+    Cascade,
+    Restrict,
+}
+
+impl Default for RevokeStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RevokeStatement {
+    /// Construct revoke statement
+    pub fn new() -> Self {
+        Self {
+            privileges: Vec::new(),
+            object: None,
+            grantees: Vec::new(),
+            grant_option_for: false,
+            option: None,
+        }
+    }
+
+    /// Add a role to revoke the privileges from
+    pub fn from<T>(&mut self, role: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.grantees.push(role.into_iden());
+        self
+    }
+
+    /// Only revoke the grant option, not the privileges themselves. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let revoke = RevokeStatement::new()
+    ///     .grant_option_for()
+    ///     .privilege(Privilege::All)
+    ///     .on_sequence(Alias::new("glyph_id_seq"))
+    ///     .from(Alias::new("reporting"))
+    ///     .cascade()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     revoke.to_string(PostgresQueryBuilder),
+    ///     r#"REVOKE GRANT OPTION FOR ALL ON SEQUENCE "glyph_id_seq" FROM "reporting" CASCADE"#
+    /// );
+    /// ```
+    pub fn grant_option_for(&mut self) -> &mut Self {
+        self.grant_option_for = true;
+        self
+    }
+
+    /// Revoke option cascade. Postgres only.
+    pub fn cascade(&mut self) -> &mut Self {
+        self.option = Some(RevokeOpt::Cascade);
+        self
+    }
+
+    /// Revoke option restrict. Postgres only.
+    pub fn restrict(&mut self) -> &mut Self {
+        self.option = Some(RevokeOpt::Restrict);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            privileges: std::mem::take(&mut self.privileges),
+            object: self.object.take(),
+            grantees: std::mem::take(&mut self.grantees),
+            grant_option_for: self.grant_option_for,
+            option: self.option.take(),
+        }
+    }
+}
+
+impl_privilege_target!(RevokeStatement);
+
+impl SchemaStatementBuilder for RevokeStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_revoke_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_revoke_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_revoke_statement(self)
+    }
+}
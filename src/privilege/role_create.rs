@@ -0,0 +1,161 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Create a role
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let role = Role::create()
+///     .name(Alias::new("reporting"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     role.to_string(MysqlQueryBuilder),
+///     r#"CREATE ROLE `reporting`"#
+/// );
+/// assert_eq!(
+///     role.to_string(PostgresQueryBuilder),
+///     r#"CREATE ROLE "reporting""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoleCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) if_not_exists: bool,
+    pub(crate) options: Vec<RoleOption>,
+}
+
+/// All available role options
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoleOption {
+    Login(bool),
+    Superuser(bool),
+    CreateDb(bool),
+    CreateRole(bool),
+    Inherit(bool),
+    Password(String),
+    ConnectionLimit(i32),
+}
+
+impl Default for RoleCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoleCreateStatement {
+    /// Construct create role statement
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            if_not_exists: false,
+            options: Vec::new(),
+        }
+    }
+
+    /// Set role name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Create role if not exists. MySQL only.
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set `LOGIN` or `NOLOGIN`. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::*;
+    ///
+    /// let role = Role::create()
+    ///     .name(Alias::new("reporting"))
+    ///     .login(true)
+    ///     .inherit(false)
+    ///     .password("secret")
+    ///     .connection_limit(5)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     role.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE ROLE "reporting" WITH LOGIN NOINHERIT PASSWORD 'secret' CONNECTION LIMIT 5"#
+    /// );
+    /// ```
+    pub fn login(&mut self, login: bool) -> &mut Self {
+        self.options.push(RoleOption::Login(login));
+        self
+    }
+
+    /// Set `SUPERUSER` or `NOSUPERUSER`. Postgres only.
+    pub fn superuser(&mut self, superuser: bool) -> &mut Self {
+        self.options.push(RoleOption::Superuser(superuser));
+        self
+    }
+
+    /// Set `CREATEDB` or `NOCREATEDB`. Postgres only.
+    pub fn create_db(&mut self, create_db: bool) -> &mut Self {
+        self.options.push(RoleOption::CreateDb(create_db));
+        self
+    }
+
+    /// Set `CREATEROLE` or `NOCREATEROLE`. Postgres only.
+    pub fn create_role(&mut self, create_role: bool) -> &mut Self {
+        self.options.push(RoleOption::CreateRole(create_role));
+        self
+    }
+
+    /// Set `INHERIT` or `NOINHERIT`. Postgres only.
+    pub fn inherit(&mut self, inherit: bool) -> &mut Self {
+        self.options.push(RoleOption::Inherit(inherit));
+        self
+    }
+
+    /// Set `PASSWORD`. Postgres only.
+    pub fn password(&mut self, password: &str) -> &mut Self {
+        self.options.push(RoleOption::Password(password.to_owned()));
+        self
+    }
+
+    /// Set `CONNECTION LIMIT`. Postgres only.
+    pub fn connection_limit(&mut self, limit: i32) -> &mut Self {
+        self.options.push(RoleOption::ConnectionLimit(limit));
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            if_not_exists: self.if_not_exists,
+            options: std::mem::take(&mut self.options),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for RoleCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_role_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_role_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_role_create_statement(self)
+    }
+}
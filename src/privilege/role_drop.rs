@@ -0,0 +1,86 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Drop roles
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let role = Role::drop()
+///     .name(Alias::new("reporting"))
+///     .name(Alias::new("auditing"))
+///     .if_exists()
+///     .to_owned();
+///
+/// assert_eq!(
+///     role.to_string(MysqlQueryBuilder),
+///     r#"DROP ROLE IF EXISTS `reporting`, `auditing`"#
+/// );
+/// assert_eq!(
+///     role.to_string(PostgresQueryBuilder),
+///     r#"DROP ROLE IF EXISTS "reporting", "auditing""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoleDropStatement {
+    pub(crate) names: Vec<DynIden>,
+    pub(crate) if_exists: bool,
+}
+
+impl Default for RoleDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoleDropStatement {
+    /// Construct drop role statement
+    pub fn new() -> Self {
+        Self {
+            names: Vec::new(),
+            if_exists: false,
+        }
+    }
+
+    /// Add a role to drop
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.names.push(name.into_iden());
+        self
+    }
+
+    /// Drop role if exists
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            names: std::mem::take(&mut self.names),
+            if_exists: self.if_exists,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for RoleDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_role_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_role_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_role_drop_statement(self)
+    }
+}
@@ -0,0 +1,6 @@
+use crate::impl_schema_statement_builder;
+
+impl_schema_statement_builder!(grant_statement_builder, GrantStatement);
+impl_schema_statement_builder!(revoke_statement_builder, RevokeStatement);
+impl_schema_statement_builder!(role_create_statement_builder, RoleCreateStatement);
+impl_schema_statement_builder!(role_drop_statement_builder, RoleDropStatement);
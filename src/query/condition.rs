@@ -1,6 +1,7 @@
 use crate::{expr::SimpleExpr, types::LogicalChainOper};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConditionType {
     Any,
     All,
@@ -8,6 +9,7 @@ pub enum ConditionType {
 
 /// Represents the value of an [`Condition::any`] or [`Condition::all`]: a set of disjunctive or conjunctive conditions.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Condition {
     pub(crate) negate: bool,
     pub(crate) condition_type: ConditionType,
@@ -24,12 +26,14 @@ pub type Cond = Condition;
 ///
 /// The arguments are automatically converted to the right enum.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConditionExpression {
     Condition(Condition),
     SimpleExpr(SimpleExpr),
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConditionHolderContents {
     Empty,
     Chain(Vec<LogicalChainOper>),
@@ -37,6 +41,7 @@ pub enum ConditionHolderContents {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionHolder {
     pub contents: ConditionHolderContents,
 }
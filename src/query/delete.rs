@@ -34,6 +34,7 @@ use crate::{
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DeleteStatement {
     pub(crate) table: Option<Box<TableRef>>,
     pub(crate) wherei: ConditionHolder,
@@ -0,0 +1,228 @@
+use crate::{
+    backend::QueryBuilder, error::Result, prepare::*, value::*, QueryStatement,
+    QueryStatementBuilder,
+};
+
+/// Explain the execution plan of a query
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let query = Query::explain()
+///     .statement(
+///         Query::select()
+///             .column(Glyph::Aspect)
+///             .from(Glyph::Table)
+///             .and_where(Expr::col(Glyph::Id).eq(1))
+///             .to_owned(),
+///     )
+///     .format(ExplainFormat::Json)
+///     .to_owned();
+///
+/// assert_eq!(
+///     query.to_string(MysqlQueryBuilder),
+///     r#"EXPLAIN FORMAT=JSON SELECT `aspect` FROM `glyph` WHERE `id` = 1"#
+/// );
+/// assert_eq!(
+///     query.to_string(PostgresQueryBuilder),
+///     r#"EXPLAIN (FORMAT JSON) SELECT "aspect" FROM "glyph" WHERE "id" = 1"#
+/// );
+/// assert_eq!(
+///     query.build(PostgresQueryBuilder),
+///     (
+///         r#"EXPLAIN (FORMAT JSON) SELECT "aspect" FROM "glyph" WHERE "id" = $1"#.to_owned(),
+///         Values(vec![Value::Int(Some(1))])
+///     )
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExplainStatement {
+    pub(crate) query: Option<Box<QueryStatement>>,
+    pub(crate) analyze: bool,
+    pub(crate) verbose: bool,
+    pub(crate) buffers: bool,
+    pub(crate) format: Option<ExplainFormat>,
+}
+
+/// All available explain output formats
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ExplainFormat {
+    Text,
+    Json,
+}
+
+impl Default for ExplainStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ExplainStatement {
+    /// Construct a new [`ExplainStatement`]
+    pub fn new() -> Self {
+        Self {
+            query: None,
+            analyze: false,
+            verbose: false,
+            buffers: false,
+            format: None,
+        }
+    }
+
+    /// Set the statement to explain
+    pub fn statement<S>(&mut self, statement: S) -> &mut Self
+    where
+        S: Into<QueryStatement>,
+    {
+        self.query = Some(Box::new(statement.into()));
+        self
+    }
+
+    /// Execute the statement and show the actual run times. MySQL and Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::explain()
+    ///     .statement(
+    ///         Query::delete()
+    ///             .from_table(Glyph::Table)
+    ///             .and_where(Expr::col(Glyph::Id).eq(1))
+    ///             .to_owned(),
+    ///     )
+    ///     .analyze()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(MysqlQueryBuilder),
+    ///     r#"EXPLAIN ANALYZE DELETE FROM `glyph` WHERE `id` = 1"#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"EXPLAIN (ANALYZE) DELETE FROM "glyph" WHERE "id" = 1"#
+    /// );
+    /// ```
+    pub fn analyze(&mut self) -> &mut Self {
+        self.analyze = true;
+        self
+    }
+
+    /// Show additional information. Postgres only.
+    pub fn verbose(&mut self) -> &mut Self {
+        self.verbose = true;
+        self
+    }
+
+    /// Show buffer usage. Postgres only.
+    pub fn buffers(&mut self) -> &mut Self {
+        self.buffers = true;
+        self
+    }
+
+    /// Set the output format. MySQL and Postgres only, and not together with [`ExplainStatement::analyze`] on MySQL.
+    pub fn format(&mut self, format: ExplainFormat) -> &mut Self {
+        self.format = Some(format);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            query: self.query.take(),
+            analyze: self.analyze,
+            verbose: self.verbose,
+            buffers: self.buffers,
+            format: self.format.take(),
+        }
+    }
+
+    /// Check that the database backend supports the explain options, which would otherwise panic when built
+    pub fn check(&self, query_builder: &dyn QueryBuilder) -> Result<()> {
+        query_builder.check_explain_statement(self)
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string,
+    /// or [`Error::Unsupported`](crate::error::Error::Unsupported) if the backend does not support the explain options
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::Error, tests_cfg::*, *};
+    ///
+    /// let query = Query::explain()
+    ///     .statement(
+    ///         Query::select()
+    ///             .column(Glyph::Aspect)
+    ///             .from(Glyph::Table)
+    ///             .to_owned(),
+    ///     )
+    ///     .verbose()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.try_to_string(PostgresQueryBuilder),
+    ///     Ok(r#"EXPLAIN (VERBOSE) SELECT "aspect" FROM "glyph""#.to_owned())
+    /// );
+    /// assert_eq!(
+    ///     query.try_to_string(MysqlQueryBuilder),
+    ///     Err(Error::Unsupported("Explain verbose is not available in MySQL.".to_owned()))
+    /// );
+    /// ```
+    pub fn try_to_string<T: QueryBuilder>(&self, query_builder: T) -> Result<String> {
+        self.check(&query_builder)?;
+        Ok(self.to_string(query_builder))
+    }
+}
+
+impl QueryStatementBuilder for ExplainStatement {
+    /// Build corresponding SQL statement for certain database backend and collect query parameters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::explain()
+    ///     .statement(
+    ///         Query::select()
+    ///             .column(Glyph::Image)
+    ///             .from(Glyph::Table)
+    ///             .and_where(Expr::col(Glyph::Aspect).gt(2))
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// let mut params = Vec::new();
+    /// let mut collector = |v| params.push(v);
+    ///
+    /// assert_eq!(
+    ///     query.build_collect(SqliteQueryBuilder, &mut collector),
+    ///     r#"EXPLAIN QUERY PLAN SELECT "image" FROM "glyph" WHERE "aspect" > ?"#
+    /// );
+    /// assert_eq!(params, vec![Value::Int(Some(2))]);
+    /// ```
+    fn build_collect<T: QueryBuilder>(
+        &self,
+        query_builder: T,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        query_builder.prepare_explain_statement(self, &mut sql, collector);
+        sql.result()
+    }
+
+    fn build_collect_any(
+        &self,
+        query_builder: &dyn QueryBuilder,
+        collector: &mut dyn FnMut(Value),
+    ) -> String {
+        let mut sql = SqlWriter::new();
+        query_builder.prepare_explain_statement(self, &mut sql, collector);
+        sql.result()
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    backend::QueryBuilder, error::*, prepare::*, types::*, value::*, Expr, Query,
+    backend::QueryBuilder, error::*, prepare::*, types::*, value::*, Expr, IntoRow, Query,
     QueryStatementBuilder, SelectExpr, SelectStatement, SimpleExpr,
 };
 
@@ -31,6 +31,7 @@ use crate::{
 /// );
 /// ```
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct InsertStatement {
     pub(crate) table: Option<Box<TableRef>>,
     pub(crate) columns: Vec<DynIden>,
@@ -179,6 +180,27 @@ impl InsertStatement {
         self.exprs(values).unwrap()
     }
 
+    /// Specify the columns and the rows to be inserted from structs implementing [`IntoRow`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if a row has not as many values as columns.
+    ///
+    /// # Examples
+    ///
+    /// See [`IntoRow`]
+    pub fn from_rows<R, I>(&mut self, rows: I) -> &mut Self
+    where
+        R: IntoRow,
+        I: IntoIterator<Item = R>,
+    {
+        self.columns(R::columns());
+        for row in rows.into_iter() {
+            self.values_panic(row.values());
+        }
+        self
+    }
+
     /// RETURNING expressions.
     ///
     /// ## Note:
@@ -6,11 +6,14 @@
 //! - Query Insert, see [`InsertStatement`]
 //! - Query Update, see [`UpdateStatement`]
 //! - Query Delete, see [`DeleteStatement`]
+//! - Query Explain, see [`ExplainStatement`]
 
 mod condition;
 mod delete;
+mod explain;
 mod insert;
 mod ordered;
+mod row;
 mod select;
 mod shim;
 mod traits;
@@ -18,18 +21,22 @@ mod update;
 
 pub use condition::*;
 pub use delete::*;
+pub use explain::*;
 pub use insert::*;
 pub use ordered::*;
+pub use row::*;
 pub use select::*;
 pub use traits::*;
 pub use update::*;
 
 /// Shorthand for constructing any table query
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Query;
 
 /// All available types of table query
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum QueryStatement {
     Select(SelectStatement),
     Insert(InsertStatement),
@@ -57,4 +64,33 @@ impl Query {
     pub fn delete() -> DeleteStatement {
         DeleteStatement::new()
     }
+
+    /// Construct [`ExplainStatement`]
+    pub fn explain() -> ExplainStatement {
+        ExplainStatement::new()
+    }
+}
+
+impl From<SelectStatement> for QueryStatement {
+    fn from(select: SelectStatement) -> Self {
+        Self::Select(select)
+    }
+}
+
+impl From<InsertStatement> for QueryStatement {
+    fn from(insert: InsertStatement) -> Self {
+        Self::Insert(insert)
+    }
+}
+
+impl From<UpdateStatement> for QueryStatement {
+    fn from(update: UpdateStatement) -> Self {
+        Self::Update(update)
+    }
+}
+
+impl From<DeleteStatement> for QueryStatement {
+    fn from(delete: DeleteStatement) -> Self {
+        Self::Delete(delete)
+    }
 }
@@ -0,0 +1,76 @@
+use crate::{types::*, value::*};
+
+/// A struct whose fields are the columns of a row, usually implemented with `#[derive(IntoRow)]`
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{*, tests_cfg::*};
+///
+/// struct NewGlyph {
+///     aspect: f64,
+///     image: String,
+/// }
+///
+/// impl IntoRow for NewGlyph {
+///     fn columns() -> Vec<DynIden> {
+///         vec![Glyph::Aspect.into_iden(), Glyph::Image.into_iden()]
+///     }
+///
+///     fn values(&self) -> Vec<Value> {
+///         vec![self.aspect.into(), self.image.clone().into()]
+///     }
+///
+///     fn update_values(&self) -> Vec<(DynIden, Value)> {
+///         Self::columns().into_iter().zip(self.values()).collect()
+///     }
+/// }
+///
+/// let glyph = NewGlyph {
+///     aspect: 2.1345,
+///     image: "24B".to_owned(),
+/// };
+///
+/// assert_eq!(
+///     Query::insert()
+///         .into_table(Glyph::Table)
+///         .from_rows([&glyph])
+///         .to_string(MysqlQueryBuilder),
+///     r#"INSERT INTO `glyph` (`aspect`, `image`) VALUES (2.1345, '24B')"#
+/// );
+/// assert_eq!(
+///     Query::update()
+///         .table(Glyph::Table)
+///         .set_from(&glyph)
+///         .and_where(Expr::col(Glyph::Id).eq(1))
+///         .to_string(MysqlQueryBuilder),
+///     r#"UPDATE `glyph` SET `aspect` = 2.1345, `image` = '24B' WHERE `id` = 1"#
+/// );
+/// ```
+pub trait IntoRow {
+    /// Columns to insert, in the order of [`IntoRow::values`]
+    fn columns() -> Vec<DynIden>;
+
+    /// Values to insert, one per column of [`IntoRow::columns`]
+    fn values(&self) -> Vec<Value>;
+
+    /// Column-value pairs to update
+    fn update_values(&self) -> Vec<(DynIden, Value)>;
+}
+
+impl<T> IntoRow for &T
+where
+    T: IntoRow,
+{
+    fn columns() -> Vec<DynIden> {
+        T::columns()
+    }
+
+    fn values(&self) -> Vec<Value> {
+        (*self).values()
+    }
+
+    fn update_values(&self) -> Vec<(DynIden, Value)> {
+        (*self).update_values()
+    }
+}
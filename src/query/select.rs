@@ -38,6 +38,7 @@ use crate::{
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectStatement {
     pub(crate) distinct: Option<SelectDistinct>,
     pub(crate) selects: Vec<SelectExpr>,
@@ -55,6 +56,7 @@ pub struct SelectStatement {
 
 /// List of distinct keywords that can be used in select statement
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SelectDistinct {
     All,
     Distinct,
@@ -63,6 +65,7 @@ pub enum SelectDistinct {
 
 /// Select expression used in select statement
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SelectExpr {
     pub expr: SimpleExpr,
     pub alias: Option<DynIden>,
@@ -70,6 +73,7 @@ pub struct SelectExpr {
 
 /// Join expression used in select statement
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct JoinExpr {
     pub join: JoinType,
     pub table: Box<TableRef>,
@@ -79,6 +83,7 @@ pub struct JoinExpr {
 
 /// List of lock types that can be used in select statement
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LockType {
     Shared,
     Exclusive,
@@ -86,6 +91,7 @@ pub enum LockType {
 
 /// List of union types that can be used in union clause
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnionType {
     Distinct,
     All,
@@ -741,6 +747,37 @@ impl SelectStatement {
         self.from_from(TableRef::SubQuery(query, alias.into_iden()))
     }
 
+    /// From table-valued function call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Alias::new("name"))
+    ///     .from_function(
+    ///         Func::cust(Alias::new("pragma_table_info")).arg("glyph"),
+    ///         Alias::new("info"),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(SqliteQueryBuilder),
+    ///     r#"SELECT "name" FROM pragma_table_info('glyph') AS "info""#
+    /// );
+    /// assert_eq!(
+    ///     query.to_string(PostgresQueryBuilder),
+    ///     r#"SELECT "name" FROM pragma_table_info('glyph') AS "info""#
+    /// );
+    /// ```
+    pub fn from_function<T>(&mut self, func: SimpleExpr, alias: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.from_from(TableRef::FunctionCall(func, alias.into_iden()))
+    }
+
     fn from_from(&mut self, select: TableRef) -> &mut Self {
         self.from = Some(Box::new(select));
         self
@@ -5,7 +5,7 @@ use crate::{
     query::{condition::*, OrderedStatement},
     types::*,
     value::*,
-    Query, QueryStatementBuilder, SelectExpr, SelectStatement,
+    IntoRow, Query, QueryStatementBuilder, SelectExpr, SelectStatement,
 };
 
 /// Update existing rows in the table
@@ -38,6 +38,7 @@ use crate::{
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UpdateStatement {
     pub(crate) table: Option<Box<TableRef>>,
     pub(crate) values: Vec<(String, Box<SimpleExpr>)>,
@@ -212,6 +213,18 @@ impl UpdateStatement {
         self
     }
 
+    /// Update the column values of a struct implementing [`IntoRow`].
+    ///
+    /// # Examples
+    ///
+    /// See [`IntoRow`]
+    pub fn set_from<R>(&mut self, row: &R) -> &mut Self
+    where
+        R: IntoRow,
+    {
+        self.values(row.update_values())
+    }
+
     fn push_boxed_value(&mut self, k: String, v: SimpleExpr) -> &mut Self {
         self.values.push((k, Box::new(v)));
         self
@@ -0,0 +1,582 @@
+//! Diffing two sets of table definitions into migration statements.
+//!
+//! # Usage
+//!
+//! Compare the desired schema (usually declared in code) against the actual schema
+//! (usually [discovered](crate::discovery) from the database) with [`SchemaDiff`].
+
+use super::table_name;
+use crate::{
+    Alias, ColumnDef, ColumnSpec, ColumnType, DynIden, ForeignKeyAction, ForeignKeyCreateStatement,
+    ForeignKeyDropStatement, ForeignKeyStatement, IndexCreateStatement, IndexDropStatement,
+    IndexStatement, IntoIden, SchemaBuilder, SchemaStatement, SqlWriter, TableAlterOption,
+    TableAlterStatement, TableCreateStatement, TableDropStatement, TableRenameStatement,
+    TableStatement,
+};
+
+/// Compute the statements that migrate the actual schema into the desired schema.
+///
+/// The statements are ordered so that they can be executed one after another:
+///
+/// 1. Rename tables, as hinted by [`SchemaDiff::rename_table`]
+/// 2. Drop removed or changed foreign keys, indexes and primary keys
+/// 3. Create new tables
+/// 4. Rename, add, modify and drop columns
+/// 5. Create new or changed primary keys, indexes and foreign keys
+/// 6. Drop removed tables
+///
+/// Without hints, a renamed table or column shows up as a drop plus a create.
+/// Indexes and foreign keys without a name cannot be dropped and are left untouched.
+/// Column types and defaults are compared as rendered by the target backend, so that a declared
+/// schema matches the one discovered from the database, e.g. `string()` equals `text` on SQLite
+/// and a discovered default `'normal'` equals the value `"normal"`.
+///
+/// SQLite cannot modify or drop columns, nor change primary and foreign keys of existing tables.
+/// Building those statements for SQLite panics; use [`SchemaStatement::try_to_string`] to get
+/// [`Error::Unsupported`](crate::error::Error::Unsupported) instead.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{diff::SchemaDiff, tests_cfg::*, *};
+///
+/// let actual = Table::create()
+///     .table(Glyph::Table)
+///     .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+///     .col(ColumnDef::new(Glyph::Image).string())
+///     .to_owned();
+///
+/// let desired = Table::create()
+///     .table(Glyph::Table)
+///     .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+///     .col(ColumnDef::new(Glyph::Image).text())
+///     .col(ColumnDef::new(Glyph::Aspect).double().not_null())
+///     .to_owned();
+///
+/// let stmts = SchemaDiff::new().diff(PostgresQueryBuilder, &[desired], &[actual]);
+///
+/// assert_eq!(
+///     stmts
+///         .iter()
+///         .map(|stmt| stmt.to_string(PostgresQueryBuilder))
+///         .collect::<Vec<_>>(),
+///     [
+///         r#"ALTER TABLE "glyph" ADD COLUMN "aspect" double precision NOT NULL"#,
+///         r#"ALTER TABLE "glyph" ALTER COLUMN "image" TYPE text"#,
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SchemaDiff {
+    pub(crate) table_renames: Vec<(String, String)>,
+    pub(crate) column_renames: Vec<(String, String, String)>,
+}
+
+impl SchemaDiff {
+    /// Construct a new [`SchemaDiff`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hint that the actual table `from` is the desired table `to`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{diff::SchemaDiff, tests_cfg::*, *};
+    ///
+    /// let actual = Table::create()
+    ///     .table(Alias::new("glyphs"))
+    ///     .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+    ///     .to_owned();
+    ///
+    /// let desired = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+    ///     .to_owned();
+    ///
+    /// let stmts = SchemaDiff::new()
+    ///     .rename_table(Alias::new("glyphs"), Glyph::Table)
+    ///     .diff(MysqlQueryBuilder, &[desired], &[actual]);
+    ///
+    /// assert_eq!(
+    ///     stmts
+    ///         .iter()
+    ///         .map(|stmt| stmt.to_string(MysqlQueryBuilder))
+    ///         .collect::<Vec<_>>(),
+    ///     ["RENAME TABLE `glyphs` TO `glyph`"]
+    /// );
+    /// ```
+    pub fn rename_table<F, T>(&mut self, from: F, to: T) -> &mut Self
+    where
+        F: IntoIden,
+        T: IntoIden,
+    {
+        self.table_renames
+            .push((from.into_iden().to_string(), to.into_iden().to_string()));
+        self
+    }
+
+    /// Hint that the actual column `from` is the desired column `to` of the desired table `table`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{diff::SchemaDiff, tests_cfg::*, *};
+    ///
+    /// let actual = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+    ///     .col(ColumnDef::new(Alias::new("img")).string())
+    ///     .to_owned();
+    ///
+    /// let desired = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+    ///     .col(ColumnDef::new(Glyph::Image).string())
+    ///     .to_owned();
+    ///
+    /// let stmts = SchemaDiff::new()
+    ///     .rename_column(Glyph::Table, Alias::new("img"), Glyph::Image)
+    ///     .diff(SqliteQueryBuilder, &[desired], &[actual]);
+    ///
+    /// assert_eq!(
+    ///     stmts
+    ///         .iter()
+    ///         .map(|stmt| stmt.to_string(SqliteQueryBuilder))
+    ///         .collect::<Vec<_>>(),
+    ///     [r#"ALTER TABLE "glyph" RENAME COLUMN "img" TO "image""#]
+    /// );
+    /// ```
+    pub fn rename_column<T, F, C>(&mut self, table: T, from: F, to: C) -> &mut Self
+    where
+        T: IntoIden,
+        F: IntoIden,
+        C: IntoIden,
+    {
+        self.column_renames.push((
+            table.into_iden().to_string(),
+            from.into_iden().to_string(),
+            to.into_iden().to_string(),
+        ));
+        self
+    }
+
+    /// Diff the desired tables against the actual tables, for the backend `schema_builder`
+    pub fn diff<T: SchemaBuilder>(
+        &self,
+        schema_builder: T,
+        desired: &[TableCreateStatement],
+        actual: &[TableCreateStatement],
+    ) -> Vec<SchemaStatement> {
+        let mut renames = Vec::new();
+        let mut drop_foreign_keys = Vec::new();
+        let mut drop_indexes = Vec::new();
+        let mut drop_primary_keys = Vec::new();
+        let mut create_tables = Vec::new();
+        let mut alter_columns = Vec::new();
+        let mut add_primary_keys = Vec::new();
+        let mut create_indexes = Vec::new();
+        let mut create_foreign_keys = Vec::new();
+        let mut drop_tables = Vec::new();
+
+        let mut matched = vec![false; actual.len()];
+
+        for want in desired {
+            let name = match want.table.as_ref().and_then(table_name) {
+                Some(name) => name,
+                None => continue,
+            };
+            let want_name = name.to_string();
+            let have_name = self
+                .table_renames
+                .iter()
+                .find(|(_, to)| to == &want_name)
+                .map(|(from, _)| from.as_str())
+                .unwrap_or(&want_name);
+
+            let position = actual.iter().position(|have| {
+                matches!(
+                    have.table.as_ref().and_then(table_name),
+                    Some(n) if n.to_string() == have_name
+                )
+            });
+            let have = match position {
+                Some(i) => {
+                    matched[i] = true;
+                    &actual[i]
+                }
+                None => {
+                    create_tables.push(want.clone());
+                    continue;
+                }
+            };
+
+            if have_name != want_name {
+                let mut rename = TableRenameStatement::new();
+                rename.from_name = Some(Alias::new(have_name).into_iden());
+                rename.to_name = Some(name.clone());
+                renames.push(SchemaStatement::TableStatement(TableStatement::Rename(
+                    rename,
+                )));
+            }
+
+            alter_columns.extend(self.diff_columns(&schema_builder, &name, want, have));
+
+            let (want_pk_name, want_pk) = primary_key(want);
+            let (have_pk_name, have_pk) = primary_key(have);
+            let have_pk: Vec<String> = have_pk
+                .iter()
+                .map(|column| self.renamed_column(&want_name, column).to_owned())
+                .collect();
+            if want_pk != have_pk {
+                if !have_pk.is_empty() {
+                    drop_primary_keys
+                        .push(alter(&name, TableAlterOption::DropPrimaryKey(have_pk_name)));
+                }
+                if !want_pk.is_empty() {
+                    let mut index = IndexCreateStatement::new();
+                    index.primary = true;
+                    index.index.name = want_pk_name;
+                    for column in want_pk {
+                        index.col(Alias::new(&column));
+                    }
+                    add_primary_keys.push(alter(&name, TableAlterOption::AddPrimaryKey(index)));
+                }
+            }
+
+            for index in indexes(have) {
+                if !indexes(want).any(|i| same_index(i, index)) && index.index.name.is_some() {
+                    drop_indexes.push(drop_index(&name, index));
+                }
+            }
+            for index in indexes(want) {
+                if !indexes(have).any(|i| same_index(i, index)) {
+                    create_indexes.push(create_index(&name, index));
+                }
+            }
+
+            for foreign_key in &have.foreign_keys {
+                if !want
+                    .foreign_keys
+                    .iter()
+                    .any(|f| same_foreign_key(f, foreign_key))
+                    && foreign_key.foreign_key.name.is_some()
+                {
+                    drop_foreign_keys.push(drop_foreign_key(&name, foreign_key));
+                }
+            }
+            for foreign_key in &want.foreign_keys {
+                if !have
+                    .foreign_keys
+                    .iter()
+                    .any(|f| same_foreign_key(foreign_key, f))
+                {
+                    create_foreign_keys.push(create_foreign_key(&name, foreign_key));
+                }
+            }
+        }
+
+        for (have, matched) in actual.iter().zip(matched) {
+            if matched {
+                continue;
+            }
+            if let Some(table) = have.table.as_ref() {
+                let mut drop = TableDropStatement::new();
+                drop.tables.push(table.clone());
+                drop_tables.push(drop);
+            }
+        }
+
+        renames
+            .into_iter()
+            .chain(
+                drop_foreign_keys.into_iter().map(|stmt| {
+                    SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Drop(stmt))
+                }),
+            )
+            .chain(
+                drop_indexes
+                    .into_iter()
+                    .map(|stmt| SchemaStatement::IndexStatement(IndexStatement::Drop(stmt))),
+            )
+            .chain(
+                drop_primary_keys
+                    .into_iter()
+                    .map(|stmt| SchemaStatement::TableStatement(TableStatement::Alter(stmt))),
+            )
+            .chain(
+                create_tables
+                    .into_iter()
+                    .map(|stmt| SchemaStatement::TableStatement(TableStatement::Create(stmt))),
+            )
+            .chain(
+                alter_columns
+                    .into_iter()
+                    .map(|stmt| SchemaStatement::TableStatement(TableStatement::Alter(stmt))),
+            )
+            .chain(
+                add_primary_keys
+                    .into_iter()
+                    .map(|stmt| SchemaStatement::TableStatement(TableStatement::Alter(stmt))),
+            )
+            .chain(
+                create_indexes
+                    .into_iter()
+                    .map(|stmt| SchemaStatement::IndexStatement(IndexStatement::Create(stmt))),
+            )
+            .chain(create_foreign_keys.into_iter().map(|stmt| {
+                SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Create(stmt))
+            }))
+            .chain(
+                drop_tables
+                    .into_iter()
+                    .map(|stmt| SchemaStatement::TableStatement(TableStatement::Drop(stmt))),
+            )
+            .collect()
+    }
+
+    /// The desired name of the actual column `column` of the desired table `table`
+    fn renamed_column<'a>(&'a self, table: &str, column: &'a str) -> &'a str {
+        self.column_renames
+            .iter()
+            .find(|(t, from, _)| t == table && from == column)
+            .map(|(_, _, to)| to.as_str())
+            .unwrap_or(column)
+    }
+
+    /// Column changes of a table present on both sides, ordered as renames, adds, modifies and drops.
+    /// The primary key is diffed separately, so column definitions leave it out.
+    fn diff_columns(
+        &self,
+        schema_builder: &dyn SchemaBuilder,
+        table: &DynIden,
+        want: &TableCreateStatement,
+        have: &TableCreateStatement,
+    ) -> Vec<TableAlterStatement> {
+        let table_name = table.to_string();
+        let (_, want_pk) = primary_key(want);
+
+        let mut renames = Vec::new();
+        let mut adds = Vec::new();
+        let mut modifies = Vec::new();
+        let mut drops = Vec::new();
+
+        for column in &have.columns {
+            let have_column = column.name.to_string();
+            let want_column = self.renamed_column(&table_name, &have_column);
+            match want
+                .columns
+                .iter()
+                .find(|c| c.name.to_string() == want_column)
+            {
+                Some(want_def) => {
+                    if have_column != want_column {
+                        renames.push(alter(
+                            table,
+                            TableAlterOption::RenameColumn(
+                                column.name.clone(),
+                                want_def.name.clone(),
+                            ),
+                        ));
+                    }
+                    if !same_column(schema_builder, want_def, column) {
+                        let mut def = column_def(want_def);
+                        // Relax NOT NULL explicitly, as omitting it keeps the constraint on Postgres
+                        if has_spec(column, |s| matches!(s, ColumnSpec::NotNull))
+                            && !has_spec(&def, |s| matches!(s, ColumnSpec::NotNull))
+                            && !want_pk.iter().any(|c| c == want_column)
+                        {
+                            def.spec.push(ColumnSpec::Null);
+                        }
+                        modifies.push(alter(table, TableAlterOption::ModifyColumn(def)));
+                        if has_spec(column, |s| matches!(s, ColumnSpec::Default(_)))
+                            && !has_spec(want_def, |s| matches!(s, ColumnSpec::Default(_)))
+                        {
+                            modifies.push(alter(
+                                table,
+                                TableAlterOption::DropColumnDefault(want_def.name.clone()),
+                            ));
+                        }
+                    }
+                }
+                None => drops.push(alter(
+                    table,
+                    TableAlterOption::DropColumn(column.name.clone()),
+                )),
+            }
+        }
+
+        for column in &want.columns {
+            let want_column = column.name.to_string();
+            let exists = have
+                .columns
+                .iter()
+                .any(|c| self.renamed_column(&table_name, &c.name.to_string()) == want_column);
+            if !exists {
+                adds.push(alter(
+                    table,
+                    TableAlterOption::AddColumn(column_def(column)),
+                ));
+            }
+        }
+
+        renames
+            .into_iter()
+            .chain(adds)
+            .chain(modifies)
+            .chain(drops)
+            .collect()
+    }
+}
+
+/// Secondary indexes; the primary key is diffed on its own
+fn indexes(table: &TableCreateStatement) -> impl Iterator<Item = &IndexCreateStatement> {
+    table.indexes.iter().filter(|index| !index.primary)
+}
+
+/// Constraint name and columns of the primary key, declared either on the table or on a column
+fn primary_key(table: &TableCreateStatement) -> (Option<String>, Vec<String>) {
+    match table.indexes.iter().find(|index| index.primary) {
+        Some(index) => (
+            index.index.name.clone(),
+            index
+                .index
+                .columns
+                .iter()
+                .map(|column| column.name.to_string())
+                .collect(),
+        ),
+        None => (
+            None,
+            table
+                .columns
+                .iter()
+                .filter(|column| has_spec(column, |s| matches!(s, ColumnSpec::PrimaryKey)))
+                .map(|column| column.name.to_string())
+                .collect(),
+        ),
+    }
+}
+
+fn has_spec(column: &ColumnDef, f: impl Fn(&ColumnSpec) -> bool) -> bool {
+    column.spec.iter().any(f)
+}
+
+/// Column definition for `ALTER TABLE`, without the primary key
+fn column_def(column: &ColumnDef) -> ColumnDef {
+    let mut def = column.clone();
+    def.table = None;
+    def.spec
+        .retain(|spec| !matches!(spec, ColumnSpec::PrimaryKey));
+    def
+}
+
+/// Drop the display width of integers, which does not change the type
+fn normalize_type(column_type: &ColumnType) -> ColumnType {
+    match column_type {
+        ColumnType::TinyInteger(_) => ColumnType::TinyInteger(None),
+        ColumnType::SmallInteger(_) => ColumnType::SmallInteger(None),
+        ColumnType::Integer(_) => ColumnType::Integer(None),
+        ColumnType::BigInteger(_) => ColumnType::BigInteger(None),
+        column_type => column_type.clone(),
+    }
+}
+
+// The statement types do not implement `PartialEq`, so definitions are compared
+// by their `Debug` output, which spells out every identifier, type and expression.
+// Column types and defaults are rendered instead, as the database reports them in its own words.
+
+fn same_column(schema_builder: &dyn SchemaBuilder, a: &ColumnDef, b: &ColumnDef) -> bool {
+    let key = |c: &ColumnDef| {
+        // `NULL` is the default and the primary key is diffed on its own
+        let mut spec: Vec<String> = c
+            .spec
+            .iter()
+            .filter(|spec| !matches!(spec, ColumnSpec::Null | ColumnSpec::PrimaryKey))
+            .map(|spec| match spec {
+                ColumnSpec::Default(_) => {
+                    let mut sql = SqlWriter::new();
+                    schema_builder.prepare_column_spec(spec, &mut sql);
+                    sql.result()
+                }
+                spec => format!("{:?}", spec),
+            })
+            .collect();
+        spec.sort();
+        let column_type = c.types.as_ref().map(|column_type| {
+            let mut sql = SqlWriter::new();
+            schema_builder.prepare_column_type(&normalize_type(column_type), &mut sql);
+            sql.result().to_lowercase()
+        });
+        format!("{:?}{:?}", column_type, spec)
+    };
+    key(a) == key(b)
+}
+
+fn same_index(a: &IndexCreateStatement, b: &IndexCreateStatement) -> bool {
+    let key = |i: &IndexCreateStatement| {
+        format!(
+            "{:?}{:?}{:?}{:?}{:?}",
+            i.index, i.unique, i.index_type, i.include_columns, i.r#where
+        )
+    };
+    key(a) == key(b)
+}
+
+/// The name is only compared if the desired foreign key has one, as SQLite does not keep it.
+/// An action left out is `NO ACTION`, which is what the database reports.
+fn same_foreign_key(want: &ForeignKeyCreateStatement, have: &ForeignKeyCreateStatement) -> bool {
+    let key = |f: &ForeignKeyCreateStatement| {
+        let f = &f.foreign_key;
+        format!(
+            "{:?}{:?}{:?}{:?}{:?}",
+            f.ref_table,
+            f.columns,
+            f.ref_columns,
+            f.on_delete.unwrap_or(ForeignKeyAction::NoAction),
+            f.on_update.unwrap_or(ForeignKeyAction::NoAction)
+        )
+    };
+    (want.foreign_key.name.is_none() || want.foreign_key.name == have.foreign_key.name)
+        && key(want) == key(have)
+}
+
+fn alter(table: &DynIden, option: TableAlterOption) -> TableAlterStatement {
+    let mut stmt = TableAlterStatement::new();
+    stmt.table = Some(table.clone());
+    stmt.alter_option = Some(option);
+    stmt
+}
+
+fn create_index(table: &DynIden, index: &IndexCreateStatement) -> IndexCreateStatement {
+    let mut stmt = index.clone();
+    stmt.table = Some(table.clone());
+    stmt
+}
+
+fn drop_index(table: &DynIden, index: &IndexCreateStatement) -> IndexDropStatement {
+    let mut stmt = IndexDropStatement::new();
+    stmt.table = Some(table.clone());
+    stmt.index = index.index.clone();
+    stmt
+}
+
+fn create_foreign_key(
+    table: &DynIden,
+    foreign_key: &ForeignKeyCreateStatement,
+) -> ForeignKeyCreateStatement {
+    let mut stmt = foreign_key.clone();
+    stmt.foreign_key.table = Some(table.clone());
+    stmt
+}
+
+fn drop_foreign_key(
+    table: &DynIden,
+    foreign_key: &ForeignKeyCreateStatement,
+) -> ForeignKeyDropStatement {
+    let mut stmt = ForeignKeyDropStatement::new();
+    stmt.table = Some(table.clone());
+    stmt.foreign_key.name = foreign_key.foreign_key.name.clone();
+    stmt
+}
@@ -0,0 +1,281 @@
+//! Discovering existing database schema.
+//!
+//! Each backend module builds the catalog queries as [`SelectStatement`](crate::SelectStatement)s
+//! and defines the row types they return. Fetch the rows with the database driver of your choice,
+//! then convert them into a [`DiscoveredTable`], which in turn produces [`TableCreateStatement`],
+//! [`IndexCreateStatement`] and [`ForeignKeyCreateStatement`].
+//!
+//! # Usage
+//!
+//! - MySQL, see [`mysql`]
+//! - Postgres, see [`postgres`]
+//! - SQLite, see [`sqlite`]
+
+use crate::{
+    Alias, ColumnDef, ColumnType, Expr, ForeignKeyAction, ForeignKeyCreateStatement,
+    IndexCreateStatement, IntoIden, TableCreateStatement,
+};
+
+#[cfg(feature = "backend-mysql")]
+#[cfg_attr(docsrs, doc(cfg(feature = "backend-mysql")))]
+pub mod mysql;
+#[cfg(feature = "backend-postgres")]
+#[cfg_attr(docsrs, doc(cfg(feature = "backend-postgres")))]
+pub mod postgres;
+#[cfg(feature = "backend-sqlite")]
+#[cfg_attr(docsrs, doc(cfg(feature = "backend-sqlite")))]
+pub mod sqlite;
+
+/// A table read back from the database
+#[derive(Debug, Clone)]
+pub struct DiscoveredTable {
+    pub name: String,
+    pub columns: Vec<DiscoveredColumn>,
+    /// Primary key columns, in key order
+    pub primary_key: Vec<String>,
+    pub indexes: Vec<DiscoveredIndex>,
+    pub foreign_keys: Vec<DiscoveredForeignKey>,
+}
+
+/// A column read back from the database
+#[derive(Debug, Clone)]
+pub struct DiscoveredColumn {
+    pub name: String,
+    pub column_type: ColumnType,
+    pub not_null: bool,
+    /// Default expression, as written in SQL
+    pub default: Option<String>,
+    pub auto_increment: bool,
+    pub unique: bool,
+}
+
+/// An index read back from the database, excluding the primary key
+#[derive(Debug, Clone)]
+pub struct DiscoveredIndex {
+    pub name: String,
+    pub columns: Vec<String>,
+    pub unique: bool,
+}
+
+/// A foreign key read back from the database
+#[derive(Debug, Clone)]
+pub struct DiscoveredForeignKey {
+    /// Constraint name, SQLite does not keep one
+    pub name: Option<String>,
+    pub columns: Vec<String>,
+    pub ref_table: String,
+    /// Empty if the referenced columns could not be resolved
+    pub ref_columns: Vec<String>,
+    pub on_delete: Option<ForeignKeyAction>,
+    pub on_update: Option<ForeignKeyAction>,
+}
+
+impl DiscoveredTable {
+    /// Convert into [`TableCreateStatement`], with columns, primary key and foreign keys.
+    /// Indexes are returned separately by [`DiscoveredTable::to_index_create_statements`].
+    pub fn to_table_create_statement(&self) -> TableCreateStatement {
+        let mut table = TableCreateStatement::new();
+        table.table(Alias::new(&self.name));
+
+        let composite_primary_key = self.primary_key.len() > 1;
+
+        for column in self.columns.iter() {
+            let mut column_def = column.to_column_def();
+            if !composite_primary_key && self.primary_key.contains(&column.name) {
+                column_def.primary_key();
+            }
+            table.col(&mut column_def);
+        }
+
+        if composite_primary_key {
+            let mut primary_key = IndexCreateStatement::new();
+            for column in self.primary_key.iter() {
+                primary_key.col(Alias::new(column));
+            }
+            table.primary_key(&mut primary_key);
+        }
+
+        for foreign_key in self.foreign_keys.iter() {
+            table.foreign_key(&mut foreign_key.to_foreign_key_create_statement(&self.name));
+        }
+
+        table
+    }
+
+    /// Convert the indexes into [`IndexCreateStatement`]s
+    pub fn to_index_create_statements(&self) -> Vec<IndexCreateStatement> {
+        self.indexes
+            .iter()
+            .map(|index| index.to_index_create_statement(&self.name))
+            .collect()
+    }
+}
+
+impl DiscoveredColumn {
+    /// Convert into [`ColumnDef`]
+    pub fn to_column_def(&self) -> ColumnDef {
+        let mut column_def =
+            ColumnDef::new_with_type(Alias::new(&self.name), self.column_type.clone());
+        if self.not_null {
+            column_def.not_null();
+        }
+        if let Some(default) = &self.default {
+            column_def.default(Expr::cust(default));
+        }
+        if self.auto_increment {
+            column_def.auto_increment();
+        }
+        if self.unique {
+            column_def.unique_key();
+        }
+        column_def
+    }
+}
+
+impl DiscoveredIndex {
+    /// Convert into [`IndexCreateStatement`]
+    pub fn to_index_create_statement(&self, table: &str) -> IndexCreateStatement {
+        let mut index = IndexCreateStatement::new();
+        index.name(&self.name).table(Alias::new(table));
+        for column in self.columns.iter() {
+            index.col(Alias::new(column));
+        }
+        if self.unique {
+            index.unique();
+        }
+        index
+    }
+}
+
+impl DiscoveredForeignKey {
+    /// Convert into [`ForeignKeyCreateStatement`]
+    pub fn to_foreign_key_create_statement(&self, table: &str) -> ForeignKeyCreateStatement {
+        let mut foreign_key = ForeignKeyCreateStatement::new();
+        if let Some(name) = &self.name {
+            foreign_key.name(name);
+        }
+        foreign_key
+            .from_tbl(Alias::new(table))
+            .to_tbl(Alias::new(&self.ref_table));
+        for column in self.columns.iter() {
+            foreign_key.from_col(Alias::new(column));
+        }
+        for column in self.ref_columns.iter() {
+            foreign_key.to_col(Alias::new(column));
+        }
+        if let Some(action) = &self.on_delete {
+            foreign_key.on_delete(*action);
+        }
+        if let Some(action) = &self.on_update {
+            foreign_key.on_update(*action);
+        }
+        foreign_key
+    }
+}
+
+/// Parse a column type as reported by the database, e.g. `varchar(255)` or `numeric(10, 2)`.
+/// Unknown types are kept as [`ColumnType::Custom`].
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{schema::discovery::parse_column_type, *};
+///
+/// assert!(matches!(
+///     parse_column_type("VARCHAR(255)"),
+///     ColumnType::String(Some(255))
+/// ));
+/// assert!(matches!(
+///     parse_column_type("numeric(10, 2)"),
+///     ColumnType::Decimal(Some((10, 2)))
+/// ));
+/// assert!(matches!(
+///     parse_column_type("int(10) unsigned"),
+///     ColumnType::Integer(Some(10))
+/// ));
+/// ```
+pub fn parse_column_type(column_type: &str) -> ColumnType {
+    let lowercase = column_type.trim().to_lowercase();
+    let (base, args) = match lowercase.find('(') {
+        Some(open) => {
+            let close = lowercase.rfind(')').unwrap_or(lowercase.len());
+            let args: Vec<u32> = lowercase[open + 1..close]
+                .split(',')
+                .filter_map(|arg| arg.trim().parse().ok())
+                .collect();
+            let rest = lowercase[(close + 1).min(lowercase.len())..].trim();
+            let base = lowercase[..open].trim();
+            (
+                if rest.is_empty() {
+                    base.to_owned()
+                } else {
+                    format!("{} {}", base, rest)
+                },
+                args,
+            )
+        }
+        None => (lowercase.clone(), Vec::new()),
+    };
+    let base = base.trim_end_matches(" unsigned").trim();
+    let len = args.first().copied();
+    match base {
+        "char" | "character" | "bpchar" | "nchar" => ColumnType::Char(len),
+        "varchar" | "character varying" | "nvarchar" => ColumnType::String(len),
+        "text" | "tinytext" | "mediumtext" | "longtext" | "clob" => ColumnType::Text,
+        "tinyint" => ColumnType::TinyInteger(len),
+        "smallint" | "int2" => ColumnType::SmallInteger(len),
+        "int" | "integer" | "int4" | "mediumint" => ColumnType::Integer(len),
+        "bigint" | "int8" => ColumnType::BigInteger(len),
+        // SQLite writes decimal as `real(precision, scale)`
+        "real" if args.len() == 2 => ColumnType::Decimal(Some((args[0], args[1]))),
+        "float" | "real" | "float4" => ColumnType::Float(len),
+        "double" | "double precision" | "float8" => ColumnType::Double(len),
+        "decimal" | "numeric" => ColumnType::Decimal(match args.as_slice() {
+            [precision, scale] => Some((*precision, *scale)),
+            [precision] => Some((*precision, 0)),
+            _ => None,
+        }),
+        "datetime" => ColumnType::DateTime(len),
+        "timestamp" | "timestamp without time zone" => ColumnType::Timestamp(len),
+        "timestamptz" | "timestamp with time zone" => ColumnType::TimestampWithTimeZone(len),
+        "time" | "time without time zone" => ColumnType::Time(len),
+        "date" => ColumnType::Date,
+        "interval" => ColumnType::Interval(None, len),
+        "binary" | "varbinary" | "blob" | "bytea" => ColumnType::Binary(len),
+        "bool" | "boolean" => ColumnType::Boolean,
+        "money" => ColumnType::Money(None),
+        "json" => ColumnType::Json,
+        "jsonb" => ColumnType::JsonBinary,
+        "uuid" => ColumnType::Uuid,
+        _ => ColumnType::Custom(Alias::new(column_type.trim()).into_iden()),
+    }
+}
+
+/// Parse a referential action as reported by the database, e.g. `SET NULL`
+pub fn parse_foreign_key_action(action: &str) -> Option<ForeignKeyAction> {
+    match action.trim().to_uppercase().as_str() {
+        "CASCADE" => Some(ForeignKeyAction::Cascade),
+        "SET NULL" => Some(ForeignKeyAction::SetNull),
+        "SET DEFAULT" => Some(ForeignKeyAction::SetDefault),
+        "RESTRICT" => Some(ForeignKeyAction::Restrict),
+        "NO ACTION" => Some(ForeignKeyAction::NoAction),
+        _ => None,
+    }
+}
+
+/// Group `(key, value)` pairs by key, in the order each key first appears.
+/// Keys are compared linearly, which suits the handful of indexes and foreign keys of a table.
+pub(crate) fn group_by_key<K, V, I>(rows: I) -> Vec<(K, Vec<V>)>
+where
+    K: PartialEq,
+    I: IntoIterator<Item = (K, V)>,
+{
+    let mut groups: Vec<(K, Vec<V>)> = Vec::new();
+    for (key, value) in rows.into_iter() {
+        match groups.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, values)) => values.push(value),
+            None => groups.push((key, vec![value])),
+        }
+    }
+    groups
+}
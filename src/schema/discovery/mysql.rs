@@ -0,0 +1,250 @@
+//! Discovering MySQL schema from `information_schema`.
+//!
+//! # Examples
+//!
+//! ```
+//! use sea_query::{schema::discovery::mysql, *};
+//!
+//! assert_eq!(
+//!     mysql::tables_query("shop").to_string(MysqlQueryBuilder),
+//!     "SELECT `table_name` FROM `information_schema`.`tables` WHERE `table_schema` = 'shop' AND `table_type` = 'BASE TABLE' ORDER BY `table_name` ASC"
+//! );
+//! assert_eq!(
+//!     mysql::columns_query("shop", "glyph").to_string(MysqlQueryBuilder),
+//!     "SELECT `column_name`, `column_type`, `is_nullable`, `column_default`, `column_key`, `extra` FROM `information_schema`.`columns` WHERE `table_schema` = 'shop' AND `table_name` = 'glyph' ORDER BY `ordinal_position` ASC"
+//! );
+//! ```
+
+use super::{
+    group_by_key, parse_column_type, parse_foreign_key_action, DiscoveredColumn,
+    DiscoveredForeignKey, DiscoveredIndex, DiscoveredTable,
+};
+use crate::{Alias, ColumnType, Condition, Expr, JoinType, Order, Query, SelectStatement};
+
+/// Row of [`columns_query`]
+#[derive(Debug, Clone)]
+pub struct ColumnRow {
+    pub column_name: String,
+    pub column_type: String,
+    pub is_nullable: String,
+    pub column_default: Option<String>,
+    pub column_key: String,
+    pub extra: String,
+}
+
+/// Row of [`indexes_query`]
+#[derive(Debug, Clone)]
+pub struct IndexRow {
+    pub index_name: String,
+    pub column_name: String,
+    pub non_unique: bool,
+}
+
+/// Row of [`foreign_keys_query`]
+#[derive(Debug, Clone)]
+pub struct ForeignKeyRow {
+    pub constraint_name: String,
+    pub column_name: String,
+    pub referenced_table_name: String,
+    pub referenced_column_name: String,
+    pub update_rule: String,
+    pub delete_rule: String,
+}
+
+/// Select the names of all base tables in a schema
+pub fn tables_query(schema: &str) -> SelectStatement {
+    Query::select()
+        .column(Alias::new("table_name"))
+        .from((Alias::new("information_schema"), Alias::new("tables")))
+        .and_where(Expr::col(Alias::new("table_schema")).eq(schema))
+        .and_where(Expr::col(Alias::new("table_type")).eq("BASE TABLE"))
+        .order_by(Alias::new("table_name"), Order::Asc)
+        .take()
+}
+
+/// Select the columns of a table, see [`ColumnRow`]
+pub fn columns_query(schema: &str, table: &str) -> SelectStatement {
+    Query::select()
+        .columns(vec![
+            Alias::new("column_name"),
+            Alias::new("column_type"),
+            Alias::new("is_nullable"),
+            Alias::new("column_default"),
+            Alias::new("column_key"),
+            Alias::new("extra"),
+        ])
+        .from((Alias::new("information_schema"), Alias::new("columns")))
+        .and_where(Expr::col(Alias::new("table_schema")).eq(schema))
+        .and_where(Expr::col(Alias::new("table_name")).eq(table))
+        .order_by(Alias::new("ordinal_position"), Order::Asc)
+        .take()
+}
+
+/// Select the indexes of a table, one row per column, see [`IndexRow`]
+pub fn indexes_query(schema: &str, table: &str) -> SelectStatement {
+    Query::select()
+        .columns(vec![
+            Alias::new("index_name"),
+            Alias::new("column_name"),
+            Alias::new("non_unique"),
+        ])
+        .from((Alias::new("information_schema"), Alias::new("statistics")))
+        .and_where(Expr::col(Alias::new("table_schema")).eq(schema))
+        .and_where(Expr::col(Alias::new("table_name")).eq(table))
+        .order_by(Alias::new("index_name"), Order::Asc)
+        .order_by(Alias::new("seq_in_index"), Order::Asc)
+        .take()
+}
+
+/// Select the foreign keys of a table, one row per column, see [`ForeignKeyRow`]
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{schema::discovery::mysql, *};
+///
+/// assert_eq!(
+///     mysql::foreign_keys_query("shop", "glyph").to_string(MysqlQueryBuilder),
+///     [
+///         "SELECT `k`.`constraint_name`, `k`.`column_name`, `k`.`referenced_table_name`, `k`.`referenced_column_name`, `r`.`update_rule`, `r`.`delete_rule`",
+///         "FROM `information_schema`.`key_column_usage` AS `k`",
+///         "INNER JOIN `information_schema`.`referential_constraints` AS `r` ON `k`.`constraint_schema` = `r`.`constraint_schema` AND `k`.`constraint_name` = `r`.`constraint_name`",
+///         "WHERE `k`.`table_schema` = 'shop' AND `k`.`table_name` = 'glyph' AND `k`.`referenced_table_name` IS NOT NULL",
+///         "ORDER BY `k`.`constraint_name` ASC, `k`.`ordinal_position` ASC",
+///     ]
+///     .join(" ")
+/// );
+/// ```
+pub fn foreign_keys_query(schema: &str, table: &str) -> SelectStatement {
+    let k = Alias::new("k");
+    let r = Alias::new("r");
+    Query::select()
+        .columns(vec![
+            (k.clone(), Alias::new("constraint_name")),
+            (k.clone(), Alias::new("column_name")),
+            (k.clone(), Alias::new("referenced_table_name")),
+            (k.clone(), Alias::new("referenced_column_name")),
+            (r.clone(), Alias::new("update_rule")),
+            (r.clone(), Alias::new("delete_rule")),
+        ])
+        .from_as(
+            (
+                Alias::new("information_schema"),
+                Alias::new("key_column_usage"),
+            ),
+            k.clone(),
+        )
+        .join_as(
+            JoinType::InnerJoin,
+            (
+                Alias::new("information_schema"),
+                Alias::new("referential_constraints"),
+            ),
+            r.clone(),
+            Condition::all()
+                .add(
+                    Expr::tbl(k.clone(), Alias::new("constraint_schema"))
+                        .equals(r.clone(), Alias::new("constraint_schema")),
+                )
+                .add(
+                    Expr::tbl(k.clone(), Alias::new("constraint_name"))
+                        .equals(r, Alias::new("constraint_name")),
+                ),
+        )
+        .and_where(Expr::tbl(k.clone(), Alias::new("table_schema")).eq(schema))
+        .and_where(Expr::tbl(k.clone(), Alias::new("table_name")).eq(table))
+        .and_where(Expr::tbl(k.clone(), Alias::new("referenced_table_name")).is_not_null())
+        .order_by((k.clone(), Alias::new("constraint_name")), Order::Asc)
+        .order_by((k, Alias::new("ordinal_position")), Order::Asc)
+        .take()
+}
+
+/// Assemble the rows of one table into [`DiscoveredTable`]
+pub fn discover_table(
+    table: &str,
+    columns: Vec<ColumnRow>,
+    indexes: Vec<IndexRow>,
+    foreign_keys: Vec<ForeignKeyRow>,
+) -> DiscoveredTable {
+    let columns = columns
+        .into_iter()
+        .map(|column| {
+            let column_type = parse_column_type(&column.column_type);
+            let extra = column.extra.to_lowercase();
+            let default = column.column_default.map(|default| {
+                let literal = matches!(
+                    column_type,
+                    ColumnType::Char(_)
+                        | ColumnType::String(_)
+                        | ColumnType::Text
+                        | ColumnType::Date
+                        | ColumnType::DateTime(_)
+                        | ColumnType::Timestamp(_)
+                        | ColumnType::Time(_)
+                        | ColumnType::Enum(_, _)
+                        | ColumnType::Custom(_)
+                );
+                if literal && !extra.contains("default_generated") {
+                    format!("'{}'", default.replace('\'', "''"))
+                } else {
+                    default
+                }
+            });
+            DiscoveredColumn {
+                name: column.column_name,
+                column_type,
+                not_null: column.is_nullable.eq_ignore_ascii_case("NO"),
+                default,
+                auto_increment: extra.contains("auto_increment"),
+                unique: false,
+            }
+        })
+        .collect();
+
+    let mut indexes = group_by_key(indexes.into_iter().map(|row| (row.index_name.clone(), row)));
+    let primary_key: Vec<String> = match indexes.iter().position(|(name, _)| name == "PRIMARY") {
+        Some(position) => indexes
+            .remove(position)
+            .1
+            .into_iter()
+            .map(|row| row.column_name)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let indexes = indexes
+        .into_iter()
+        .map(|(name, rows)| DiscoveredIndex {
+            name,
+            unique: !rows[0].non_unique,
+            columns: rows.into_iter().map(|row| row.column_name).collect(),
+        })
+        .collect();
+
+    let foreign_keys = group_by_key(
+        foreign_keys
+            .into_iter()
+            .map(|row| (row.constraint_name.clone(), row)),
+    )
+    .into_iter()
+    .map(|(name, rows)| DiscoveredForeignKey {
+        name: Some(name),
+        columns: rows.iter().map(|row| row.column_name.clone()).collect(),
+        ref_table: rows[0].referenced_table_name.clone(),
+        ref_columns: rows
+            .iter()
+            .map(|row| row.referenced_column_name.clone())
+            .collect(),
+        on_delete: parse_foreign_key_action(&rows[0].delete_rule),
+        on_update: parse_foreign_key_action(&rows[0].update_rule),
+    })
+    .collect();
+
+    DiscoveredTable {
+        name: table.to_owned(),
+        columns,
+        primary_key,
+        indexes,
+        foreign_keys,
+    }
+}
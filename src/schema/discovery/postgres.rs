@@ -0,0 +1,343 @@
+//! Discovering Postgres schema from `information_schema` and, for indexes, `pg_catalog`.
+//!
+//! # Examples
+//!
+//! ```
+//! use sea_query::{schema::discovery::postgres, *};
+//!
+//! assert_eq!(
+//!     postgres::tables_query("public").to_string(PostgresQueryBuilder),
+//!     r#"SELECT "table_name" FROM "information_schema"."tables" WHERE "table_schema" = 'public' AND "table_type" = 'BASE TABLE' ORDER BY "table_name" ASC"#
+//! );
+//! ```
+
+use super::{
+    group_by_key, parse_column_type, parse_foreign_key_action, DiscoveredColumn,
+    DiscoveredForeignKey, DiscoveredIndex, DiscoveredTable,
+};
+use crate::{
+    Alias, ColumnType, Condition, Expr, IntoIden, JoinType, Order, Query, SelectStatement,
+};
+
+/// Row of [`columns_query`]
+#[derive(Debug, Clone)]
+pub struct ColumnRow {
+    pub column_name: String,
+    pub data_type: String,
+    pub udt_name: String,
+    pub character_maximum_length: Option<i32>,
+    pub numeric_precision: Option<i32>,
+    pub numeric_scale: Option<i32>,
+    pub is_nullable: String,
+    pub column_default: Option<String>,
+}
+
+/// Row of [`indexes_query`]
+#[derive(Debug, Clone)]
+pub struct IndexRow {
+    pub index_name: String,
+    pub column_name: String,
+    pub is_unique: bool,
+    pub is_primary: bool,
+}
+
+/// Row of [`foreign_keys_query`]
+#[derive(Debug, Clone)]
+pub struct ForeignKeyRow {
+    pub constraint_name: String,
+    pub column_name: String,
+    pub referenced_table_name: String,
+    pub referenced_column_name: String,
+    pub update_rule: String,
+    pub delete_rule: String,
+}
+
+/// Select the names of all base tables in a schema
+pub fn tables_query(schema: &str) -> SelectStatement {
+    Query::select()
+        .column(Alias::new("table_name"))
+        .from((Alias::new("information_schema"), Alias::new("tables")))
+        .and_where(Expr::col(Alias::new("table_schema")).eq(schema))
+        .and_where(Expr::col(Alias::new("table_type")).eq("BASE TABLE"))
+        .order_by(Alias::new("table_name"), Order::Asc)
+        .take()
+}
+
+/// Select the columns of a table, see [`ColumnRow`]
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{schema::discovery::postgres, *};
+///
+/// assert_eq!(
+///     postgres::columns_query("public", "glyph").to_string(PostgresQueryBuilder),
+///     [
+///         r#"SELECT "column_name", "data_type", "udt_name", "character_maximum_length", "numeric_precision", "numeric_scale", "is_nullable", "column_default""#,
+///         r#"FROM "information_schema"."columns""#,
+///         r#"WHERE "table_schema" = 'public' AND "table_name" = 'glyph'"#,
+///         r#"ORDER BY "ordinal_position" ASC"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+pub fn columns_query(schema: &str, table: &str) -> SelectStatement {
+    Query::select()
+        .columns(vec![
+            Alias::new("column_name"),
+            Alias::new("data_type"),
+            Alias::new("udt_name"),
+            Alias::new("character_maximum_length"),
+            Alias::new("numeric_precision"),
+            Alias::new("numeric_scale"),
+            Alias::new("is_nullable"),
+            Alias::new("column_default"),
+        ])
+        .from((Alias::new("information_schema"), Alias::new("columns")))
+        .and_where(Expr::col(Alias::new("table_schema")).eq(schema))
+        .and_where(Expr::col(Alias::new("table_name")).eq(table))
+        .order_by(Alias::new("ordinal_position"), Order::Asc)
+        .take()
+}
+
+/// Select the indexes of a table, one row per column, see [`IndexRow`]
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{schema::discovery::postgres, *};
+///
+/// assert_eq!(
+///     postgres::indexes_query("public", "glyph").to_string(PostgresQueryBuilder),
+///     [
+///         r#"SELECT "i"."relname" AS "index_name", "a"."attname" AS "column_name", "x"."indisunique" AS "is_unique", "x"."indisprimary" AS "is_primary""#,
+///         r#"FROM "pg_catalog"."pg_index" AS "x""#,
+///         r#"INNER JOIN "pg_catalog"."pg_class" AS "t" ON "t"."oid" = "x"."indrelid""#,
+///         r#"INNER JOIN "pg_catalog"."pg_class" AS "i" ON "i"."oid" = "x"."indexrelid""#,
+///         r#"INNER JOIN "pg_catalog"."pg_namespace" AS "n" ON "n"."oid" = "t"."relnamespace""#,
+///         r#"INNER JOIN "pg_catalog"."pg_attribute" AS "a" ON "a"."attrelid" = "t"."oid" AND "a"."attnum" = ANY("x"."indkey")"#,
+///         r#"WHERE "n"."nspname" = 'public' AND "t"."relname" = 'glyph'"#,
+///         r#"ORDER BY "i"."relname" ASC, array_position("x"."indkey"::int2[], "a"."attnum") ASC"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+pub fn indexes_query(schema: &str, table: &str) -> SelectStatement {
+    let pg_catalog = Alias::new("pg_catalog");
+    let (x, t, i, n, a) = (
+        Alias::new("x"),
+        Alias::new("t"),
+        Alias::new("i"),
+        Alias::new("n"),
+        Alias::new("a"),
+    );
+    Query::select()
+        .expr_as(
+            Expr::tbl(i.clone(), Alias::new("relname")),
+            Alias::new("index_name"),
+        )
+        .expr_as(
+            Expr::tbl(a.clone(), Alias::new("attname")),
+            Alias::new("column_name"),
+        )
+        .expr_as(
+            Expr::tbl(x.clone(), Alias::new("indisunique")),
+            Alias::new("is_unique"),
+        )
+        .expr_as(
+            Expr::tbl(x.clone(), Alias::new("indisprimary")),
+            Alias::new("is_primary"),
+        )
+        .from_as((pg_catalog.clone(), Alias::new("pg_index")), x.clone())
+        .join_as(
+            JoinType::InnerJoin,
+            (pg_catalog.clone(), Alias::new("pg_class")),
+            t.clone(),
+            Expr::tbl(t.clone(), Alias::new("oid")).equals(x.clone(), Alias::new("indrelid")),
+        )
+        .join_as(
+            JoinType::InnerJoin,
+            (pg_catalog.clone(), Alias::new("pg_class")),
+            i.clone(),
+            Expr::tbl(i.clone(), Alias::new("oid")).equals(x, Alias::new("indexrelid")),
+        )
+        .join_as(
+            JoinType::InnerJoin,
+            (pg_catalog.clone(), Alias::new("pg_namespace")),
+            n.clone(),
+            Expr::tbl(n.clone(), Alias::new("oid")).equals(t.clone(), Alias::new("relnamespace")),
+        )
+        .join_as(
+            JoinType::InnerJoin,
+            (pg_catalog, Alias::new("pg_attribute")),
+            a.clone(),
+            Condition::all()
+                .add(
+                    Expr::tbl(a.clone(), Alias::new("attrelid"))
+                        .equals(t.clone(), Alias::new("oid")),
+                )
+                .add(Expr::cust(r#""a"."attnum" = ANY("x"."indkey")"#)),
+        )
+        .and_where(Expr::tbl(n, Alias::new("nspname")).eq(schema))
+        .and_where(Expr::tbl(t, Alias::new("relname")).eq(table))
+        .order_by((i, Alias::new("relname")), Order::Asc)
+        .order_by_expr(
+            Expr::cust(r#"array_position("x"."indkey"::int2[], "a"."attnum")"#),
+            Order::Asc,
+        )
+        .take()
+}
+
+/// Select the foreign keys of a table, one row per column, see [`ForeignKeyRow`]
+pub fn foreign_keys_query(schema: &str, table: &str) -> SelectStatement {
+    let information_schema = Alias::new("information_schema");
+    let (k, r, u) = (Alias::new("k"), Alias::new("r"), Alias::new("u"));
+    Query::select()
+        .column((k.clone(), Alias::new("constraint_name")))
+        .column((k.clone(), Alias::new("column_name")))
+        .expr_as(
+            Expr::tbl(u.clone(), Alias::new("table_name")),
+            Alias::new("referenced_table_name"),
+        )
+        .expr_as(
+            Expr::tbl(u.clone(), Alias::new("column_name")),
+            Alias::new("referenced_column_name"),
+        )
+        .column((r.clone(), Alias::new("update_rule")))
+        .column((r.clone(), Alias::new("delete_rule")))
+        .from_as(
+            (information_schema.clone(), Alias::new("key_column_usage")),
+            k.clone(),
+        )
+        .join_as(
+            JoinType::InnerJoin,
+            (
+                information_schema.clone(),
+                Alias::new("referential_constraints"),
+            ),
+            r.clone(),
+            Condition::all()
+                .add(
+                    Expr::tbl(k.clone(), Alias::new("constraint_schema"))
+                        .equals(r.clone(), Alias::new("constraint_schema")),
+                )
+                .add(
+                    Expr::tbl(k.clone(), Alias::new("constraint_name"))
+                        .equals(r.clone(), Alias::new("constraint_name")),
+                ),
+        )
+        .join_as(
+            JoinType::InnerJoin,
+            (information_schema, Alias::new("key_column_usage")),
+            u.clone(),
+            Condition::all()
+                .add(
+                    Expr::tbl(u.clone(), Alias::new("constraint_schema"))
+                        .equals(r.clone(), Alias::new("unique_constraint_schema")),
+                )
+                .add(
+                    Expr::tbl(u.clone(), Alias::new("constraint_name"))
+                        .equals(r, Alias::new("unique_constraint_name")),
+                )
+                .add(
+                    Expr::tbl(u, Alias::new("ordinal_position"))
+                        .equals(k.clone(), Alias::new("position_in_unique_constraint")),
+                ),
+        )
+        .and_where(Expr::tbl(k.clone(), Alias::new("table_schema")).eq(schema))
+        .and_where(Expr::tbl(k.clone(), Alias::new("table_name")).eq(table))
+        .order_by((k.clone(), Alias::new("constraint_name")), Order::Asc)
+        .order_by((k, Alias::new("ordinal_position")), Order::Asc)
+        .take()
+}
+
+fn column_type(column: &ColumnRow) -> ColumnType {
+    let len = column.character_maximum_length.map(|len| len as u32);
+    match column.data_type.as_str() {
+        "character varying" => ColumnType::String(len),
+        "character" => ColumnType::Char(len),
+        "numeric" => ColumnType::Decimal(
+            column
+                .numeric_precision
+                .zip(column.numeric_scale)
+                .map(|(precision, scale)| (precision as u32, scale as u32)),
+        ),
+        "ARRAY" => ColumnType::Array(Some(column.udt_name.trim_start_matches('_').to_owned())),
+        "USER-DEFINED" => ColumnType::Custom(Alias::new(&column.udt_name).into_iden()),
+        data_type => parse_column_type(data_type),
+    }
+}
+
+/// Assemble the rows of one table into [`DiscoveredTable`]
+pub fn discover_table(
+    table: &str,
+    columns: Vec<ColumnRow>,
+    indexes: Vec<IndexRow>,
+    foreign_keys: Vec<ForeignKeyRow>,
+) -> DiscoveredTable {
+    let mut indexes = group_by_key(indexes.into_iter().map(|row| (row.index_name.clone(), row)));
+    let primary_key: Vec<String> = match indexes.iter().position(|(_, rows)| rows[0].is_primary) {
+        Some(position) => indexes
+            .remove(position)
+            .1
+            .into_iter()
+            .map(|row| row.column_name)
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let columns = columns
+        .into_iter()
+        .map(|column| {
+            let column_type = column_type(&column);
+            let auto_increment = matches!(
+                &column.column_default,
+                Some(default) if default.starts_with("nextval(")
+            );
+            DiscoveredColumn {
+                name: column.column_name,
+                column_type,
+                not_null: column.is_nullable.eq_ignore_ascii_case("NO"),
+                default: column.column_default.filter(|_| !auto_increment),
+                auto_increment,
+                unique: false,
+            }
+        })
+        .collect();
+
+    let indexes = indexes
+        .into_iter()
+        .map(|(name, rows)| DiscoveredIndex {
+            name,
+            unique: rows[0].is_unique,
+            columns: rows.into_iter().map(|row| row.column_name).collect(),
+        })
+        .collect();
+
+    let foreign_keys = group_by_key(
+        foreign_keys
+            .into_iter()
+            .map(|row| (row.constraint_name.clone(), row)),
+    )
+    .into_iter()
+    .map(|(name, rows)| DiscoveredForeignKey {
+        name: Some(name),
+        columns: rows.iter().map(|row| row.column_name.clone()).collect(),
+        ref_table: rows[0].referenced_table_name.clone(),
+        ref_columns: rows
+            .iter()
+            .map(|row| row.referenced_column_name.clone())
+            .collect(),
+        on_delete: parse_foreign_key_action(&rows[0].delete_rule),
+        on_update: parse_foreign_key_action(&rows[0].update_rule),
+    })
+    .collect();
+
+    DiscoveredTable {
+        name: table.to_owned(),
+        columns,
+        primary_key,
+        indexes,
+        foreign_keys,
+    }
+}
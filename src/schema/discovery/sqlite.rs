@@ -0,0 +1,351 @@
+//! Discovering SQLite schema from `sqlite_master` and the `pragma_*` table-valued functions.
+//!
+//! # Examples
+//!
+//! ```
+//! use sea_query::{schema::discovery::sqlite, *};
+//!
+//! assert_eq!(
+//!     sqlite::tables_query().to_string(SqliteQueryBuilder),
+//!     r#"SELECT "name", "sql" FROM "sqlite_master" WHERE "type" = 'table' AND "name" NOT LIKE 'sqlite_%' ORDER BY "name" ASC"#
+//! );
+//! assert_eq!(
+//!     sqlite::columns_query("glyph").to_string(SqliteQueryBuilder),
+//!     r#"SELECT "name", "type", "notnull", "dflt_value", "pk" FROM pragma_table_info('glyph') AS "info" ORDER BY "cid" ASC"#
+//! );
+//! ```
+
+use super::{
+    group_by_key, parse_column_type, parse_foreign_key_action, DiscoveredColumn,
+    DiscoveredForeignKey, DiscoveredIndex, DiscoveredTable,
+};
+use crate::{Alias, Expr, Func, Order, Query, SelectStatement, SimpleExpr};
+
+/// Row of [`tables_query`]
+#[derive(Debug, Clone)]
+pub struct TableRow {
+    pub name: String,
+    pub sql: String,
+}
+
+/// Row of [`columns_query`]
+#[derive(Debug, Clone)]
+pub struct ColumnRow {
+    pub name: String,
+    pub column_type: String,
+    pub not_null: bool,
+    pub default: Option<String>,
+    /// Position in the primary key, or zero if not part of it
+    pub primary_key: u32,
+}
+
+/// Row of [`indexes_query`], with the columns from [`index_columns_query`]
+#[derive(Debug, Clone)]
+pub struct IndexRow {
+    pub name: String,
+    pub unique: bool,
+    /// `c` for `CREATE INDEX`, `u` for `UNIQUE` constraint and `pk` for `PRIMARY KEY`
+    pub origin: String,
+    pub columns: Vec<String>,
+}
+
+/// Row of [`foreign_keys_query`]
+#[derive(Debug, Clone)]
+pub struct ForeignKeyRow {
+    pub id: i64,
+    pub table: String,
+    pub from: String,
+    /// Referenced column, resolved to the parent's primary key when the foreign key omits it.
+    /// `None` if the parent table is missing or has no primary key.
+    pub to: Option<String>,
+    pub on_update: String,
+    pub on_delete: String,
+}
+
+/// Select all user tables, see [`TableRow`]
+pub fn tables_query() -> SelectStatement {
+    Query::select()
+        .columns(vec![Alias::new("name"), Alias::new("sql")])
+        .from(Alias::new("sqlite_master"))
+        .and_where(Expr::col(Alias::new("type")).eq("table"))
+        .and_where(Expr::col(Alias::new("name")).not_like("sqlite_%"))
+        .order_by(Alias::new("name"), Order::Asc)
+        .take()
+}
+
+/// Select the columns of a table, see [`ColumnRow`]
+pub fn columns_query(table: &str) -> SelectStatement {
+    Query::select()
+        .columns(vec![
+            Alias::new("name"),
+            Alias::new("type"),
+            Alias::new("notnull"),
+            Alias::new("dflt_value"),
+            Alias::new("pk"),
+        ])
+        .from_function(
+            Func::cust(Alias::new("pragma_table_info")).arg(table),
+            Alias::new("info"),
+        )
+        .order_by(Alias::new("cid"), Order::Asc)
+        .take()
+}
+
+/// Select the indexes of a table, see [`IndexRow`]
+pub fn indexes_query(table: &str) -> SelectStatement {
+    Query::select()
+        .columns(vec![
+            Alias::new("name"),
+            Alias::new("unique"),
+            Alias::new("origin"),
+        ])
+        .from_function(
+            Func::cust(Alias::new("pragma_index_list")).arg(table),
+            Alias::new("list"),
+        )
+        .order_by(Alias::new("seq"), Order::Desc)
+        .take()
+}
+
+/// Select the column names of an index
+pub fn index_columns_query(index: &str) -> SelectStatement {
+    Query::select()
+        .column(Alias::new("name"))
+        .from_function(
+            Func::cust(Alias::new("pragma_index_info")).arg(index),
+            Alias::new("info"),
+        )
+        .order_by(Alias::new("seqno"), Order::Asc)
+        .take()
+}
+
+/// Select the foreign keys of a table, one row per column, see [`ForeignKeyRow`]
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{schema::discovery::sqlite, *};
+///
+/// assert_eq!(
+///     sqlite::foreign_keys_query("character").to_string(SqliteQueryBuilder),
+///     [
+///         r#"SELECT "id", "table", "from","#,
+///         r#"IFNULL("list"."to", (SELECT "parent"."name" FROM pragma_table_info("list"."table") AS "parent" WHERE "parent"."pk" = "list"."seq" + 1)) AS "to","#,
+///         r#""on_update", "on_delete""#,
+///         r#"FROM pragma_foreign_key_list('character') AS "list""#,
+///         r#"ORDER BY "id" ASC, "seq" ASC"#,
+///     ]
+///     .join(" ")
+/// );
+/// ```
+pub fn foreign_keys_query(table: &str) -> SelectStatement {
+    // `to` is NULL when the foreign key references the implicit primary key of the parent
+    let parent_key = Query::select()
+        .column((Alias::new("parent"), Alias::new("name")))
+        .from_function(
+            Func::cust(Alias::new("pragma_table_info"))
+                .arg(Expr::col((Alias::new("list"), Alias::new("table")))),
+            Alias::new("parent"),
+        )
+        .and_where(
+            Expr::col((Alias::new("parent"), Alias::new("pk")))
+                .into_simple_expr()
+                .equals(Expr::col((Alias::new("list"), Alias::new("seq"))).add(1)),
+        )
+        .take();
+    Query::select()
+        .columns(vec![
+            Alias::new("id"),
+            Alias::new("table"),
+            Alias::new("from"),
+        ])
+        .expr_as(
+            Func::if_null(
+                Expr::col((Alias::new("list"), Alias::new("to"))),
+                SimpleExpr::SubQuery(Box::new(parent_key)),
+            ),
+            Alias::new("to"),
+        )
+        .columns(vec![Alias::new("on_update"), Alias::new("on_delete")])
+        .from_function(
+            Func::cust(Alias::new("pragma_foreign_key_list")).arg(table),
+            Alias::new("list"),
+        )
+        .order_by(Alias::new("id"), Order::Asc)
+        .order_by(Alias::new("seq"), Order::Asc)
+        .take()
+}
+
+/// Assemble the rows of one table into [`DiscoveredTable`].
+/// Multi-column `UNIQUE` constraints keep the `sqlite_autoindex_*` name SQLite gave them.
+pub fn discover_table(
+    table: TableRow,
+    columns: Vec<ColumnRow>,
+    indexes: Vec<IndexRow>,
+    foreign_keys: Vec<ForeignKeyRow>,
+) -> DiscoveredTable {
+    // AUTOINCREMENT is only allowed on the rowid alias, a sole `INTEGER PRIMARY KEY` column
+    let auto_increment = autoincrement_column(&table.sql);
+    let mut primary_key: Vec<(u32, String)> = columns
+        .iter()
+        .filter(|column| column.primary_key > 0)
+        .map(|column| (column.primary_key, column.name.clone()))
+        .collect();
+    primary_key.sort();
+    let mut columns: Vec<DiscoveredColumn> = columns
+        .into_iter()
+        .map(|column| DiscoveredColumn {
+            auto_increment: auto_increment.as_deref() == Some(column.name.as_str())
+                && column.primary_key > 0
+                && column.column_type.eq_ignore_ascii_case("integer"),
+            name: column.name,
+            column_type: parse_column_type(&column.column_type),
+            not_null: column.not_null,
+            default: column.default,
+            unique: false,
+        })
+        .collect();
+
+    let mut discovered_indexes = Vec::new();
+    for index in indexes.into_iter() {
+        match index.origin.as_str() {
+            "pk" => {}
+            "u" if index.columns.len() == 1 => {
+                if let Some(column) = columns.iter_mut().find(|c| c.name == index.columns[0]) {
+                    column.unique = true;
+                }
+            }
+            _ => discovered_indexes.push(DiscoveredIndex {
+                name: index.name,
+                columns: index.columns,
+                unique: index.unique,
+            }),
+        }
+    }
+
+    let foreign_keys = group_by_key(foreign_keys.into_iter().map(|row| (row.id, row)))
+        .into_iter()
+        .map(|(_, rows)| DiscoveredForeignKey {
+            name: None,
+            columns: rows.iter().map(|row| row.from.clone()).collect(),
+            ref_table: rows[0].table.clone(),
+            ref_columns: rows
+                .iter()
+                .map(|row| row.to.clone())
+                .collect::<Option<_>>()
+                .unwrap_or_default(),
+            on_delete: parse_foreign_key_action(&rows[0].on_delete),
+            on_update: parse_foreign_key_action(&rows[0].on_update),
+        })
+        .collect();
+
+    DiscoveredTable {
+        name: table.name,
+        columns,
+        primary_key: primary_key.into_iter().map(|(_, name)| name).collect(),
+        indexes: discovered_indexes,
+        foreign_keys,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Punct(char),
+}
+
+/// Find the column declared with `AUTOINCREMENT` in a `CREATE TABLE` statement
+fn autoincrement_column(sql: &str) -> Option<String> {
+    let tokens = tokenize(sql);
+    let start = tokens
+        .iter()
+        .position(|token| *token == Token::Punct('('))?;
+    let mut depth = 0;
+    let mut definitions: Vec<&[Token]> = Vec::new();
+    let mut from = start + 1;
+    for (i, token) in tokens.iter().enumerate().skip(start) {
+        match token {
+            Token::Punct('(') => depth += 1,
+            Token::Punct(')') => {
+                depth -= 1;
+                if depth == 0 {
+                    definitions.push(&tokens[from..i]);
+                    break;
+                }
+            }
+            Token::Punct(',') if depth == 1 => {
+                definitions.push(&tokens[from..i]);
+                from = i + 1;
+            }
+            _ => {}
+        }
+    }
+    definitions.into_iter().find_map(|definition| {
+        let is_autoincrement = definition[1..].iter().any(
+            |token| matches!(token, Token::Word(word) if word.eq_ignore_ascii_case("AUTOINCREMENT")),
+        );
+        match definition.first() {
+            Some(Token::Word(name)) if is_autoincrement => Some(name.clone()),
+            _ => None,
+        }
+    })
+}
+
+/// Split SQL into words and punctuation, unquoting identifiers and dropping string literals and comments
+fn tokenize(sql: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' | '`' | '[' | '\'' => {
+                let close = if c == '[' { ']' } else { c };
+                let mut word = String::new();
+                while let Some(c) = chars.next() {
+                    if c == close {
+                        // Quotes are escaped by doubling them
+                        if close != ']' && chars.peek() == Some(&close) {
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                    word.push(c);
+                }
+                if c != '\'' {
+                    tokens.push(Token::Word(word));
+                }
+            }
+            '-' if chars.peek() == Some(&'-') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut previous = ' ';
+                for c in chars.by_ref() {
+                    if previous == '*' && c == '/' {
+                        break;
+                    }
+                    previous = c;
+                }
+            }
+            c if c.is_alphanumeric() || c == '_' => {
+                let mut word = c.to_string();
+                while let Some(&c) = chars.peek() {
+                    if !c.is_alphanumeric() && c != '_' && c != '$' {
+                        break;
+                    }
+                    word.push(c);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+            c if c.is_whitespace() => {}
+            c => tokens.push(Token::Punct(c)),
+        }
+    }
+    tokens
+}
@@ -0,0 +1,154 @@
+//! Schema definition & alternations statements
+
+pub mod diff;
+pub mod discovery;
+mod set;
+
+pub use set::*;
+
+use crate::{
+    backend::SchemaBuilder, error::Result, DynIden, ForeignKeyStatement, IndexStatement,
+    NamespaceStatement, PrivilegeStatement, TableRef, TableStatement, TriggerStatement,
+    ViewStatement,
+};
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SchemaStatement {
+    TableStatement(TableStatement),
+    IndexStatement(IndexStatement),
+    ForeignKeyStatement(ForeignKeyStatement),
+    ViewStatement(ViewStatement),
+    TriggerStatement(TriggerStatement),
+    NamespaceStatement(NamespaceStatement),
+    PrivilegeStatement(PrivilegeStatement),
+}
+
+impl SchemaStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        match self {
+            Self::TableStatement(stat) => stat.build(schema_builder),
+            Self::IndexStatement(stat) => stat.build(schema_builder),
+            Self::ForeignKeyStatement(stat) => stat.build(schema_builder),
+            Self::ViewStatement(stat) => stat.build(schema_builder),
+            Self::TriggerStatement(stat) => stat.build(schema_builder),
+            Self::NamespaceStatement(stat) => stat.build(schema_builder),
+            Self::PrivilegeStatement(stat) => stat.build(schema_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        match self {
+            Self::TableStatement(stat) => stat.build_any(schema_builder),
+            Self::IndexStatement(stat) => stat.build_any(schema_builder),
+            Self::ForeignKeyStatement(stat) => stat.build_any(schema_builder),
+            Self::ViewStatement(stat) => stat.build_any(schema_builder),
+            Self::TriggerStatement(stat) => stat.build_any(schema_builder),
+            Self::NamespaceStatement(stat) => stat.build_any(schema_builder),
+            Self::PrivilegeStatement(stat) => stat.build_any(schema_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        match self {
+            Self::TableStatement(stat) => stat.to_string(schema_builder),
+            Self::IndexStatement(stat) => stat.to_string(schema_builder),
+            Self::ForeignKeyStatement(stat) => stat.to_string(schema_builder),
+            Self::ViewStatement(stat) => stat.to_string(schema_builder),
+            Self::TriggerStatement(stat) => stat.to_string(schema_builder),
+            Self::NamespaceStatement(stat) => stat.to_string(schema_builder),
+            Self::PrivilegeStatement(stat) => stat.to_string(schema_builder),
+        }
+    }
+
+    /// Build the statements setting comments, to run after the statement on backends which cannot set them inline
+    pub fn comment_statements(&self, schema_builder: &dyn SchemaBuilder) -> Vec<String> {
+        match self {
+            Self::TableStatement(stat) => stat.comment_statements(schema_builder),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Check that the database backend supports the statement, see [`SchemaStatementBuilder::check`]
+    pub fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        match self {
+            Self::TableStatement(stat) => stat.check(schema_builder),
+            Self::IndexStatement(stat) => stat.check(schema_builder),
+            Self::ForeignKeyStatement(stat) => stat.check(schema_builder),
+            Self::ViewStatement(stat) => stat.check(schema_builder),
+            Self::TriggerStatement(stat) => stat.check(schema_builder),
+            Self::NamespaceStatement(stat) => stat.check(schema_builder),
+            Self::PrivilegeStatement(stat) => stat.check(schema_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string,
+    /// see [`SchemaStatementBuilder::try_to_string`]
+    pub fn try_to_string<T: SchemaBuilder>(&self, schema_builder: T) -> Result<String> {
+        self.check(&schema_builder)?;
+        Ok(self.build(schema_builder))
+    }
+}
+
+pub trait SchemaStatementBuilder {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String;
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String;
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        self.build(schema_builder)
+    }
+
+    /// Check that the database backend supports the statement, which would otherwise panic when built
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        let _ = schema_builder;
+        Ok(())
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string,
+    /// or [`Error::Unsupported`](crate::error::Error::Unsupported) if the backend does not support the statement
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::Error, *};
+    ///
+    /// let grant = GrantStatement::new()
+    ///     .privilege(Privilege::Select)
+    ///     .on_table(Alias::new("glyph"))
+    ///     .to(Alias::new("reporting"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     grant.try_to_string(PostgresQueryBuilder),
+    ///     Ok(r#"GRANT SELECT ON TABLE "glyph" TO "reporting""#.to_owned())
+    /// );
+    /// assert_eq!(
+    ///     grant.try_to_string(SqliteQueryBuilder),
+    ///     Err(Error::Unsupported("Grant is not available in SQLite.".to_owned()))
+    /// );
+    /// ```
+    fn try_to_string<T: SchemaBuilder>(&self, schema_builder: T) -> Result<String> {
+        self.check(&schema_builder)?;
+        Ok(self.build(schema_builder))
+    }
+}
+
+/// The unqualified name of a table
+pub(crate) fn table_name(table: &TableRef) -> Option<DynIden> {
+    match table {
+        TableRef::Table(tbl)
+        | TableRef::SchemaTable(_, tbl)
+        | TableRef::DatabaseSchemaTable(_, _, tbl)
+        | TableRef::TableAlias(tbl, _)
+        | TableRef::SchemaTableAlias(_, tbl, _)
+        | TableRef::DatabaseSchemaTableAlias(_, _, tbl, _) => Some(tbl.clone()),
+        TableRef::SubQuery(_, _) | TableRef::FunctionCall(_, _) => None,
+    }
+}
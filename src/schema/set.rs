@@ -0,0 +1,317 @@
+use super::table_name;
+use crate::{
+    backend::SchemaBuilder, error::*, ForeignKeyCreateStatement, ForeignKeyDropStatement,
+    ForeignKeyStatement, SchemaStatement, TableCreateStatement, TableDropStatement, TableStatement,
+};
+
+/// A set of tables, created and dropped in the order of their foreign keys.
+///
+/// Referenced tables are created before the tables referencing them, and dropped after.
+/// Foreign keys forming a cycle between tables are deferred: they are added with
+/// `ALTER TABLE ... ADD CONSTRAINT` once all tables exist, and dropped before any table.
+/// Tables referencing themselves or tables outside of the set need no deferral.
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let schema = SchemaSet::new()
+///     .table(
+///         Table::create()
+///             .table(Char::Table)
+///             .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+///             .col(ColumnDef::new(Char::FontId).integer())
+///             .foreign_key(
+///                 ForeignKey::create()
+///                     .name("FK_character_font")
+///                     .from(Char::Table, Char::FontId)
+///                     .to(Font::Table, Font::Id),
+///             ),
+///     )
+///     .table(
+///         Table::create()
+///             .table(Font::Table)
+///             .col(ColumnDef::new(Font::Id).integer().not_null().primary_key()),
+///     )
+///     .to_owned();
+///
+/// assert_eq!(
+///     schema.build_create(PostgresQueryBuilder).unwrap(),
+///     [
+///         r#"CREATE TABLE "font" ( "id" integer NOT NULL PRIMARY KEY )"#,
+///         [
+///             r#"CREATE TABLE "character" ("#,
+///             r#""id" integer NOT NULL PRIMARY KEY,"#,
+///             r#""font_id" integer,"#,
+///             r#"CONSTRAINT "FK_character_font""#,
+///             r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+///             r#")"#,
+///         ]
+///         .join(" ")
+///         .as_str(),
+///     ]
+/// );
+/// assert_eq!(
+///     schema.build_drop(MysqlQueryBuilder),
+///     ["DROP TABLE `character`", "DROP TABLE `font`"]
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SchemaSet {
+    pub(crate) tables: Vec<TableCreateStatement>,
+}
+
+/// Tables in creation order, with the foreign keys deferred after them
+struct Plan {
+    tables: Vec<TableCreateStatement>,
+    deferred: Vec<ForeignKeyCreateStatement>,
+}
+
+impl SchemaSet {
+    /// Construct a new [`SchemaSet`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a table
+    pub fn table(&mut self, table: &mut TableCreateStatement) -> &mut Self {
+        self.tables.push(table.take());
+        self
+    }
+
+    pub fn get_tables(&self) -> &Vec<TableCreateStatement> {
+        &self.tables
+    }
+
+    /// Statements creating all tables, followed by the deferred foreign keys
+    pub fn create_statements(&self) -> Vec<SchemaStatement> {
+        let plan = self.plan();
+        plan.tables
+            .into_iter()
+            .map(|stmt| SchemaStatement::TableStatement(TableStatement::Create(stmt)))
+            .chain(plan.deferred.into_iter().map(|stmt| {
+                SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Create(stmt))
+            }))
+            .collect()
+    }
+
+    /// Statements dropping the deferred foreign keys, followed by all tables in reverse creation order
+    pub fn drop_statements(&self) -> Vec<SchemaStatement> {
+        let plan = self.plan();
+        plan.deferred
+            .iter()
+            .filter_map(drop_foreign_key)
+            .map(|stmt| SchemaStatement::ForeignKeyStatement(ForeignKeyStatement::Drop(stmt)))
+            .chain(
+                plan.tables
+                    .iter()
+                    .rev()
+                    .filter_map(drop_table)
+                    .map(|stmt| SchemaStatement::TableStatement(TableStatement::Drop(stmt))),
+            )
+            .collect()
+    }
+
+    /// Tables whose foreign keys reference each other in a cycle, one group per cycle
+    pub fn cycles(&self) -> Vec<Vec<String>> {
+        let names = self.names();
+        let edges = self.edges(&names);
+        let reaches = |from: usize| {
+            let mut seen = vec![false; names.len()];
+            let mut stack = vec![from];
+            while let Some(node) = stack.pop() {
+                for &next in edges[node].iter() {
+                    if !seen[next] {
+                        seen[next] = true;
+                        stack.push(next);
+                    }
+                }
+            }
+            seen
+        };
+        let reachable: Vec<Vec<bool>> = (0..names.len()).map(reaches).collect();
+
+        let mut grouped = vec![false; names.len()];
+        let mut cycles = Vec::new();
+        for i in 0..names.len() {
+            if grouped[i] {
+                continue;
+            }
+            let group: Vec<usize> = (i..names.len())
+                .filter(|&j| j != i && reachable[i][j] && reachable[j][i])
+                .collect();
+            if group.is_empty() {
+                continue;
+            }
+            let mut cycle = vec![names[i].clone()];
+            for j in group {
+                grouped[j] = true;
+                cycle.push(names[j].clone());
+            }
+            cycles.push(cycle);
+        }
+        cycles
+    }
+
+    /// Build the create statements for certain database backend,
+    /// each table followed by the statements setting its comments if the backend cannot set them inline
+    ///
+    /// Fails if foreign keys have to be deferred but the backend cannot add them to existing tables,
+    /// as in SQLite.
+    pub fn build_create<T: SchemaBuilder>(&self, schema_builder: T) -> Result<Vec<String>> {
+        if !schema_builder.alterable_foreign_keys() {
+            if let Some(cycle) = self.cycles().into_iter().next() {
+                return Err(Error::ForeignKeyCycle(cycle));
+            }
+        }
+        Ok(self
+            .create_statements()
+            .iter()
+            .flat_map(|stmt| {
+                std::iter::once(stmt.build_any(&schema_builder))
+                    .chain(stmt.comment_statements(&schema_builder))
+            })
+            .collect())
+    }
+
+    /// Build the drop statements for certain database backend
+    ///
+    /// Foreign keys are dropped together with their tables if the backend cannot drop them separately.
+    pub fn build_drop<T: SchemaBuilder>(&self, schema_builder: T) -> Vec<String> {
+        let alterable = schema_builder.alterable_foreign_keys();
+        self.drop_statements()
+            .iter()
+            .filter(|stmt| alterable || !matches!(stmt, SchemaStatement::ForeignKeyStatement(_)))
+            .map(|stmt| stmt.build_any(&schema_builder))
+            .collect()
+    }
+
+    fn names(&self) -> Vec<String> {
+        self.tables
+            .iter()
+            .map(|table| {
+                table
+                    .table
+                    .as_ref()
+                    .and_then(table_name)
+                    .map(|name| name.to_string())
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
+    /// For each table, the other tables in the set it references
+    fn edges(&self, names: &[String]) -> Vec<Vec<usize>> {
+        self.tables
+            .iter()
+            .enumerate()
+            .map(|(i, table)| {
+                let mut edges: Vec<usize> = table
+                    .foreign_keys
+                    .iter()
+                    .filter_map(|fk| referenced(fk, names))
+                    .filter(|&j| j != i)
+                    .collect();
+                edges.sort_unstable();
+                edges.dedup();
+                edges
+            })
+            .collect()
+    }
+
+    /// Order the tables so that referenced tables come first, keeping the insertion order otherwise.
+    /// When no table is ready, the first table of a cycle that references nothing outside it is
+    /// created with its foreign keys to the rest of the cycle deferred.
+    fn plan(&self) -> Plan {
+        let names = self.names();
+        let edges = self.edges(&names);
+        let cycles: Vec<Vec<usize>> = self
+            .cycles()
+            .iter()
+            .map(|cycle| {
+                cycle
+                    .iter()
+                    .filter_map(|name| names.iter().position(|n| n == name))
+                    .collect()
+            })
+            .collect();
+        let mut created = vec![false; self.tables.len()];
+        let mut plan = Plan {
+            tables: Vec::new(),
+            deferred: Vec::new(),
+        };
+
+        while plan.tables.len() < self.tables.len() {
+            let ready = (0..self.tables.len())
+                .find(|&i| !created[i] && edges[i].iter().all(|&j| created[j]));
+            let next = match ready {
+                Some(i) => i,
+                None => (0..self.tables.len())
+                    .find(|&i| {
+                        !created[i]
+                            && cycles.iter().any(|cycle| {
+                                cycle.contains(&i)
+                                    && edges[i].iter().all(|j| created[*j] || cycle.contains(j))
+                            })
+                    })
+                    .or_else(|| (0..self.tables.len()).find(|&i| !created[i]))
+                    .unwrap(),
+            };
+
+            let mut table = self.tables[next].clone();
+            let (keep, defer): (Vec<_>, Vec<_>) =
+                table
+                    .foreign_keys
+                    .drain(..)
+                    .partition(|fk| match referenced(fk, &names) {
+                        Some(j) => j == next || created[j],
+                        None => true,
+                    });
+            table.foreign_keys = keep;
+            for mut fk in defer {
+                if fk.foreign_key.table.is_none() {
+                    fk.foreign_key.table = table.table.as_ref().and_then(table_name);
+                }
+                // Name the constraint as Postgres would, so that it can be dropped again
+                if fk.foreign_key.name.is_none() {
+                    if let Some(table) = &fk.foreign_key.table {
+                        let columns: Vec<String> = fk
+                            .foreign_key
+                            .columns
+                            .iter()
+                            .map(|column| column.to_string())
+                            .collect();
+                        fk.foreign_key.name =
+                            Some(format!("{}_{}_fkey", table.to_string(), columns.join("_")));
+                    }
+                }
+                plan.deferred.push(fk);
+            }
+
+            created[next] = true;
+            plan.tables.push(table);
+        }
+        plan
+    }
+}
+
+/// Index of the table in the set referenced by the foreign key
+fn referenced(foreign_key: &ForeignKeyCreateStatement, names: &[String]) -> Option<usize> {
+    let ref_table = foreign_key.foreign_key.ref_table.as_ref()?.to_string();
+    names.iter().position(|name| name == &ref_table)
+}
+
+fn drop_foreign_key(foreign_key: &ForeignKeyCreateStatement) -> Option<ForeignKeyDropStatement> {
+    foreign_key.foreign_key.name.as_ref()?;
+    let mut stmt = ForeignKeyDropStatement::new();
+    stmt.table = foreign_key.foreign_key.table.clone();
+    stmt.foreign_key.name = foreign_key.foreign_key.name.clone();
+    Some(stmt)
+}
+
+fn drop_table(table: &TableCreateStatement) -> Option<TableDropStatement> {
+    let mut stmt = TableDropStatement::new();
+    stmt.tables.push(table.table.clone()?);
+    Some(stmt)
+}
@@ -3,13 +3,17 @@ macro_rules! impl_schema_statement_builder {
     ( $mod_name: ident, $struct_name: ident ) => {
         mod $mod_name {
 
-            use crate::{$struct_name, SchemaBuilder, SchemaStatementBuilder};
+            use crate::{error::Result, $struct_name, SchemaBuilder, SchemaStatementBuilder};
 
             impl $struct_name {
                 pub fn to_string<T: SchemaBuilder>(&self, schema_builder: T) -> String {
                     <Self as SchemaStatementBuilder>::to_string(self, schema_builder)
                 }
 
+                pub fn try_to_string<T: SchemaBuilder>(&self, schema_builder: T) -> Result<String> {
+                    <Self as SchemaStatementBuilder>::try_to_string(self, schema_builder)
+                }
+
                 pub fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
                     <Self as SchemaStatementBuilder>::build(self, schema_builder)
                 }
@@ -22,6 +26,39 @@ macro_rules! impl_schema_statement_builder {
     };
 }
 
+#[macro_export]
+macro_rules! impl_transaction_statement_builder {
+    ( $mod_name: ident, $struct_name: ident ) => {
+        mod $mod_name {
+
+            use $crate::{
+                error::Result, $struct_name, TransactionBuilder, TransactionStatementBuilder,
+            };
+
+            impl $struct_name {
+                pub fn to_string<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+                    <Self as TransactionStatementBuilder>::to_string(self, transaction_builder)
+                }
+
+                pub fn try_to_string<T: TransactionBuilder>(
+                    &self,
+                    transaction_builder: T,
+                ) -> Result<String> {
+                    <Self as TransactionStatementBuilder>::try_to_string(self, transaction_builder)
+                }
+
+                pub fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+                    <Self as TransactionStatementBuilder>::build(self, transaction_builder)
+                }
+
+                pub fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+                    <Self as TransactionStatementBuilder>::build_any(self, transaction_builder)
+                }
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_query_statement_builder {
     ( $mod_name: ident, $struct_name: ident ) => {
@@ -1,4 +1,7 @@
-use crate::{backend::SchemaBuilder, prepare::*, types::*, ColumnDef, SchemaStatementBuilder};
+use crate::{
+    backend::SchemaBuilder, error::Result, prepare::*, types::*, ColumnDef, IndexCreateStatement,
+    SchemaStatementBuilder,
+};
 
 /// Alter a table
 ///
@@ -31,6 +34,7 @@ use crate::{backend::SchemaBuilder, prepare::*, types::*, ColumnDef, SchemaState
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableAlterStatement {
     pub(crate) table: Option<DynIden>,
     pub(crate) alter_option: Option<TableAlterOption>,
@@ -38,11 +42,15 @@ pub struct TableAlterStatement {
 
 /// All available table alter options
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableAlterOption {
     AddColumn(ColumnDef),
     ModifyColumn(ColumnDef),
     RenameColumn(DynIden, DynIden),
     DropColumn(DynIden),
+    DropColumnDefault(DynIden),
+    AddPrimaryKey(IndexCreateStatement),
+    DropPrimaryKey(Option<String>),
 }
 
 impl Default for TableAlterStatement {
@@ -203,6 +211,101 @@ impl TableAlterStatement {
         self.alter_option(TableAlterOption::DropColumn(SeaRc::new(col_name)))
     }
 
+    /// Drop the default value of a column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .drop_column_default(Font::Variant)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `font` ALTER COLUMN `variant` DROP DEFAULT"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "font" ALTER COLUMN "variant" DROP DEFAULT"#
+    /// );
+    /// // Sqlite not support modifying table column
+    /// ```
+    pub fn drop_column_default<T>(&mut self, col_name: T) -> &mut Self
+    where
+        T: Iden + 'static,
+    {
+        self.alter_option(TableAlterOption::DropColumnDefault(SeaRc::new(col_name)))
+    }
+
+    /// Add a primary key to existing table
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Char::Table)
+    ///     .add_primary_key(Index::create().col(Char::FontId).col(Char::Character))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `character` ADD PRIMARY KEY (`font_id`, `character`)"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "character" ADD PRIMARY KEY ("font_id", "character")"#
+    /// );
+    /// // Sqlite not support modifying primary key
+    /// ```
+    pub fn add_primary_key(&mut self, index: &mut IndexCreateStatement) -> &mut Self {
+        let mut index = index.take();
+        index.primary = true;
+        self.alter_option(TableAlterOption::AddPrimaryKey(index))
+    }
+
+    /// Drop the primary key of existing table.
+    /// Postgres drops the constraint `<table>_pkey`, see [`TableAlterStatement::drop_primary_key_named`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Char::Table)
+    ///     .drop_primary_key()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"ALTER TABLE `character` DROP PRIMARY KEY"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER TABLE "character" DROP CONSTRAINT "character_pkey""#
+    /// );
+    /// // Sqlite not support modifying primary key
+    /// ```
+    pub fn drop_primary_key(&mut self) -> &mut Self {
+        self.alter_option(TableAlterOption::DropPrimaryKey(None))
+    }
+
+    /// Drop the primary key constraint `name` of existing table. MySQL ignores the name.
+    pub fn drop_primary_key_named(&mut self, name: &str) -> &mut Self {
+        self.alter_option(TableAlterOption::DropPrimaryKey(Some(name.to_owned())))
+    }
+
+    /// Build the statement setting the comment of an added or modified column, to run after the alteration
+    /// on backends which cannot set it inline, i.e. Postgres. Empty on other backends.
+    pub fn comment_statements<T: SchemaBuilder>(&self, schema_builder: T) -> Vec<String> {
+        schema_builder.table_alter_comment_statements(self)
+    }
+
     fn alter_option(&mut self, alter_option: TableAlterOption) -> &mut Self {
         self.alter_option = Some(alter_option);
         self
@@ -228,4 +331,8 @@ impl SchemaStatementBuilder for TableAlterStatement {
         schema_builder.prepare_table_alter_statement(self, &mut sql);
         sql.result()
     }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_table_alter_statement(self)
+    }
 }
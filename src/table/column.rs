@@ -1,7 +1,8 @@
-use crate::{types::*, value::*};
+use crate::{expr::*, types::*};
 
 /// Specification of a table column
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColumnDef {
     pub(crate) table: Option<TableRef>,
     pub(crate) name: DynIden,
@@ -12,6 +13,7 @@ pub struct ColumnDef {
 /// All column types
 #[non_exhaustive]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnType {
     Char(Option<u32>),
     String(Option<u32>),
@@ -42,18 +44,40 @@ pub enum ColumnType {
 
 /// All column specification keywords
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnSpec {
     Null,
     NotNull,
-    Default(Value),
+    Default(SimpleExpr),
     AutoIncrement,
     UniqueKey,
     PrimaryKey,
+    Identity(ColumnIdentity),
+    OnUpdate(SimpleExpr),
+    Comment(String),
     Extra(String),
 }
 
+/// Identity column specification, `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY`
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnIdentity {
+    pub(crate) generation: IdentityGeneration,
+    pub(crate) start: Option<i64>,
+    pub(crate) increment: Option<i64>,
+}
+
+/// All identity generation modes
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IdentityGeneration {
+    Always,
+    ByDefault,
+}
+
 // All interval fields
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum PgInterval {
     Year,
     Month,
@@ -92,6 +116,50 @@ impl quote::ToTokens for PgInterval {
     }
 }
 
+impl ColumnIdentity {
+    /// Construct an identity specification, `GENERATED ALWAYS AS IDENTITY`
+    pub fn always() -> Self {
+        Self {
+            generation: IdentityGeneration::Always,
+            start: None,
+            increment: None,
+        }
+    }
+
+    /// Construct an identity specification, `GENERATED BY DEFAULT AS IDENTITY`
+    pub fn by_default() -> Self {
+        Self {
+            generation: IdentityGeneration::ByDefault,
+            start: None,
+            increment: None,
+        }
+    }
+
+    /// Set the start value of the identity sequence, `START WITH`
+    pub fn start_with(mut self, start: i64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Set the increment of the identity sequence, `INCREMENT BY`. Only 1 is available outside Postgres
+    pub fn increment_by(mut self, increment: i64) -> Self {
+        self.increment = Some(increment);
+        self
+    }
+
+    pub fn get_generation(&self) -> &IdentityGeneration {
+        &self.generation
+    }
+
+    pub fn get_start(&self) -> Option<i64> {
+        self.start
+    }
+
+    pub fn get_increment(&self) -> Option<i64> {
+        self.increment
+    }
+}
+
 impl ColumnDef {
     /// Construct a table column
     pub fn new<T: 'static>(name: T) -> Self
@@ -125,21 +193,167 @@ impl ColumnDef {
         self
     }
 
-    /// Set default value of a column
+    /// Set default value of a column. Accepts a value, a keyword, a function call or any other expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Char::Table)
+    ///     .col(ColumnDef::new(Char::FontSize).integer().default(12))
+    ///     .col(
+    ///         ColumnDef::new(Char::Character)
+    ///             .string()
+    ///             .default(Func::cust(Alias::new("UUID"))),
+    ///     )
+    ///     .col(
+    ///         ColumnDef::new(Alias::new("created_at"))
+    ///             .timestamp()
+    ///             .default(Expr::current_timestamp()),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `character` ("#,
+    ///         r#"`font_size` int DEFAULT 12,"#,
+    ///         r#"`character` varchar(255) DEFAULT (UUID()),"#,
+    ///         r#"`created_at` timestamp DEFAULT CURRENT_TIMESTAMP"#,
+    ///         r#")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE "character" ("#,
+    ///         r#""font_size" integer DEFAULT 12,"#,
+    ///         r#""character" varchar DEFAULT UUID(),"#,
+    ///         r#""created_at" timestamp DEFAULT CURRENT_TIMESTAMP"#,
+    ///         r#")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE "character" ("#,
+    ///         r#""font_size" integer DEFAULT 12,"#,
+    ///         r#""character" text DEFAULT (UUID()),"#,
+    ///         r#""created_at" text DEFAULT CURRENT_TIMESTAMP"#,
+    ///         r#")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
     pub fn default<T>(&mut self, value: T) -> &mut Self
     where
-        T: Into<Value>,
+        T: Into<SimpleExpr>,
     {
         self.spec.push(ColumnSpec::Default(value.into()));
         self
     }
 
+    /// Set the value assigned to a column on row update, `ON UPDATE`. MySQL only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Char::Table)
+    ///     .col(
+    ///         ColumnDef::new(Alias::new("created_at"))
+    ///             .timestamp()
+    ///             .default(Expr::current_timestamp())
+    ///             .on_update(Expr::current_timestamp()),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `character` ( `created_at` timestamp DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP )"#
+    /// );
+    /// ```
+    pub fn on_update<T>(&mut self, value: T) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.spec.push(ColumnSpec::OnUpdate(value.into()));
+        self
+    }
+
     /// Set column auto increment
     pub fn auto_increment(&mut self) -> &mut Self {
         self.spec.push(ColumnSpec::AutoIncrement);
         self
     }
 
+    /// Set column as identity column.
+    ///
+    /// On Postgres, this is rendered as `GENERATED { ALWAYS | BY DEFAULT } AS IDENTITY`.
+    /// On MySQL, the column is set `AUTO_INCREMENT` and the start value becomes the `AUTO_INCREMENT` table option.
+    /// On SQLite, the column becomes an alias of rowid, i.e. `integer PRIMARY KEY`,
+    /// which cannot have a start value nor be combined with another primary key.
+    /// Neither prevents inserting explicit values, so only [`ColumnIdentity::by_default`] is available on both.
+    /// An increment other than 1 is only available in Postgres.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(
+    ///         ColumnDef::new(Glyph::Id)
+    ///             .integer()
+    ///             .not_null()
+    ///             .identity(ColumnIdentity::by_default().start_with(100))
+    ///             .primary_key(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `glyph` ( `id` int NOT NULL AUTO_INCREMENT PRIMARY KEY ) AUTO_INCREMENT=100"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     [
+    ///         r#"CREATE TABLE "glyph" ("#,
+    ///         r#""id" integer NOT NULL GENERATED BY DEFAULT AS IDENTITY (START WITH 100) PRIMARY KEY"#,
+    ///         r#")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// assert!(table.try_to_string(SqliteQueryBuilder).is_err());
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(
+    ///         ColumnDef::new(Glyph::Id)
+    ///             .integer()
+    ///             .identity(ColumnIdentity::by_default().increment_by(10)),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "id" integer GENERATED BY DEFAULT AS IDENTITY (INCREMENT BY 10) )"#
+    /// );
+    /// assert!(table.try_to_string(MysqlQueryBuilder).is_err());
+    /// assert!(table.try_to_string(SqliteQueryBuilder).is_err());
+    /// ```
+    pub fn identity(&mut self, identity: ColumnIdentity) -> &mut Self {
+        self.spec.push(ColumnSpec::Identity(identity));
+        self
+    }
+
     /// Set column unique constraint
     pub fn unique_key(&mut self) -> &mut Self {
         self.spec.push(ColumnSpec::UniqueKey);
@@ -447,6 +661,46 @@ impl ColumnDef {
         self
     }
 
+    /// Set column comment. On Postgres, comments are set by `COMMENT ON COLUMN` statements to run after
+    /// the table creation, see [`TableCreateStatement::comment_statements`]. Ignored on SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(
+    ///         ColumnDef::new(Glyph::Id)
+    ///             .integer()
+    ///             .not_null()
+    ///             .comment("Glyph's identifier"),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `glyph` ( `id` int NOT NULL COMMENT 'Glyph\'s identifier' )"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "id" integer NOT NULL )"#
+    /// );
+    /// assert_eq!(
+    ///     table.comment_statements(PostgresQueryBuilder),
+    ///     [r#"COMMENT ON COLUMN "glyph"."id" IS E'Glyph\'s identifier'"#]
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "id" integer NOT NULL )"#
+    /// );
+    /// ```
+    pub fn comment(&mut self, comment: &str) -> &mut Self {
+        self.spec.push(ColumnSpec::Comment(comment.into()));
+        self
+    }
+
     /// Some extra options in custom string
     pub fn extra(&mut self, string: String) -> &mut Self {
         self.spec.push(ColumnSpec::Extra(string));
@@ -1,6 +1,6 @@
 use crate::{
-    backend::SchemaBuilder, foreign_key::*, index::*, prepare::*, types::*, ColumnDef,
-    SchemaStatementBuilder,
+    backend::SchemaBuilder, error::Result, expr::*, foreign_key::*, index::*, prepare::*, types::*,
+    ColumnDef, SchemaStatementBuilder,
 };
 
 /// Create a table
@@ -77,6 +77,7 @@ use crate::{
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableCreateStatement {
     pub(crate) table: Option<TableRef>,
     pub(crate) columns: Vec<ColumnDef>,
@@ -85,10 +86,12 @@ pub struct TableCreateStatement {
     pub(crate) indexes: Vec<IndexCreateStatement>,
     pub(crate) foreign_keys: Vec<ForeignKeyCreateStatement>,
     pub(crate) if_not_exists: bool,
+    pub(crate) comment: Option<String>,
 }
 
 /// All available table options
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableOpt {
     Engine(String),
     Collate(String),
@@ -97,7 +100,44 @@ pub enum TableOpt {
 
 /// All available table partition options
 #[derive(Debug, Clone)]
-pub enum TablePartition {}
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TablePartition {
+    /// `PARTITION BY { RANGE | LIST | HASH } (key, ...)`
+    PartitionBy(PartitionMethod, Vec<SimpleExpr>),
+    /// `PARTITION OF parent FOR VALUES ...`, Postgres only
+    PartitionOf(Box<TableRef>, PartitionBound),
+    /// `PARTITION name VALUES ...`, MySQL only
+    Partition(DynIden, PartitionBound),
+    /// `PARTITIONS num`, MySQL only
+    Partitions(u32),
+}
+
+/// All available partitioning methods
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartitionMethod {
+    Range,
+    List,
+    Hash,
+}
+
+/// All available partition bounds
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PartitionBound {
+    /// `FROM (...) TO (...)`, Postgres only
+    FromTo(Vec<SimpleExpr>, Vec<SimpleExpr>),
+    /// `LESS THAN (...)`, MySQL only
+    LessThan(Vec<SimpleExpr>),
+    /// `LESS THAN MAXVALUE`, MySQL only
+    LessThanMaxValue,
+    /// `IN (...)`
+    In(Vec<SimpleExpr>),
+    /// `WITH (MODULUS m, REMAINDER r)`, Postgres only
+    Modulus(u32, u32),
+    /// `DEFAULT`, Postgres only
+    Default,
+}
 
 impl Default for TableCreateStatement {
     fn default() -> Self {
@@ -116,6 +156,7 @@ impl TableCreateStatement {
             indexes: Vec::new(),
             foreign_keys: Vec::new(),
             if_not_exists: false,
+            comment: None,
         }
     }
 
@@ -256,13 +297,154 @@ impl TableCreateStatement {
         self
     }
 
+    /// Set table comment. On Postgres, the comment is set by a `COMMENT ON TABLE` statement
+    /// to run after the table creation, see [`TableCreateStatement::comment_statements`]. Ignored on SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(ColumnDef::new(Glyph::Id).integer().not_null())
+    ///     .comment("Glyphs of all fonts")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `glyph` ( `id` int NOT NULL ) COMMENT='Glyphs of all fonts'"#
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "id" integer NOT NULL )"#
+    /// );
+    /// assert_eq!(
+    ///     table.comment_statements(PostgresQueryBuilder),
+    ///     [r#"COMMENT ON TABLE "glyph" IS 'Glyphs of all fonts'"#]
+    /// );
+    /// assert_eq!(
+    ///     table.to_string(SqliteQueryBuilder),
+    ///     r#"CREATE TABLE "glyph" ( "id" integer NOT NULL )"#
+    /// );
+    /// ```
+    pub fn comment(&mut self, comment: &str) -> &mut Self {
+        self.comment = Some(comment.into());
+        self
+    }
+
+    /// Build the statements setting table and column comments, to run after the table creation
+    /// on backends which cannot set them inline, i.e. Postgres. Empty on other backends.
+    pub fn comment_statements<T: SchemaBuilder>(&self, schema_builder: T) -> Vec<String> {
+        schema_builder.table_comment_statements(self)
+    }
+
     fn opt(&mut self, option: TableOpt) -> &mut Self {
         self.options.push(option);
         self
     }
 
-    #[allow(dead_code)]
-    fn partition(&mut self, partition: TablePartition) -> &mut Self {
+    /// Partition the table by a list of key expressions.
+    ///
+    /// On MySQL, `RANGE` and `LIST` partitioning by plain columns becomes `RANGE COLUMNS` and `LIST COLUMNS`,
+    /// which accept any column type and several columns. Otherwise, a single expression is required.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(ColumnDef::new(Glyph::Id).integer().not_null())
+    ///     .col(ColumnDef::new(Glyph::Aspect).integer().not_null())
+    ///     .partition_by(PartitionMethod::Range, vec![Expr::col(Glyph::Aspect)])
+    ///     .partition(Alias::new("p0"), PartitionBound::less_than(vec![10]))
+    ///     .partition(Alias::new("p1"), PartitionBound::LessThanMaxValue)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     vec![
+    ///         r#"CREATE TABLE `glyph` ( `id` int NOT NULL, `aspect` int NOT NULL )"#,
+    ///         r#"PARTITION BY RANGE COLUMNS (`aspect`) ("#,
+    ///         r#"PARTITION `p0` VALUES LESS THAN (10),"#,
+    ///         r#"PARTITION `p1` VALUES LESS THAN (MAXVALUE)"#,
+    ///         r#")"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn partition_by<T, I>(&mut self, method: PartitionMethod, keys: I) -> &mut Self
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        self.add_partition(TablePartition::PartitionBy(
+            method,
+            keys.into_iter().map(|key| key.into()).collect(),
+        ))
+    }
+
+    /// Create the table as a partition of a partitioned table. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Alias::new("glyph_small"))
+    ///     .partition_of(Glyph::Table, PartitionBound::from_to(vec![0], vec![10]))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TABLE "glyph_small" PARTITION OF "glyph" FOR VALUES FROM (0) TO (10)"#
+    /// );
+    /// ```
+    pub fn partition_of<T>(&mut self, parent: T, bound: PartitionBound) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.add_partition(TablePartition::PartitionOf(
+            Box::new(parent.into_table_ref()),
+            bound,
+        ))
+    }
+
+    /// Add a partition definition. MySQL only.
+    pub fn partition<T>(&mut self, name: T, bound: PartitionBound) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.add_partition(TablePartition::Partition(name.into_iden(), bound))
+    }
+
+    /// Set the number of hash partitions. MySQL only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::create()
+    ///     .table(Glyph::Table)
+    ///     .col(ColumnDef::new(Glyph::Id).integer().not_null())
+    ///     .partition_by(PartitionMethod::Hash, vec![Expr::col(Glyph::Id)])
+    ///     .partitions(4)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE TABLE `glyph` ( `id` int NOT NULL ) PARTITION BY HASH (`id`) PARTITIONS 4"#
+    /// );
+    /// ```
+    pub fn partitions(&mut self, num: u32) -> &mut Self {
+        self.add_partition(TablePartition::Partitions(num))
+    }
+
+    fn add_partition(&mut self, partition: TablePartition) -> &mut Self {
         self.partitions.push(partition);
         self
     }
@@ -283,6 +465,14 @@ impl TableCreateStatement {
         self.indexes.as_ref()
     }
 
+    pub fn get_partitions(&self) -> &Vec<TablePartition> {
+        self.partitions.as_ref()
+    }
+
+    pub fn get_comment(&self) -> Option<&String> {
+        self.comment.as_ref()
+    }
+
     pub fn take(&mut self) -> Self {
         Self {
             table: self.table.take(),
@@ -292,10 +482,50 @@ impl TableCreateStatement {
             indexes: std::mem::take(&mut self.indexes),
             foreign_keys: std::mem::take(&mut self.foreign_keys),
             if_not_exists: self.if_not_exists,
+            comment: self.comment.take(),
         }
     }
 }
 
+impl PartitionBound {
+    /// Construct partition bound `FROM (...) TO (...)`
+    pub fn from_to<T, I, U, J>(from: I, to: J) -> Self
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+        U: Into<SimpleExpr>,
+        J: IntoIterator<Item = U>,
+    {
+        Self::FromTo(
+            from.into_iter().map(|v| v.into()).collect(),
+            to.into_iter().map(|v| v.into()).collect(),
+        )
+    }
+
+    /// Construct partition bound `LESS THAN (...)`
+    pub fn less_than<T, I>(values: I) -> Self
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        Self::LessThan(values.into_iter().map(|v| v.into()).collect())
+    }
+
+    /// Construct partition bound `IN (...)`
+    pub fn values_in<T, I>(values: I) -> Self
+    where
+        T: Into<SimpleExpr>,
+        I: IntoIterator<Item = T>,
+    {
+        Self::In(values.into_iter().map(|v| v.into()).collect())
+    }
+
+    /// Construct partition bound `WITH (MODULUS m, REMAINDER r)`
+    pub fn modulus(modulus: u32, remainder: u32) -> Self {
+        Self::Modulus(modulus, remainder)
+    }
+}
+
 impl SchemaStatementBuilder for TableCreateStatement {
     fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
         let mut sql = SqlWriter::new();
@@ -308,4 +538,8 @@ impl SchemaStatementBuilder for TableCreateStatement {
         schema_builder.prepare_table_create_statement(self, &mut sql);
         sql.result()
     }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_table_create_statement(self)
+    }
 }
@@ -26,6 +26,7 @@ use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableDropStatement {
     pub(crate) tables: Vec<TableRef>,
     pub(crate) options: Vec<TableDropOpt>,
@@ -34,6 +35,7 @@ pub struct TableDropStatement {
 
 /// All available table drop options
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableDropOpt {
     Restrict,
     Cascade,
@@ -8,7 +8,7 @@
 //! - Table Rename, see [`TableRenameStatement`]
 //! - Table Truncate, see [`TableTruncateStatement`]
 
-use crate::SchemaBuilder;
+use crate::{error::Result, SchemaBuilder, SchemaStatementBuilder};
 
 mod alter;
 mod column;
@@ -27,10 +27,12 @@ pub use truncate::*;
 
 /// Helper for constructing any table statement
 #[derive(Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Table;
 
 /// All available types of table statement
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableStatement {
     Create(TableCreateStatement),
     Alter(TableAlterStatement),
@@ -99,4 +101,24 @@ impl TableStatement {
             Self::Truncate(stat) => stat.to_string(table_builder),
         }
     }
+
+    /// Build the statements setting comments, to run after the statement on backends which cannot set them inline
+    pub fn comment_statements(&self, table_builder: &dyn SchemaBuilder) -> Vec<String> {
+        match self {
+            Self::Create(stat) => table_builder.table_comment_statements(stat),
+            Self::Alter(stat) => table_builder.table_alter_comment_statements(stat),
+            Self::Drop(_) | Self::Rename(_) | Self::Truncate(_) => Vec::new(),
+        }
+    }
+
+    /// Check that the database backend supports the statement, see [`SchemaStatementBuilder::check`]
+    pub fn check(&self, table_builder: &dyn SchemaBuilder) -> Result<()> {
+        match self {
+            Self::Create(stat) => stat.check(table_builder),
+            Self::Alter(stat) => stat.check(table_builder),
+            Self::Drop(stat) => stat.check(table_builder),
+            Self::Rename(stat) => stat.check(table_builder),
+            Self::Truncate(stat) => stat.check(table_builder),
+        }
+    }
 }
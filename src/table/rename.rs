@@ -25,6 +25,7 @@ use crate::{backend::SchemaBuilder, prepare::*, types::*, SchemaStatementBuilder
 /// );
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableRenameStatement {
     pub(crate) from_name: Option<DynIden>,
     pub(crate) to_name: Option<DynIden>,
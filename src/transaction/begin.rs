@@ -0,0 +1,145 @@
+use super::{AccessMode, IsolationLevel, TransactionStatementBuilder};
+use crate::{backend::TransactionBuilder, error::Result, prepare::*};
+
+/// Start a transaction
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let begin = Transaction::begin().read_only().to_owned();
+///
+/// assert_eq!(
+///     begin.to_string(MysqlQueryBuilder),
+///     r#"START TRANSACTION READ ONLY"#
+/// );
+/// assert_eq!(
+///     begin.to_string(PostgresQueryBuilder),
+///     r#"BEGIN READ ONLY"#
+/// );
+/// assert_eq!(
+///     Transaction::begin().to_string(SqliteQueryBuilder),
+///     r#"BEGIN"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransactionBeginStatement {
+    pub(crate) isolation_level: Option<IsolationLevel>,
+    pub(crate) access_mode: Option<AccessMode>,
+    pub(crate) deferrable: Option<bool>,
+    pub(crate) behavior: Option<TransactionBehavior>,
+}
+
+/// All available SQLite transaction behaviors
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransactionBehavior {
+    Deferred,
+    Immediate,
+    Exclusive,
+}
+
+impl Default for TransactionBeginStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransactionBeginStatement {
+    /// Construct begin transaction statement
+    pub fn new() -> Self {
+        Self {
+            isolation_level: None,
+            access_mode: None,
+            deferrable: None,
+            behavior: None,
+        }
+    }
+
+    /// Set isolation level. Postgres only, use [`TransactionSetStatement`] in MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::*;
+    ///
+    /// assert_eq!(
+    ///     Transaction::begin()
+    ///         .isolation_level(IsolationLevel::Serializable)
+    ///         .read_only()
+    ///         .deferrable(true)
+    ///         .to_string(PostgresQueryBuilder),
+    ///     r#"BEGIN ISOLATION LEVEL SERIALIZABLE, READ ONLY, DEFERRABLE"#
+    /// );
+    /// ```
+    pub fn isolation_level(&mut self, level: IsolationLevel) -> &mut Self {
+        self.isolation_level = Some(level);
+        self
+    }
+
+    /// Set access mode `READ ONLY`
+    pub fn read_only(&mut self) -> &mut Self {
+        self.access_mode = Some(AccessMode::ReadOnly);
+        self
+    }
+
+    /// Set access mode `READ WRITE`
+    pub fn read_write(&mut self) -> &mut Self {
+        self.access_mode = Some(AccessMode::ReadWrite);
+        self
+    }
+
+    /// Set `DEFERRABLE` or `NOT DEFERRABLE`. Postgres only.
+    pub fn deferrable(&mut self, deferrable: bool) -> &mut Self {
+        self.deferrable = Some(deferrable);
+        self
+    }
+
+    /// Set transaction behavior. SQLite only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::*;
+    ///
+    /// assert_eq!(
+    ///     Transaction::begin()
+    ///         .behavior(TransactionBehavior::Immediate)
+    ///         .to_string(SqliteQueryBuilder),
+    ///     r#"BEGIN IMMEDIATE"#
+    /// );
+    /// ```
+    pub fn behavior(&mut self, behavior: TransactionBehavior) -> &mut Self {
+        self.behavior = Some(behavior);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            isolation_level: self.isolation_level.take(),
+            access_mode: self.access_mode.take(),
+            deferrable: self.deferrable.take(),
+            behavior: self.behavior.take(),
+        }
+    }
+}
+
+impl TransactionStatementBuilder for TransactionBeginStatement {
+    fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_begin_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_begin_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, transaction_builder: &dyn TransactionBuilder) -> Result<()> {
+        transaction_builder.check_transaction_begin_statement(self)
+    }
+}
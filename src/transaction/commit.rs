@@ -0,0 +1,40 @@
+use super::TransactionStatementBuilder;
+use crate::{backend::TransactionBuilder, prepare::*};
+
+/// Commit the current transaction
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let commit = Transaction::commit();
+///
+/// assert_eq!(commit.to_string(MysqlQueryBuilder), r#"COMMIT"#);
+/// assert_eq!(commit.to_string(PostgresQueryBuilder), r#"COMMIT"#);
+/// assert_eq!(commit.to_string(SqliteQueryBuilder), r#"COMMIT"#);
+/// ```
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransactionCommitStatement {}
+
+impl TransactionCommitStatement {
+    /// Construct commit statement
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl TransactionStatementBuilder for TransactionCommitStatement {
+    fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_commit_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_commit_statement(self, &mut sql);
+        sql.result()
+    }
+}
@@ -0,0 +1,140 @@
+use super::TransactionStatementBuilder;
+use crate::{backend::TransactionBuilder, error::Result, prepare::*, types::*};
+
+/// Lock tables
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let lock = Transaction::lock()
+///     .table(Glyph::Table)
+///     .table(Font::Table)
+///     .mode(LockMode::Share)
+///     .nowait()
+///     .to_owned();
+///
+/// assert_eq!(
+///     lock.to_string(PostgresQueryBuilder),
+///     r#"LOCK TABLE "glyph", "font" IN SHARE MODE NOWAIT"#
+/// );
+///
+/// let lock = Transaction::lock()
+///     .table_with_mode(Glyph::Table, LockMode::Write)
+///     .table_with_mode(Font::Table, LockMode::Read)
+///     .to_owned();
+///
+/// assert_eq!(
+///     lock.to_string(MysqlQueryBuilder),
+///     r#"LOCK TABLES `glyph` WRITE, `font` READ"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LockTableStatement {
+    pub(crate) tables: Vec<(TableRef, Option<LockMode>)>,
+    pub(crate) mode: Option<LockMode>,
+    pub(crate) nowait: bool,
+}
+
+/// All available table lock modes
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LockMode {
+    /// Postgres only
+    AccessShare,
+    /// Postgres only
+    RowShare,
+    /// Postgres only
+    RowExclusive,
+    /// Postgres only
+    ShareUpdateExclusive,
+    /// Postgres only
+    Share,
+    /// Postgres only
+    ShareRowExclusive,
+    /// Postgres only
+    Exclusive,
+    /// Postgres only
+    AccessExclusive,
+    /// MySQL only
+    Read,
+    /// MySQL only
+    ReadLocal,
+    /// MySQL only
+    Write,
+}
+
+impl Default for LockTableStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LockTableStatement {
+    /// Construct lock table statement
+    pub fn new() -> Self {
+        Self {
+            tables: Vec::new(),
+            mode: None,
+            nowait: false,
+        }
+    }
+
+    /// Add a table to lock
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.tables.push((table.into_table_ref(), None));
+        self
+    }
+
+    /// Add a table to lock in its own mode. MySQL only.
+    pub fn table_with_mode<T>(&mut self, table: T, mode: LockMode) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.tables.push((table.into_table_ref(), Some(mode)));
+        self
+    }
+
+    /// Set the lock mode of all tables
+    pub fn mode(&mut self, mode: LockMode) -> &mut Self {
+        self.mode = Some(mode);
+        self
+    }
+
+    /// Fail instead of waiting for the lock. Postgres only.
+    pub fn nowait(&mut self) -> &mut Self {
+        self.nowait = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            tables: std::mem::take(&mut self.tables),
+            mode: self.mode.take(),
+            nowait: self.nowait,
+        }
+    }
+}
+
+impl TransactionStatementBuilder for LockTableStatement {
+    fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_lock_table_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_lock_table_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, transaction_builder: &dyn TransactionBuilder) -> Result<()> {
+        transaction_builder.check_lock_table_statement(self)
+    }
+}
@@ -0,0 +1,203 @@
+//! Transaction control statements.
+//!
+//! # Usage
+//!
+//! - Begin, see [`TransactionBeginStatement`]
+//! - Set Transaction, see [`TransactionSetStatement`]
+//! - Commit, see [`TransactionCommitStatement`]
+//! - Rollback, see [`TransactionRollbackStatement`]
+//! - Savepoint, see [`SavepointStatement`]
+//! - Release Savepoint, see [`SavepointReleaseStatement`]
+//! - Lock Table, see [`LockTableStatement`]
+
+use crate::{backend::TransactionBuilder, error::Result};
+
+mod begin;
+mod commit;
+mod lock;
+mod release;
+mod rollback;
+mod savepoint;
+mod set;
+mod shim;
+
+pub use begin::*;
+pub use commit::*;
+pub use lock::*;
+pub use release::*;
+pub use rollback::*;
+pub use savepoint::*;
+pub use set::*;
+
+/// Shorthand for constructing any transaction statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transaction;
+
+/// All available transaction isolation levels
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IsolationLevel {
+    ReadUncommitted,
+    ReadCommitted,
+    RepeatableRead,
+    Serializable,
+}
+
+/// All available transaction access modes
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AccessMode {
+    ReadOnly,
+    ReadWrite,
+}
+
+/// All available types of transaction statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TransactionStatement {
+    Begin(TransactionBeginStatement),
+    Set(TransactionSetStatement),
+    Commit(TransactionCommitStatement),
+    Rollback(TransactionRollbackStatement),
+    Savepoint(SavepointStatement),
+    Release(SavepointReleaseStatement),
+    Lock(LockTableStatement),
+}
+
+pub trait TransactionStatementBuilder {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String;
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String;
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    fn to_string<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        self.build(transaction_builder)
+    }
+
+    /// Check that the database backend supports the statement, which would otherwise panic when built
+    fn check(&self, transaction_builder: &dyn TransactionBuilder) -> Result<()> {
+        let _ = transaction_builder;
+        Ok(())
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string,
+    /// or [`Error::Unsupported`](crate::error::Error::Unsupported) if the backend does not support the statement
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{error::Error, *};
+    ///
+    /// let lock = Transaction::lock()
+    ///     .table(Alias::new("glyph"))
+    ///     .mode(LockMode::Exclusive)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     lock.try_to_string(PostgresQueryBuilder),
+    ///     Ok(r#"LOCK TABLE "glyph" IN EXCLUSIVE MODE"#.to_owned())
+    /// );
+    /// assert_eq!(
+    ///     lock.try_to_string(SqliteQueryBuilder),
+    ///     Err(Error::Unsupported("Lock table is not available in SQLite.".to_owned()))
+    /// );
+    /// ```
+    fn try_to_string<T: TransactionBuilder>(&self, transaction_builder: T) -> Result<String> {
+        self.check(&transaction_builder)?;
+        Ok(self.build(transaction_builder))
+    }
+}
+
+impl Transaction {
+    /// Construct [`TransactionBeginStatement`]
+    pub fn begin() -> TransactionBeginStatement {
+        TransactionBeginStatement::new()
+    }
+
+    /// Construct [`TransactionSetStatement`]
+    pub fn set() -> TransactionSetStatement {
+        TransactionSetStatement::new()
+    }
+
+    /// Construct [`TransactionCommitStatement`]
+    pub fn commit() -> TransactionCommitStatement {
+        TransactionCommitStatement::new()
+    }
+
+    /// Construct [`TransactionRollbackStatement`]
+    pub fn rollback() -> TransactionRollbackStatement {
+        TransactionRollbackStatement::new()
+    }
+
+    /// Construct [`SavepointStatement`]
+    pub fn savepoint() -> SavepointStatement {
+        SavepointStatement::new()
+    }
+
+    /// Construct [`SavepointReleaseStatement`]
+    pub fn release() -> SavepointReleaseStatement {
+        SavepointReleaseStatement::new()
+    }
+
+    /// Construct [`LockTableStatement`]
+    pub fn lock() -> LockTableStatement {
+        LockTableStatement::new()
+    }
+}
+
+impl TransactionStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        match self {
+            Self::Begin(stat) => stat.build(transaction_builder),
+            Self::Set(stat) => stat.build(transaction_builder),
+            Self::Commit(stat) => stat.build(transaction_builder),
+            Self::Rollback(stat) => stat.build(transaction_builder),
+            Self::Savepoint(stat) => stat.build(transaction_builder),
+            Self::Release(stat) => stat.build(transaction_builder),
+            Self::Lock(stat) => stat.build(transaction_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        match self {
+            Self::Begin(stat) => stat.build_any(transaction_builder),
+            Self::Set(stat) => stat.build_any(transaction_builder),
+            Self::Commit(stat) => stat.build_any(transaction_builder),
+            Self::Rollback(stat) => stat.build_any(transaction_builder),
+            Self::Savepoint(stat) => stat.build_any(transaction_builder),
+            Self::Release(stat) => stat.build_any(transaction_builder),
+            Self::Lock(stat) => stat.build_any(transaction_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        match self {
+            Self::Begin(stat) => stat.to_string(transaction_builder),
+            Self::Set(stat) => stat.to_string(transaction_builder),
+            Self::Commit(stat) => stat.to_string(transaction_builder),
+            Self::Rollback(stat) => stat.to_string(transaction_builder),
+            Self::Savepoint(stat) => stat.to_string(transaction_builder),
+            Self::Release(stat) => stat.to_string(transaction_builder),
+            Self::Lock(stat) => stat.to_string(transaction_builder),
+        }
+    }
+
+    /// Check that the database backend supports the statement, see [`TransactionStatementBuilder::check`]
+    pub fn check(&self, transaction_builder: &dyn TransactionBuilder) -> Result<()> {
+        match self {
+            Self::Begin(stat) => stat.check(transaction_builder),
+            Self::Set(stat) => stat.check(transaction_builder),
+            Self::Commit(stat) => stat.check(transaction_builder),
+            Self::Rollback(stat) => stat.check(transaction_builder),
+            Self::Savepoint(stat) => stat.check(transaction_builder),
+            Self::Release(stat) => stat.check(transaction_builder),
+            Self::Lock(stat) => stat.check(transaction_builder),
+        }
+    }
+}
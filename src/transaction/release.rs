@@ -0,0 +1,74 @@
+use super::TransactionStatementBuilder;
+use crate::{backend::TransactionBuilder, prepare::*, types::*};
+
+/// Release a savepoint of the current transaction
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let release = Transaction::release()
+///     .name(Alias::new("before_import"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     release.to_string(MysqlQueryBuilder),
+///     r#"RELEASE SAVEPOINT `before_import`"#
+/// );
+/// assert_eq!(
+///     release.to_string(PostgresQueryBuilder),
+///     r#"RELEASE SAVEPOINT "before_import""#
+/// );
+/// assert_eq!(
+///     release.to_string(SqliteQueryBuilder),
+///     r#"RELEASE SAVEPOINT "before_import""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavepointReleaseStatement {
+    pub(crate) name: Option<DynIden>,
+}
+
+impl Default for SavepointReleaseStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SavepointReleaseStatement {
+    /// Construct release savepoint statement
+    pub fn new() -> Self {
+        Self { name: None }
+    }
+
+    /// Set savepoint name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+        }
+    }
+}
+
+impl TransactionStatementBuilder for SavepointReleaseStatement {
+    fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_savepoint_release_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_savepoint_release_statement(self, &mut sql);
+        sql.result()
+    }
+}
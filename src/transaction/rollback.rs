@@ -0,0 +1,74 @@
+use super::TransactionStatementBuilder;
+use crate::{backend::TransactionBuilder, prepare::*, types::*};
+
+/// Roll back the current transaction, or to a savepoint
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let rollback = Transaction::rollback()
+///     .to_savepoint(Alias::new("before_import"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     rollback.to_string(MysqlQueryBuilder),
+///     r#"ROLLBACK TO SAVEPOINT `before_import`"#
+/// );
+/// assert_eq!(
+///     rollback.to_string(PostgresQueryBuilder),
+///     r#"ROLLBACK TO SAVEPOINT "before_import""#
+/// );
+/// assert_eq!(
+///     rollback.to_string(SqliteQueryBuilder),
+///     r#"ROLLBACK TO SAVEPOINT "before_import""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransactionRollbackStatement {
+    pub(crate) savepoint: Option<DynIden>,
+}
+
+impl Default for TransactionRollbackStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransactionRollbackStatement {
+    /// Construct rollback statement
+    pub fn new() -> Self {
+        Self { savepoint: None }
+    }
+
+    /// Only roll back to a savepoint
+    pub fn to_savepoint<T>(&mut self, savepoint: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.savepoint = Some(savepoint.into_iden());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            savepoint: self.savepoint.take(),
+        }
+    }
+}
+
+impl TransactionStatementBuilder for TransactionRollbackStatement {
+    fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_rollback_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_rollback_statement(self, &mut sql);
+        sql.result()
+    }
+}
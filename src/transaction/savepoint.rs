@@ -0,0 +1,74 @@
+use super::TransactionStatementBuilder;
+use crate::{backend::TransactionBuilder, prepare::*, types::*};
+
+/// Define a savepoint in the current transaction
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let savepoint = Transaction::savepoint()
+///     .name(Alias::new("before_import"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     savepoint.to_string(MysqlQueryBuilder),
+///     r#"SAVEPOINT `before_import`"#
+/// );
+/// assert_eq!(
+///     savepoint.to_string(PostgresQueryBuilder),
+///     r#"SAVEPOINT "before_import""#
+/// );
+/// assert_eq!(
+///     savepoint.to_string(SqliteQueryBuilder),
+///     r#"SAVEPOINT "before_import""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavepointStatement {
+    pub(crate) name: Option<DynIden>,
+}
+
+impl Default for SavepointStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SavepointStatement {
+    /// Construct savepoint statement
+    pub fn new() -> Self {
+        Self { name: None }
+    }
+
+    /// Set savepoint name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+        }
+    }
+}
+
+impl TransactionStatementBuilder for SavepointStatement {
+    fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_savepoint_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_savepoint_statement(self, &mut sql);
+        sql.result()
+    }
+}
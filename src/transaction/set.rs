@@ -0,0 +1,98 @@
+use super::{AccessMode, IsolationLevel, TransactionStatementBuilder};
+use crate::{backend::TransactionBuilder, error::Result, prepare::*};
+
+/// Set the characteristics of the current transaction
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::*;
+///
+/// let set = Transaction::set()
+///     .isolation_level(IsolationLevel::RepeatableRead)
+///     .read_write()
+///     .to_owned();
+///
+/// assert_eq!(
+///     set.to_string(MysqlQueryBuilder),
+///     r#"SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ WRITE"#
+/// );
+/// assert_eq!(
+///     set.to_string(PostgresQueryBuilder),
+///     r#"SET TRANSACTION ISOLATION LEVEL REPEATABLE READ, READ WRITE"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TransactionSetStatement {
+    pub(crate) isolation_level: Option<IsolationLevel>,
+    pub(crate) access_mode: Option<AccessMode>,
+    pub(crate) deferrable: Option<bool>,
+}
+
+impl Default for TransactionSetStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TransactionSetStatement {
+    /// Construct set transaction statement
+    pub fn new() -> Self {
+        Self {
+            isolation_level: None,
+            access_mode: None,
+            deferrable: None,
+        }
+    }
+
+    /// Set isolation level
+    pub fn isolation_level(&mut self, level: IsolationLevel) -> &mut Self {
+        self.isolation_level = Some(level);
+        self
+    }
+
+    /// Set access mode `READ ONLY`
+    pub fn read_only(&mut self) -> &mut Self {
+        self.access_mode = Some(AccessMode::ReadOnly);
+        self
+    }
+
+    /// Set access mode `READ WRITE`
+    pub fn read_write(&mut self) -> &mut Self {
+        self.access_mode = Some(AccessMode::ReadWrite);
+        self
+    }
+
+    /// Set `DEFERRABLE` or `NOT DEFERRABLE`. Postgres only.
+    pub fn deferrable(&mut self, deferrable: bool) -> &mut Self {
+        self.deferrable = Some(deferrable);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            isolation_level: self.isolation_level.take(),
+            access_mode: self.access_mode.take(),
+            deferrable: self.deferrable.take(),
+        }
+    }
+}
+
+impl TransactionStatementBuilder for TransactionSetStatement {
+    fn build<T: TransactionBuilder>(&self, transaction_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_set_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, transaction_builder: &dyn TransactionBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        transaction_builder.prepare_transaction_set_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, transaction_builder: &dyn TransactionBuilder) -> Result<()> {
+        transaction_builder.check_transaction_set_statement(self)
+    }
+}
@@ -0,0 +1,21 @@
+use crate::impl_transaction_statement_builder;
+
+impl_transaction_statement_builder!(
+    transaction_begin_statement_builder,
+    TransactionBeginStatement
+);
+impl_transaction_statement_builder!(transaction_set_statement_builder, TransactionSetStatement);
+impl_transaction_statement_builder!(
+    transaction_commit_statement_builder,
+    TransactionCommitStatement
+);
+impl_transaction_statement_builder!(
+    transaction_rollback_statement_builder,
+    TransactionRollbackStatement
+);
+impl_transaction_statement_builder!(savepoint_statement_builder, SavepointStatement);
+impl_transaction_statement_builder!(
+    savepoint_release_statement_builder,
+    SavepointReleaseStatement
+);
+impl_transaction_statement_builder!(lock_table_statement_builder, LockTableStatement);
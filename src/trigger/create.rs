@@ -0,0 +1,268 @@
+use crate::{
+    backend::SchemaBuilder, error::Result, expr::*, prepare::*, types::*, QueryStatement,
+    SchemaStatementBuilder,
+};
+
+/// Create a trigger
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let trigger = Trigger::create()
+///     .name(Alias::new("glyph_audit"))
+///     .after()
+///     .insert()
+///     .table(Glyph::Table)
+///     .for_each_row()
+///     .statement(
+///         Query::insert()
+///             .into_table(Alias::new("audit"))
+///             .columns(vec![Alias::new("glyph_id")])
+///             .exprs_panic(vec![Expr::cust("NEW.id")])
+///             .to_owned(),
+///     )
+///     .to_owned();
+///
+/// assert_eq!(
+///     trigger.to_string(MysqlQueryBuilder),
+///     r#"CREATE TRIGGER `glyph_audit` AFTER INSERT ON `glyph` FOR EACH ROW BEGIN INSERT INTO `audit` (`glyph_id`) VALUES (NEW.id); END"#
+/// );
+/// assert_eq!(
+///     trigger.to_string(SqliteQueryBuilder),
+///     r#"CREATE TRIGGER "glyph_audit" AFTER INSERT ON "glyph" FOR EACH ROW BEGIN INSERT INTO "audit" ("glyph_id") VALUES (NEW.id); END"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriggerCreateStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) table: Option<TableRef>,
+    pub(crate) timing: Option<TriggerTiming>,
+    pub(crate) events: Vec<TriggerEvent>,
+    pub(crate) for_each_row: bool,
+    pub(crate) when: Option<SimpleExpr>,
+    pub(crate) action: Option<TriggerAction>,
+    pub(crate) if_not_exists: bool,
+}
+
+/// When a trigger fires relative to its event
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerTiming {
+    Before,
+    After,
+    InsteadOf,
+}
+
+/// All available trigger events
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerEvent {
+    Insert,
+    /// `UPDATE`, or `UPDATE OF columns` if any column is given
+    Update(Vec<DynIden>),
+    Delete,
+    /// Postgres only
+    Truncate,
+}
+
+/// What a trigger does when it fires
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerAction {
+    /// `EXECUTE FUNCTION name()`, Postgres only
+    ExecuteFunction(DynIden),
+    /// `BEGIN ...; END`, MySQL and SQLite only
+    Statements(Vec<QueryStatement>),
+}
+
+impl Default for TriggerCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TriggerCreateStatement {
+    /// Construct create trigger statement
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            table: None,
+            timing: None,
+            events: Vec::new(),
+            for_each_row: false,
+            when: None,
+            action: None,
+            if_not_exists: false,
+        }
+    }
+
+    /// Set trigger name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the table or view the trigger is attached to
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.table = Some(table.into_table_ref());
+        self
+    }
+
+    /// Create trigger if not exists. Not available in Postgres.
+    pub fn if_not_exists(&mut self) -> &mut Self {
+        self.if_not_exists = true;
+        self
+    }
+
+    /// Set the trigger timing
+    pub fn timing(&mut self, timing: TriggerTiming) -> &mut Self {
+        self.timing = Some(timing);
+        self
+    }
+
+    /// Fire `BEFORE` the event
+    pub fn before(&mut self) -> &mut Self {
+        self.timing(TriggerTiming::Before)
+    }
+
+    /// Fire `AFTER` the event
+    pub fn after(&mut self) -> &mut Self {
+        self.timing(TriggerTiming::After)
+    }
+
+    /// Fire `INSTEAD OF` the event, on views. Not available in MySQL.
+    pub fn instead_of(&mut self) -> &mut Self {
+        self.timing(TriggerTiming::InsteadOf)
+    }
+
+    /// Add an event. Only Postgres accepts more than one event.
+    pub fn event(&mut self, event: TriggerEvent) -> &mut Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Fire on `INSERT`
+    pub fn insert(&mut self) -> &mut Self {
+        self.event(TriggerEvent::Insert)
+    }
+
+    /// Fire on `UPDATE`
+    pub fn update(&mut self) -> &mut Self {
+        self.event(TriggerEvent::Update(Vec::new()))
+    }
+
+    /// Fire on `UPDATE OF columns`. Not available in MySQL.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let trigger = Trigger::create()
+    ///     .name(Alias::new("glyph_touch"))
+    ///     .before()
+    ///     .update_of(vec![Glyph::Aspect, Glyph::Image])
+    ///     .table(Glyph::Table)
+    ///     .for_each_row()
+    ///     .when(Expr::cust("OLD.aspect IS DISTINCT FROM NEW.aspect"))
+    ///     .execute_function(Alias::new("touch_glyph"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     trigger.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE TRIGGER "glyph_touch" BEFORE UPDATE OF "aspect", "image" ON "glyph" FOR EACH ROW WHEN (OLD.aspect IS DISTINCT FROM NEW.aspect) EXECUTE FUNCTION "touch_glyph"()"#
+    /// );
+    /// ```
+    pub fn update_of<T, I>(&mut self, columns: I) -> &mut Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.event(TriggerEvent::Update(
+            columns.into_iter().map(|col| col.into_iden()).collect(),
+        ))
+    }
+
+    /// Fire on `DELETE`
+    pub fn delete(&mut self) -> &mut Self {
+        self.event(TriggerEvent::Delete)
+    }
+
+    /// Fire on `TRUNCATE`. Postgres only.
+    pub fn truncate(&mut self) -> &mut Self {
+        self.event(TriggerEvent::Truncate)
+    }
+
+    /// Fire once for every affected row, instead of once per statement. Required by MySQL.
+    pub fn for_each_row(&mut self) -> &mut Self {
+        self.for_each_row = true;
+        self
+    }
+
+    /// Only fire when the condition holds. Not available in MySQL.
+    pub fn when(&mut self, condition: SimpleExpr) -> &mut Self {
+        self.when = Some(condition);
+        self
+    }
+
+    /// Execute a function when fired. Postgres only.
+    pub fn execute_function<T>(&mut self, function: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.action = Some(TriggerAction::ExecuteFunction(function.into_iden()));
+        self
+    }
+
+    /// Append a statement to the trigger body. MySQL and SQLite only.
+    pub fn statement<S>(&mut self, statement: S) -> &mut Self
+    where
+        S: Into<QueryStatement>,
+    {
+        match &mut self.action {
+            Some(TriggerAction::Statements(statements)) => statements.push(statement.into()),
+            _ => self.action = Some(TriggerAction::Statements(vec![statement.into()])),
+        }
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            table: self.table.take(),
+            timing: self.timing.take(),
+            events: std::mem::take(&mut self.events),
+            for_each_row: self.for_each_row,
+            when: self.when.take(),
+            action: self.action.take(),
+            if_not_exists: self.if_not_exists,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for TriggerCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_trigger_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_trigger_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_trigger_create_statement(self)
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Drop a trigger
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let trigger = Trigger::drop()
+///     .name(Alias::new("glyph_audit"))
+///     .if_exists()
+///     .to_owned();
+///
+/// assert_eq!(
+///     trigger.to_string(MysqlQueryBuilder),
+///     r#"DROP TRIGGER IF EXISTS `glyph_audit`"#
+/// );
+/// assert_eq!(
+///     trigger.to_string(SqliteQueryBuilder),
+///     r#"DROP TRIGGER IF EXISTS "glyph_audit""#
+/// );
+///
+/// let trigger = Trigger::drop()
+///     .name(Alias::new("glyph_audit"))
+///     .table(Glyph::Table)
+///     .if_exists()
+///     .to_owned();
+///
+/// assert_eq!(
+///     trigger.to_string(PostgresQueryBuilder),
+///     r#"DROP TRIGGER IF EXISTS "glyph_audit" ON "glyph""#
+/// );
+/// assert_eq!(
+///     trigger.try_to_string(MysqlQueryBuilder),
+///     Err(error::Error::Unsupported(
+///         "Drop trigger ON table is not available in MySQL.".to_owned()
+///     ))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TriggerDropStatement {
+    pub(crate) name: Option<DynIden>,
+    pub(crate) table: Option<TableRef>,
+    pub(crate) if_exists: bool,
+    pub(crate) option: Option<TriggerDropOpt>,
+}
+
+/// All available trigger drop options
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerDropOpt {
+    Cascade,
+    Restrict,
+}
+
+impl Default for TriggerDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TriggerDropStatement {
+    /// Construct drop trigger statement
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            table: None,
+            if_exists: false,
+            option: None,
+        }
+    }
+
+    /// Set trigger name
+    pub fn name<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.name = Some(name.into_iden());
+        self
+    }
+
+    /// Set the table of the trigger. Postgres only, where it is required.
+    pub fn table<T>(&mut self, table: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.table = Some(table.into_table_ref());
+        self
+    }
+
+    /// Drop trigger if exists
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Drop option cascade. Postgres only.
+    pub fn cascade(&mut self) -> &mut Self {
+        self.option = Some(TriggerDropOpt::Cascade);
+        self
+    }
+
+    /// Drop option restrict. Postgres only.
+    pub fn restrict(&mut self) -> &mut Self {
+        self.option = Some(TriggerDropOpt::Restrict);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            name: self.name.take(),
+            table: self.table.take(),
+            if_exists: self.if_exists,
+            option: self.option.take(),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for TriggerDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_trigger_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_trigger_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_trigger_drop_statement(self)
+    }
+}
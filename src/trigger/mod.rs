@@ -0,0 +1,74 @@
+//! Trigger definition statements.
+//!
+//! # Usage
+//!
+//! - Trigger Create, see [`TriggerCreateStatement`]
+//! - Trigger Drop, see [`TriggerDropStatement`]
+
+use crate::{error::Result, SchemaBuilder, SchemaStatementBuilder};
+
+mod create;
+mod drop;
+mod shim;
+
+pub use create::*;
+pub use drop::*;
+
+/// Shorthand for constructing any trigger statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trigger;
+
+/// All available types of trigger statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TriggerStatement {
+    Create(TriggerCreateStatement),
+    Drop(TriggerDropStatement),
+}
+
+impl Trigger {
+    /// Construct trigger [`TriggerCreateStatement`]
+    pub fn create() -> TriggerCreateStatement {
+        TriggerCreateStatement::new()
+    }
+
+    /// Construct trigger [`TriggerDropStatement`]
+    pub fn drop() -> TriggerDropStatement {
+        TriggerDropStatement::new()
+    }
+}
+
+impl TriggerStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: SchemaBuilder>(&self, trigger_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.build(trigger_builder),
+            Self::Drop(stat) => stat.build(trigger_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, trigger_builder: &dyn SchemaBuilder) -> String {
+        match self {
+            Self::Create(stat) => stat.build_any(trigger_builder),
+            Self::Drop(stat) => stat.build_any(trigger_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: SchemaBuilder>(&self, trigger_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.to_string(trigger_builder),
+            Self::Drop(stat) => stat.to_string(trigger_builder),
+        }
+    }
+
+    /// Check that the database backend supports the statement, see [`SchemaStatementBuilder::check`]
+    pub fn check(&self, trigger_builder: &dyn SchemaBuilder) -> Result<()> {
+        match self {
+            Self::Create(stat) => stat.check(trigger_builder),
+            Self::Drop(stat) => stat.check(trigger_builder),
+        }
+    }
+}
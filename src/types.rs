@@ -57,8 +57,25 @@ impl fmt::Debug for dyn Iden {
     }
 }
 
+/// Identifiers serialize as their unquoted name
+#[cfg(feature = "with-serde")]
+impl serde::Serialize for dyn Iden {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Identifiers deserialize into [`Alias`]
+#[cfg(feature = "with-serde")]
+impl<'de> serde::Deserialize<'de> for Box<dyn Iden> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Box::new(Alias(String::deserialize(deserializer)?)))
+    }
+}
+
 /// Column references
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColumnRef {
     Column(DynIden),
     TableColumn(DynIden, DynIden),
@@ -74,6 +91,7 @@ pub trait IntoColumnRef {
 /// Table references
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TableRef {
     /// Table identifier without any schema / database prefix
     Table(DynIden),
@@ -89,6 +107,8 @@ pub enum TableRef {
     DatabaseSchemaTableAlias(DynIden, DynIden, DynIden, DynIden),
     /// Subquery with alias
     SubQuery(SelectStatement, DynIden),
+    /// Table-valued function call with alias
+    FunctionCall(SimpleExpr, DynIden),
 }
 
 pub trait IntoTableRef {
@@ -97,12 +117,14 @@ pub trait IntoTableRef {
 
 /// Unary operator
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum UnOper {
     Not,
 }
 
 /// Binary operator
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum BinOper {
     And,
     Or,
@@ -137,6 +159,7 @@ pub enum BinOper {
 
 /// Logical chain operator
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum LogicalChainOper {
     And(SimpleExpr),
     Or(SimpleExpr),
@@ -144,6 +167,7 @@ pub enum LogicalChainOper {
 
 /// Join types
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JoinType {
     Join,
     InnerJoin,
@@ -153,6 +177,7 @@ pub enum JoinType {
 
 /// Nulls order
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NullOrdering {
     First,
     Last,
@@ -160,6 +185,7 @@ pub enum NullOrdering {
 
 /// Order expression
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OrderExpr {
     pub(crate) expr: SimpleExpr,
     pub(crate) order: Order,
@@ -168,6 +194,7 @@ pub struct OrderExpr {
 
 /// Join on types
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum JoinOn {
     Condition(Box<ConditionHolder>),
     Columns(Vec<SimpleExpr>),
@@ -175,6 +202,7 @@ pub enum JoinOn {
 
 /// Ordering options
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Order {
     Asc,
     Desc,
@@ -182,16 +210,22 @@ pub enum Order {
 
 /// Helper for create name alias
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Alias(String);
 
 /// Null Alias
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NullAlias;
 
 /// Common SQL Keywords
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Keyword {
     Null,
+    CurrentDate,
+    CurrentTime,
+    CurrentTimestamp,
     Custom(DynIden),
 }
 
@@ -342,6 +376,7 @@ impl TableRef {
                 Self::DatabaseSchemaTableAlias(database, schema, table, alias.into_iden())
             }
             Self::SubQuery(statement, _) => Self::SubQuery(statement, alias.into_iden()),
+            Self::FunctionCall(func, _) => Self::FunctionCall(func, alias.into_iden()),
         }
     }
 }
@@ -18,12 +18,13 @@ use bigdecimal::BigDecimal;
 #[cfg(feature = "with-uuid")]
 use uuid::Uuid;
 
-use crate::ColumnType;
+use crate::{ColumnType, SimpleExpr};
 
 /// Value variants
 ///
 /// We want Value to be exactly 1 pointer sized, so anything larger should be boxed.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Value {
     Bool(Option<bool>),
     TinyInt(Option<i8>),
@@ -118,6 +119,7 @@ pub trait ValueType: Sized {
 }
 
 #[derive(Debug)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ValueTypeErr;
 
 impl std::error::Error for ValueTypeErr {}
@@ -129,9 +131,11 @@ impl std::fmt::Display for ValueTypeErr {
 }
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Values(pub Vec<Value>);
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ValueTuple {
     One(Value),
     Two(Value, Value),
@@ -172,6 +176,12 @@ macro_rules! type_to_value {
             }
         }
 
+        impl From<$type> for SimpleExpr {
+            fn from(x: $type) -> SimpleExpr {
+                SimpleExpr::Value(x.into())
+            }
+        }
+
         impl Nullable for $type {
             fn null() -> Value {
                 Value::$name(None)
@@ -206,6 +216,12 @@ macro_rules! type_to_box_value {
             }
         }
 
+        impl From<$type> for SimpleExpr {
+            fn from(x: $type) -> SimpleExpr {
+                SimpleExpr::Value(x.into())
+            }
+        }
+
         impl Nullable for $type {
             fn null() -> Value {
                 Value::$name(None)
@@ -259,6 +275,18 @@ impl<'a> From<&'a str> for Value {
     }
 }
 
+impl<'a> From<&'a [u8]> for SimpleExpr {
+    fn from(x: &'a [u8]) -> SimpleExpr {
+        SimpleExpr::Value(x.into())
+    }
+}
+
+impl<'a> From<&'a str> for SimpleExpr {
+    fn from(x: &'a str) -> SimpleExpr {
+        SimpleExpr::Value(x.into())
+    }
+}
+
 impl<'a> Nullable for &'a str {
     fn null() -> Value {
         Value::String(None)
@@ -277,6 +305,15 @@ where
     }
 }
 
+impl<T> From<Option<T>> for SimpleExpr
+where
+    T: Into<Value> + Nullable,
+{
+    fn from(x: Option<T>) -> SimpleExpr {
+        SimpleExpr::Value(x.into())
+    }
+}
+
 impl<T> ValueType for Option<T>
 where
     T: ValueType + Nullable,
@@ -333,6 +370,18 @@ mod with_chrono {
         }
     }
 
+    impl From<DateTime<FixedOffset>> for SimpleExpr {
+        fn from(x: DateTime<FixedOffset>) -> SimpleExpr {
+            SimpleExpr::Value(x.into())
+        }
+    }
+
+    impl From<DateTime<Utc>> for SimpleExpr {
+        fn from(x: DateTime<Utc>) -> SimpleExpr {
+            SimpleExpr::Value(x.into())
+        }
+    }
+
     impl Nullable for DateTime<FixedOffset> {
         fn null() -> Value {
             Value::DateTimeWithTimeZone(None)
@@ -459,6 +508,15 @@ mod with_array {
         }
     }
 
+    impl<T> From<Vec<T>> for SimpleExpr
+    where
+        T: Into<Value> + NotU8,
+    {
+        fn from(x: Vec<T>) -> SimpleExpr {
+            SimpleExpr::Value(x.into())
+        }
+    }
+
     impl<T> Nullable for Vec<T>
     where
         T: Into<Value> + NotU8,
@@ -0,0 +1,210 @@
+use crate::{
+    backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder,
+    SelectStatement,
+};
+
+/// Create a view
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let view = View::create()
+///     .view(Alias::new("font_name"))
+///     .columns(vec![Font::Id, Font::Name])
+///     .query(
+///         Query::select()
+///             .columns(vec![Font::Id, Font::Name])
+///             .from(Font::Table)
+///             .and_where(Expr::col(Font::Language).eq("en"))
+///             .to_owned(),
+///     )
+///     .to_owned();
+///
+/// assert_eq!(
+///     view.to_string(MysqlQueryBuilder),
+///     r#"CREATE VIEW `font_name` (`id`, `name`) AS SELECT `id`, `name` FROM `font` WHERE `language` = 'en'"#
+/// );
+/// assert_eq!(
+///     view.to_string(PostgresQueryBuilder),
+///     r#"CREATE VIEW "font_name" ("id", "name") AS SELECT "id", "name" FROM "font" WHERE "language" = 'en'"#
+/// );
+/// assert_eq!(
+///     view.to_string(SqliteQueryBuilder),
+///     r#"CREATE VIEW "font_name" ("id", "name") AS SELECT "id", "name" FROM "font" WHERE "language" = 'en'"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewCreateStatement {
+    pub(crate) view: Option<TableRef>,
+    pub(crate) columns: Vec<DynIden>,
+    pub(crate) query: Option<Box<SelectStatement>>,
+    pub(crate) or_replace: bool,
+    pub(crate) materialized: bool,
+    pub(crate) check_option: bool,
+}
+
+impl Default for ViewCreateStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViewCreateStatement {
+    /// Construct create view statement
+    pub fn new() -> Self {
+        Self {
+            view: None,
+            columns: Vec::new(),
+            query: None,
+            or_replace: false,
+            materialized: false,
+            check_option: false,
+        }
+    }
+
+    /// Set view name
+    pub fn view<T>(&mut self, view: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.view = Some(view.into_table_ref());
+        self
+    }
+
+    /// Add a column to the column list of view
+    pub fn column<C>(&mut self, column: C) -> &mut Self
+    where
+        C: IntoIden,
+    {
+        self.columns.push(column.into_iden());
+        self
+    }
+
+    /// Set the column list of view
+    pub fn columns<T, I>(&mut self, columns: I) -> &mut Self
+    where
+        T: IntoIden,
+        I: IntoIterator<Item = T>,
+    {
+        self.columns
+            .extend(columns.into_iter().map(|col| col.into_iden()));
+        self
+    }
+
+    /// Set the select statement defining the view
+    pub fn query(&mut self, query: SelectStatement) -> &mut Self {
+        self.query = Some(Box::new(query));
+        self
+    }
+
+    /// Replace the view if it already exists. Not available in SQLite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let view = View::create()
+    ///     .or_replace()
+    ///     .view(Alias::new("font_name"))
+    ///     .query(
+    ///         Query::select()
+    ///             .column(Font::Name)
+    ///             .from(Font::Table)
+    ///             .to_owned(),
+    ///     )
+    ///     .with_check_option()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     view.to_string(MysqlQueryBuilder),
+    ///     r#"CREATE OR REPLACE VIEW `font_name` AS SELECT `name` FROM `font` WITH CHECK OPTION"#
+    /// );
+    /// assert_eq!(
+    ///     view.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE OR REPLACE VIEW "font_name" AS SELECT "name" FROM "font" WITH CHECK OPTION"#
+    /// );
+    /// ```
+    pub fn or_replace(&mut self) -> &mut Self {
+        self.or_replace = true;
+        self
+    }
+
+    /// Create a materialized view. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let view = View::create()
+    ///     .materialized()
+    ///     .view(Alias::new("font_count"))
+    ///     .query(
+    ///         Query::select()
+    ///             .expr(Func::count(Expr::col(Font::Id)))
+    ///             .from(Font::Table)
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     view.to_string(PostgresQueryBuilder),
+    ///     r#"CREATE MATERIALIZED VIEW "font_count" AS SELECT COUNT("id") FROM "font""#
+    /// );
+    /// ```
+    pub fn materialized(&mut self) -> &mut Self {
+        self.materialized = true;
+        self
+    }
+
+    /// Reject inserts and updates through the view that the view could not select. Not available in SQLite.
+    pub fn with_check_option(&mut self) -> &mut Self {
+        self.check_option = true;
+        self
+    }
+
+    pub fn get_view_name(&self) -> Option<&TableRef> {
+        self.view.as_ref()
+    }
+
+    pub fn get_columns(&self) -> &Vec<DynIden> {
+        self.columns.as_ref()
+    }
+
+    pub fn get_query(&self) -> Option<&SelectStatement> {
+        self.query.as_deref()
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            view: self.view.take(),
+            columns: std::mem::take(&mut self.columns),
+            query: self.query.take(),
+            or_replace: self.or_replace,
+            materialized: self.materialized,
+            check_option: self.check_option,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for ViewCreateStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_create_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_view_create_statement(self)
+    }
+}
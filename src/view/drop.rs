@@ -0,0 +1,125 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Drop views
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let view = View::drop()
+///     .view(Alias::new("font_name"))
+///     .view(Alias::new("glyph_image"))
+///     .if_exists()
+///     .cascade()
+///     .to_owned();
+///
+/// assert_eq!(
+///     view.to_string(MysqlQueryBuilder),
+///     r#"DROP VIEW IF EXISTS `font_name`, `glyph_image` CASCADE"#
+/// );
+/// assert_eq!(
+///     view.to_string(PostgresQueryBuilder),
+///     r#"DROP VIEW IF EXISTS "font_name", "glyph_image" CASCADE"#
+/// );
+/// assert_eq!(
+///     view.try_to_string(SqliteQueryBuilder),
+///     Err(error::Error::Unsupported(
+///         "Dropping multiple views is not available in SQLite.".to_owned()
+///     ))
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewDropStatement {
+    pub(crate) views: Vec<TableRef>,
+    pub(crate) options: Vec<ViewDropOpt>,
+    pub(crate) if_exists: bool,
+    pub(crate) materialized: bool,
+}
+
+/// All available view drop options
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ViewDropOpt {
+    Restrict,
+    Cascade,
+}
+
+impl Default for ViewDropStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViewDropStatement {
+    /// Construct drop view statement
+    pub fn new() -> Self {
+        Self {
+            views: Vec::new(),
+            options: Vec::new(),
+            if_exists: false,
+            materialized: false,
+        }
+    }
+
+    /// Set view name
+    pub fn view<T>(&mut self, view: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.views.push(view.into_table_ref());
+        self
+    }
+
+    /// Drop view if exists
+    pub fn if_exists(&mut self) -> &mut Self {
+        self.if_exists = true;
+        self
+    }
+
+    /// Drop a materialized view. Postgres only.
+    pub fn materialized(&mut self) -> &mut Self {
+        self.materialized = true;
+        self
+    }
+
+    /// Drop option restrict. Ignored by SQLite.
+    pub fn restrict(&mut self) -> &mut Self {
+        self.options.push(ViewDropOpt::Restrict);
+        self
+    }
+
+    /// Drop option cascade. Ignored by SQLite.
+    pub fn cascade(&mut self) -> &mut Self {
+        self.options.push(ViewDropOpt::Cascade);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            views: std::mem::take(&mut self.views),
+            options: std::mem::take(&mut self.options),
+            if_exists: self.if_exists,
+            materialized: self.materialized,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for ViewDropStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_drop_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_view_drop_statement(self)
+    }
+}
@@ -0,0 +1,100 @@
+//! View definition & alternations statements.
+//!
+//! # Usage
+//!
+//! - View Create, see [`ViewCreateStatement`]
+//! - View Drop, see [`ViewDropStatement`]
+//! - View Rename, see [`ViewRenameStatement`]
+//! - Materialized View Refresh, see [`ViewRefreshStatement`]
+
+use crate::{error::Result, SchemaBuilder, SchemaStatementBuilder};
+
+mod create;
+mod drop;
+mod refresh;
+mod rename;
+mod shim;
+
+pub use create::*;
+pub use drop::*;
+pub use refresh::*;
+pub use rename::*;
+
+/// Shorthand for constructing any view statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct View;
+
+/// All available types of view statement
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ViewStatement {
+    Create(ViewCreateStatement),
+    Drop(ViewDropStatement),
+    Rename(ViewRenameStatement),
+    Refresh(ViewRefreshStatement),
+}
+
+impl View {
+    /// Construct view [`ViewCreateStatement`]
+    pub fn create() -> ViewCreateStatement {
+        ViewCreateStatement::new()
+    }
+
+    /// Construct view [`ViewDropStatement`]
+    pub fn drop() -> ViewDropStatement {
+        ViewDropStatement::new()
+    }
+
+    /// Construct view [`ViewRenameStatement`]
+    pub fn rename() -> ViewRenameStatement {
+        ViewRenameStatement::new()
+    }
+
+    /// Construct view [`ViewRefreshStatement`]
+    pub fn refresh() -> ViewRefreshStatement {
+        ViewRefreshStatement::new()
+    }
+}
+
+impl ViewStatement {
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build<T: SchemaBuilder>(&self, view_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.build(view_builder),
+            Self::Drop(stat) => stat.build(view_builder),
+            Self::Rename(stat) => stat.build(view_builder),
+            Self::Refresh(stat) => stat.build(view_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn build_any(&self, view_builder: &dyn SchemaBuilder) -> String {
+        match self {
+            Self::Create(stat) => stat.build_any(view_builder),
+            Self::Drop(stat) => stat.build_any(view_builder),
+            Self::Rename(stat) => stat.build_any(view_builder),
+            Self::Refresh(stat) => stat.build_any(view_builder),
+        }
+    }
+
+    /// Build corresponding SQL statement for certain database backend and return SQL string
+    pub fn to_string<T: SchemaBuilder>(&self, view_builder: T) -> String {
+        match self {
+            Self::Create(stat) => stat.to_string(view_builder),
+            Self::Drop(stat) => stat.to_string(view_builder),
+            Self::Rename(stat) => stat.to_string(view_builder),
+            Self::Refresh(stat) => stat.to_string(view_builder),
+        }
+    }
+
+    /// Check that the database backend supports the statement, see [`SchemaStatementBuilder::check`]
+    pub fn check(&self, view_builder: &dyn SchemaBuilder) -> Result<()> {
+        match self {
+            Self::Create(stat) => stat.check(view_builder),
+            Self::Drop(stat) => stat.check(view_builder),
+            Self::Rename(stat) => stat.check(view_builder),
+            Self::Refresh(stat) => stat.check(view_builder),
+        }
+    }
+}
@@ -0,0 +1,106 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Refresh a materialized view, Postgres only
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let view = View::refresh()
+///     .view(Alias::new("font_count"))
+///     .concurrently()
+///     .to_owned();
+///
+/// assert_eq!(
+///     view.to_string(PostgresQueryBuilder),
+///     r#"REFRESH MATERIALIZED VIEW CONCURRENTLY "font_count""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewRefreshStatement {
+    pub(crate) view: Option<TableRef>,
+    pub(crate) concurrently: bool,
+    pub(crate) with_data: Option<bool>,
+}
+
+impl Default for ViewRefreshStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViewRefreshStatement {
+    /// Construct refresh materialized view statement
+    pub fn new() -> Self {
+        Self {
+            view: None,
+            concurrently: false,
+            with_data: None,
+        }
+    }
+
+    /// Set view name
+    pub fn view<T>(&mut self, view: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.view = Some(view.into_table_ref());
+        self
+    }
+
+    /// Refresh without locking out concurrent selects
+    pub fn concurrently(&mut self) -> &mut Self {
+        self.concurrently = true;
+        self
+    }
+
+    /// Append `WITH DATA` or `WITH NO DATA`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let view = View::refresh()
+    ///     .view(Alias::new("font_count"))
+    ///     .with_data(false)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     view.to_string(PostgresQueryBuilder),
+    ///     r#"REFRESH MATERIALIZED VIEW "font_count" WITH NO DATA"#
+    /// );
+    /// ```
+    pub fn with_data(&mut self, with_data: bool) -> &mut Self {
+        self.with_data = Some(with_data);
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            view: self.view.take(),
+            concurrently: self.concurrently,
+            with_data: self.with_data.take(),
+        }
+    }
+}
+
+impl SchemaStatementBuilder for ViewRefreshStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_refresh_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_refresh_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_view_refresh_statement(self)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{backend::SchemaBuilder, error::Result, prepare::*, types::*, SchemaStatementBuilder};
+
+/// Rename a view
+///
+/// # Examples
+///
+/// ```
+/// use sea_query::{tests_cfg::*, *};
+///
+/// let view = View::rename()
+///     .view(Alias::new("font_name"), Alias::new("font_name_new"))
+///     .to_owned();
+///
+/// assert_eq!(
+///     view.to_string(MysqlQueryBuilder),
+///     r#"RENAME TABLE `font_name` TO `font_name_new`"#
+/// );
+/// assert_eq!(
+///     view.to_string(PostgresQueryBuilder),
+///     r#"ALTER VIEW "font_name" RENAME TO "font_name_new""#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "with-serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ViewRenameStatement {
+    pub(crate) from_name: Option<TableRef>,
+    pub(crate) to_name: Option<DynIden>,
+    pub(crate) materialized: bool,
+}
+
+impl Default for ViewRenameStatement {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ViewRenameStatement {
+    /// Construct rename view statement
+    pub fn new() -> Self {
+        Self {
+            from_name: None,
+            to_name: None,
+            materialized: false,
+        }
+    }
+
+    /// Set old and new view name
+    pub fn view<T, R>(&mut self, from_name: T, to_name: R) -> &mut Self
+    where
+        T: IntoTableRef,
+        R: IntoIden,
+    {
+        self.from_name = Some(from_name.into_table_ref());
+        self.to_name = Some(to_name.into_iden());
+        self
+    }
+
+    /// Rename a materialized view. Postgres only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use sea_query::{tests_cfg::*, *};
+    ///
+    /// let view = View::rename()
+    ///     .materialized()
+    ///     .view(Alias::new("font_count"), Alias::new("font_total"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     view.to_string(PostgresQueryBuilder),
+    ///     r#"ALTER MATERIALIZED VIEW "font_count" RENAME TO "font_total""#
+    /// );
+    /// ```
+    pub fn materialized(&mut self) -> &mut Self {
+        self.materialized = true;
+        self
+    }
+
+    pub fn take(&mut self) -> Self {
+        Self {
+            from_name: self.from_name.take(),
+            to_name: self.to_name.take(),
+            materialized: self.materialized,
+        }
+    }
+}
+
+impl SchemaStatementBuilder for ViewRenameStatement {
+    fn build<T: SchemaBuilder>(&self, schema_builder: T) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_rename_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn build_any(&self, schema_builder: &dyn SchemaBuilder) -> String {
+        let mut sql = SqlWriter::new();
+        schema_builder.prepare_view_rename_statement(self, &mut sql);
+        sql.result()
+    }
+
+    fn check(&self, schema_builder: &dyn SchemaBuilder) -> Result<()> {
+        schema_builder.check_view_rename_statement(self)
+    }
+}
@@ -0,0 +1,340 @@
+use sea_query::{codegen::Codegen, *};
+
+/// Compiles the expected code and keeps its tokens for comparison with the generated ones
+macro_rules! expected {
+    ( $( $code: tt )* ) => {
+        $( $code )*
+
+        fn expected() -> proc_macro2::TokenStream {
+            quote::quote! { $( $code )* }
+        }
+    };
+}
+
+macro_rules! assert_same_sql {
+    ( $left: expr, $right: expr ) => {
+        assert_eq!(
+            $left.to_string(MysqlQueryBuilder),
+            $right.to_string(MysqlQueryBuilder)
+        );
+        assert_eq!(
+            $left.to_string(PostgresQueryBuilder),
+            $right.to_string(PostgresQueryBuilder)
+        );
+        assert_eq!(
+            $left.to_string(SqliteQueryBuilder),
+            $right.to_string(SqliteQueryBuilder)
+        );
+    };
+}
+
+mod codegen_1 {
+    use super::*;
+
+    expected! {
+        #[derive(Copy, Clone, Debug, sea_query::Iden)]
+        pub enum Character {
+            Table,
+            Id,
+            Symbol,
+            FontSize,
+            FontId,
+            CreatedAt
+        }
+        impl Character {
+            pub fn create_table() -> sea_query::TableCreateStatement {
+                sea_query::Table::create()
+                    .table(Self::Table)
+                    .col(
+                        sea_query::ColumnDef::new(Self::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key()
+                    )
+                    .col(sea_query::ColumnDef::new(Self::Symbol).string_len(255).not_null())
+                    .col(sea_query::ColumnDef::new(Self::FontSize).integer().default(12i32))
+                    .col(sea_query::ColumnDef::new(Self::FontId).integer())
+                    .col(
+                        sea_query::ColumnDef::new(Self::CreatedAt)
+                            .timestamp()
+                            .default(sea_query::Expr::current_timestamp())
+                    )
+                    .index(
+                        sea_query::Index::create()
+                            .name("idx-character-symbol")
+                            .col(sea_query::IndexColumn::new(Self::Symbol).order(sea_query::IndexOrder::Desc))
+                            .unique()
+                    )
+                    .foreign_key(
+                        sea_query::ForeignKey::create()
+                            .name("fk-character-font")
+                            .from_tbl(Self::Table)
+                            .from_col(Self::FontId)
+                            .to_tbl(Font::Table)
+                            .to_col(Font::Id)
+                            .on_delete(sea_query::ForeignKeyAction::Cascade)
+                    )
+                    .to_owned()
+            }
+        }
+        #[derive(Copy, Clone, Debug, sea_query::Iden)]
+        pub enum Font {
+            Table,
+            Id,
+            Name
+        }
+        impl Font {
+            pub fn create_table() -> sea_query::TableCreateStatement {
+                sea_query::Table::create()
+                    .table(Self::Table)
+                    .col(sea_query::ColumnDef::new(Self::Id).integer().not_null().primary_key())
+                    .col(sea_query::ColumnDef::new(Self::Name).text().default("Sans"))
+                    .to_owned()
+            }
+        }
+    }
+
+    #[test]
+    fn codegen_1() {
+        let character = Table::create()
+            .table(Alias::new("character"))
+            .col(
+                ColumnDef::new(Alias::new("id"))
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key(),
+            )
+            .col(
+                ColumnDef::new(Alias::new("symbol"))
+                    .string_len(255)
+                    .not_null(),
+            )
+            .col(
+                ColumnDef::new(Alias::new("font_size"))
+                    .integer()
+                    .default(12),
+            )
+            .col(ColumnDef::new(Alias::new("font_id")).integer())
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp()
+                    .default(Expr::current_timestamp()),
+            )
+            .index(
+                Index::create()
+                    .name("idx-character-symbol")
+                    .col((Alias::new("symbol"), IndexOrder::Desc))
+                    .unique(),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-character-font")
+                    .from(Alias::new("character"), Alias::new("font_id"))
+                    .to(Alias::new("font"), Alias::new("id"))
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned();
+        let font = Table::create()
+            .table(Alias::new("font"))
+            .col(
+                ColumnDef::new(Alias::new("id"))
+                    .integer()
+                    .not_null()
+                    .primary_key(),
+            )
+            .col(ColumnDef::new(Alias::new("name")).text().default("Sans"))
+            .to_owned();
+
+        let tokens = Codegen::new()
+            .table(&mut character.clone())
+            .table(&mut font.clone())
+            .generate();
+
+        assert_eq!(tokens.to_string(), expected().to_string());
+        assert_same_sql!(Character::create_table(), character);
+        assert_same_sql!(Font::create_table(), font);
+    }
+}
+
+mod codegen_2 {
+    use super::*;
+
+    expected! {
+        #[derive(Copy, Clone, Debug, sea_query::Iden)]
+        #[iden = "glyph_v2"]
+        pub enum GlyphV2 {
+            Table,
+            #[iden = "glyphId"]
+            GlyphId,
+            #[iden = "table"]
+            TableColumn,
+            Aspect,
+            Kind,
+            ParentId,
+            Tags
+        }
+        impl GlyphV2 {
+            pub fn create_table() -> sea_query::TableCreateStatement {
+                sea_query::Table::create()
+                    .table((sea_query::Alias::new("public"), Self::Table))
+                    .col(sea_query::ColumnDef::new(Self::GlyphId).big_integer().not_null())
+                    .col(sea_query::ColumnDef::new(Self::TableColumn).string())
+                    .col(
+                        sea_query::ColumnDef::new(Self::Aspect)
+                            .decimal_len(10, 2)
+                            .default(sea_query::Expr::cust("(1 + 1)"))
+                    )
+                    .col(sea_query::ColumnDef::new(Self::Kind).enumeration("glyph_kind", ["outline", "bitmap"]))
+                    .col(sea_query::ColumnDef::new(Self::ParentId).big_integer())
+                    .col(sea_query::ColumnDef::new(Self::Tags).custom(sea_query::Alias::new("tsvector")))
+                    .primary_key(sea_query::Index::create().col(Self::GlyphId).col(Self::TableColumn))
+                    .foreign_key(
+                        sea_query::ForeignKey::create()
+                            .name("fk-glyph-parent")
+                            .from_col(Self::ParentId)
+                            .to_tbl(Self::Table)
+                            .to_col(Self::GlyphId)
+                    )
+                    .foreign_key(
+                        sea_query::ForeignKey::create()
+                            .from_col(Self::Kind)
+                            .to_tbl(sea_query::Alias::new("kind"))
+                            .to_col(sea_query::Alias::new("name"))
+                    )
+                    .comment("Glyphs of all fonts")
+                    .to_owned()
+            }
+        }
+    }
+
+    #[test]
+    fn codegen_2() {
+        let glyph = Table::create()
+            .table((Alias::new("public"), Alias::new("glyph_v2")))
+            .col(
+                ColumnDef::new(Alias::new("glyphId"))
+                    .big_integer()
+                    .not_null(),
+            )
+            .col(ColumnDef::new(Alias::new("table")).string())
+            .col(
+                ColumnDef::new(Alias::new("aspect"))
+                    .decimal_len(10, 2)
+                    .default(Expr::val(1).add(1)),
+            )
+            .col(
+                ColumnDef::new(Alias::new("kind")).enumeration("glyph_kind", ["outline", "bitmap"]),
+            )
+            .col(ColumnDef::new(Alias::new("parent_id")).big_integer())
+            .col(ColumnDef::new(Alias::new("tags")).custom(Alias::new("tsvector")))
+            .primary_key(
+                Index::create()
+                    .col(Alias::new("glyphId"))
+                    .col(Alias::new("table")),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-glyph-parent")
+                    .from_col(Alias::new("parent_id"))
+                    .to_tbl(Alias::new("glyph_v2"))
+                    .to_col(Alias::new("glyphId")),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .from_col(Alias::new("kind"))
+                    .to_tbl(Alias::new("kind"))
+                    .to_col(Alias::new("name")),
+            )
+            .comment("Glyphs of all fonts")
+            .to_owned();
+
+        let tokens = Codegen::new().table(&mut glyph.clone()).generate();
+
+        assert_eq!(tokens.to_string(), expected().to_string());
+        assert_eq!(
+            GlyphV2::create_table().to_string(PostgresQueryBuilder),
+            glyph.to_string(PostgresQueryBuilder)
+        );
+    }
+}
+
+mod codegen_3 {
+    use super::*;
+
+    expected! {
+        #[derive(Copy, Clone, Debug, sea_query::Iden)]
+        pub enum Font {
+            Table,
+            FontId,
+            #[iden = "FontId"]
+            FontId2,
+            #[iden = "a-b"]
+            AB,
+            #[iden = "a_b"]
+            AB2
+        }
+        impl Font {
+            pub fn create_table() -> sea_query::TableCreateStatement {
+                sea_query::Table::create()
+                    .table(Self::Table)
+                    .col(sea_query::ColumnDef::new(Self::FontId).integer())
+                    .col(sea_query::ColumnDef::new(Self::FontId2).integer())
+                    .col(sea_query::ColumnDef::new(Self::AB).text())
+                    .col(sea_query::ColumnDef::new(Self::AB2).text())
+                    .to_owned()
+            }
+        }
+        #[derive(Copy, Clone, Debug, sea_query::Iden)]
+        #[iden = "Font"]
+        pub enum Font2 {
+            Table,
+            Id
+        }
+        impl Font2 {
+            pub fn create_table() -> sea_query::TableCreateStatement {
+                sea_query::Table::create()
+                    .table(Self::Table)
+                    .col(sea_query::ColumnDef::new(Self::Id).integer())
+                    .foreign_key(
+                        sea_query::ForeignKey::create()
+                            .from_col(Self::Id)
+                            .to_tbl(Font::Table)
+                            .to_col(Font::FontId2)
+                    )
+                    .to_owned()
+            }
+        }
+    }
+
+    #[test]
+    fn codegen_3() {
+        let lower = Table::create()
+            .table(Alias::new("font"))
+            .col(ColumnDef::new(Alias::new("font_id")).integer())
+            .col(ColumnDef::new(Alias::new("FontId")).integer())
+            .col(ColumnDef::new(Alias::new("a-b")).text())
+            .col(ColumnDef::new(Alias::new("a_b")).text())
+            .to_owned();
+        let upper = Table::create()
+            .table(Alias::new("Font"))
+            .col(ColumnDef::new(Alias::new("id")).integer())
+            .foreign_key(
+                ForeignKey::create()
+                    .from_col(Alias::new("id"))
+                    .to_tbl(Alias::new("font"))
+                    .to_col(Alias::new("FontId")),
+            )
+            .to_owned();
+
+        let tokens = Codegen::new()
+            .table(&mut lower.clone())
+            .table(&mut upper.clone())
+            .generate();
+
+        assert_eq!(tokens.to_string(), expected().to_string());
+        assert_same_sql!(Font::create_table(), lower);
+        assert_same_sql!(Font2::create_table(), upper);
+    }
+}
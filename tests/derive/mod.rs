@@ -77,3 +77,284 @@ fn derive_4() {
     assert_eq!(Iden::to_string(&SomeType), "some_type");
     assert_eq!(Iden::to_string(&CustomName), "another_name");
 }
+
+#[test]
+fn derive_5() {
+    #[derive(Debug, Iden)]
+    #[allow(dead_code)]
+    struct User {
+        id: i32,
+        first_name: String,
+        #[iden = "mail"]
+        email: String,
+    }
+
+    #[derive(Debug, Iden)]
+    #[iden = "person"]
+    #[allow(dead_code)]
+    struct Customer {
+        id: i32,
+    }
+
+    println!("Named structs");
+    assert_eq!(Iden::to_string(&UserIden::Table), "user");
+    assert_eq!(Iden::to_string(&UserIden::Id), "id");
+    assert_eq!(Iden::to_string(&UserIden::FirstName), "first_name");
+    assert_eq!(Iden::to_string(&UserIden::Email), "mail");
+    assert_eq!(Iden::to_string(&CustomerIden::Table), "person");
+    assert_eq!(Iden::to_string(&CustomerIden::Id), "id");
+}
+
+#[test]
+fn derive_6() {
+    #[derive(Debug, Iden)]
+    #[iden(rename_all = "PascalCase")]
+    enum LegacyUser {
+        Table,
+        UserId,
+        #[iden = "mail"]
+        Email,
+    }
+
+    #[derive(Debug, Iden)]
+    #[iden(rename_all = "kebab-case")]
+    #[iden(prefix = "acct-")]
+    #[allow(dead_code)]
+    struct Account {
+        id: i32,
+        display_name: String,
+    }
+
+    println!("Case conventions");
+    assert_eq!(Iden::to_string(&LegacyUser::Table), "LegacyUser");
+    assert_eq!(Iden::to_string(&LegacyUser::UserId), "UserId");
+    assert_eq!(Iden::to_string(&LegacyUser::Email), "mail");
+    assert_eq!(Iden::to_string(&AccountIden::Table), "account");
+    assert_eq!(Iden::to_string(&AccountIden::Id), "acct-id");
+    assert_eq!(
+        Iden::to_string(&AccountIden::DisplayName),
+        "acct-display-name"
+    );
+}
+
+#[test]
+fn derive_table_1() {
+    #[derive(Debug, Iden, Table)]
+    #[index(name = "idx-character-font", columns(font_id, font_size), unique)]
+    #[allow(dead_code)]
+    struct Character {
+        #[column(primary_key, auto_increment)]
+        id: i32,
+        #[column(unique)]
+        character: String,
+        #[column(default = 12)]
+        font_size: i16,
+        #[foreign_key(
+            name = "fk-character-font",
+            table = "font",
+            column = "id",
+            on_delete = "Cascade"
+        )]
+        font_id: Option<i64>,
+        #[column(type = "string_len(64)")]
+        #[iden = "note"]
+        comment: Option<String>,
+        #[column(type = "timestamp", default_expr = "CURRENT_TIMESTAMP")]
+        created_at: String,
+    }
+
+    assert_eq!(Iden::to_string(&CharacterIden::Comment), "note");
+    assert_eq!(
+        Character::create_table().to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "character" ("#,
+            r#""id" serial NOT NULL PRIMARY KEY,"#,
+            r#""character" varchar NOT NULL UNIQUE,"#,
+            r#""font_size" smallint NOT NULL DEFAULT 12,"#,
+            r#""font_id" bigint,"#,
+            r#""note" varchar(64),"#,
+            r#""created_at" timestamp NOT NULL DEFAULT CURRENT_TIMESTAMP,"#,
+            r#"CONSTRAINT "idx-character-font" UNIQUE ("font_id", "font_size"),"#,
+            r#"CONSTRAINT "fk-character-font""#,
+            r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+            r#"ON DELETE CASCADE"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn derive_table_2() {
+    #[derive(Debug, Table)]
+    #[iden = "glyph_tags"]
+    #[iden(rename_all = "camelCase")]
+    #[allow(dead_code)]
+    struct GlyphTag {
+        #[column(primary_key)]
+        glyph_id: i64,
+        #[column(primary_key)]
+        tag_name: String,
+        weight: Option<f64>,
+        visible: bool,
+        data: Vec<u8>,
+    }
+
+    assert_eq!(
+        GlyphTag::create_table().to_string(MysqlQueryBuilder),
+        [
+            "CREATE TABLE `glyph_tags` (",
+            "`glyphId` bigint NOT NULL,",
+            "`tagName` varchar(255) NOT NULL,",
+            "`weight` double,",
+            "`visible` bool NOT NULL,",
+            "`data` blob NOT NULL,",
+            "PRIMARY KEY (`glyphId`, `tagName`)",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn derive_into_row_1() {
+    #[derive(Debug, IntoRow)]
+    struct Glyph {
+        #[skip_insert]
+        id: i32,
+        aspect: f64,
+        image: Option<String>,
+    }
+
+    let glyphs = vec![
+        Glyph {
+            id: 0,
+            aspect: 2.1345,
+            image: Some("24B".to_owned()),
+        },
+        Glyph {
+            id: 0,
+            aspect: 5.15,
+            image: None,
+        },
+    ];
+
+    assert_eq!(
+        Query::insert()
+            .into_table(Alias::new("glyph"))
+            .from_rows(&glyphs)
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "glyph" ("aspect", "image") VALUES (2.1345, '24B'), (5.15, NULL)"#
+    );
+}
+
+#[test]
+fn derive_into_row_2() {
+    #[derive(Debug, IntoRow)]
+    #[iden(rename_all = "camelCase")]
+    #[allow(dead_code)]
+    struct Font {
+        #[skip_update]
+        id: i32,
+        #[iden = "title"]
+        name: String,
+        font_size: u16,
+        #[skip_insert]
+        #[skip_update]
+        updated_at: Option<String>,
+    }
+
+    let font = Font {
+        id: 1,
+        name: "Sans".to_owned(),
+        font_size: 12,
+        updated_at: None,
+    };
+
+    assert_eq!(
+        Query::insert()
+            .into_table(Alias::new("font"))
+            .from_rows([&font])
+            .to_string(MysqlQueryBuilder),
+        "INSERT INTO `font` (`id`, `title`, `fontSize`) VALUES (1, 'Sans', 12)"
+    );
+    assert_eq!(
+        Query::update()
+            .table(Alias::new("font"))
+            .set_from(&font)
+            .and_where(Expr::col(Alias::new("id")).eq(font.id))
+            .to_string(MysqlQueryBuilder),
+        "UPDATE `font` SET `title` = 'Sans', `fontSize` = 12 WHERE `id` = 1"
+    );
+}
+
+#[test]
+fn derive_db_enum_1() {
+    #[derive(Debug, Clone, Copy, DbEnum)]
+    #[allow(dead_code)]
+    enum FontFamily {
+        Serif,
+        Sans,
+        #[iden = "mono"]
+        Monospace,
+    }
+
+    assert_eq!(
+        FontFamily::create_type().to_string(PostgresQueryBuilder),
+        r#"CREATE TYPE "font_family" AS ENUM ('serif', 'sans', 'mono')"#
+    );
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("font"))
+            .col(&mut ColumnDef::new_with_type(
+                Alias::new("family"),
+                FontFamily::column_type()
+            ))
+            .to_string(MysqlQueryBuilder),
+        "CREATE TABLE `font` ( `family` ENUM('serif', 'sans', 'mono') )"
+    );
+    assert_eq!(
+        Query::insert()
+            .into_table(Alias::new("font"))
+            .columns(vec![Alias::new("family")])
+            .exprs_panic(vec![FontFamily::Monospace.as_enum()])
+            .to_string(PostgresQueryBuilder),
+        r#"INSERT INTO "font" ("family") VALUES (CAST('mono' AS font_family))"#
+    );
+    assert_eq!(
+        Value::from(FontFamily::Sans),
+        Value::String(Some(Box::new("sans".to_owned())))
+    );
+}
+
+#[test]
+fn derive_db_enum_2() {
+    #[derive(Debug, DbEnum)]
+    #[iden = "glyph_kind"]
+    #[iden(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[allow(dead_code)]
+    enum Kind {
+        Outline,
+        ColorBitmap,
+        Bitmap,
+        Svg,
+    }
+
+    assert_eq!(Kind::type_name(), "glyph_kind");
+    assert_eq!(
+        Kind::values(),
+        vec!["OUTLINE", "COLOR_BITMAP", "BITMAP", "SVG"]
+    );
+    assert_eq!(
+        Kind::alter_type(&["BITMAP"])
+            .iter()
+            .map(|alter| alter.to_string(PostgresQueryBuilder))
+            .collect::<Vec<_>>(),
+        vec![
+            r#"ALTER TYPE "glyph_kind" ADD VALUE 'OUTLINE' BEFORE 'BITMAP'"#,
+            r#"ALTER TYPE "glyph_kind" ADD VALUE 'COLOR_BITMAP' AFTER 'OUTLINE'"#,
+            r#"ALTER TYPE "glyph_kind" ADD VALUE 'SVG' AFTER 'BITMAP'"#,
+        ]
+    );
+    assert!(Kind::alter_type(&Kind::values()).is_empty());
+}
@@ -0,0 +1,73 @@
+use super::*;
+use sea_query::diff::SchemaDiff;
+
+#[test]
+fn diff_1() {
+    let actual = Table::create()
+        .table(Glyph::Table)
+        .col(
+            ColumnDef::new(Glyph::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(Glyph::Image).string_len(255))
+        .col(ColumnDef::new(Glyph::Aspect).float())
+        .index(Index::create().name("idx-glyph-aspect").col(Glyph::Aspect))
+        .to_owned();
+
+    let desired = Table::create()
+        .table(Glyph::Table)
+        .col(
+            ColumnDef::new(Glyph::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(Glyph::Image).text().not_null())
+        .col(ColumnDef::new(Glyph::Aspect).float())
+        .to_owned();
+
+    assert_eq!(
+        SchemaDiff::new()
+            .diff(MysqlQueryBuilder, &[desired], &[actual])
+            .iter()
+            .map(|stmt| stmt.to_string(MysqlQueryBuilder))
+            .collect::<Vec<_>>(),
+        [
+            "DROP INDEX `idx-glyph-aspect` ON `glyph`",
+            "ALTER TABLE `glyph` MODIFY COLUMN `image` text NOT NULL",
+        ]
+    );
+}
+
+#[test]
+fn diff_2() {
+    let actual = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::FontId).integer_len(11).not_null())
+        .col(ColumnDef::new(Char::Character).string_len(255).not_null())
+        .primary_key(Index::create().col(Char::FontId).col(Char::Character))
+        .to_owned();
+
+    let desired = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::FontId).integer().not_null())
+        .col(ColumnDef::new(Char::Character).string().not_null())
+        .primary_key(Index::create().col(Char::FontId))
+        .to_owned();
+
+    assert_eq!(
+        SchemaDiff::new()
+            .diff(MysqlQueryBuilder, &[desired], &[actual])
+            .iter()
+            .map(|stmt| stmt.to_string(MysqlQueryBuilder))
+            .collect::<Vec<_>>(),
+        [
+            "ALTER TABLE `character` DROP PRIMARY KEY",
+            "ALTER TABLE `character` ADD PRIMARY KEY (`font_id`)",
+        ]
+    );
+}
@@ -0,0 +1,142 @@
+use super::*;
+use sea_query::schema::discovery::mysql;
+
+#[test]
+fn discover_1() {
+    let column =
+        |name: &str, column_type: &str, nullable: bool, key: &str, extra: &str| mysql::ColumnRow {
+            column_name: name.to_owned(),
+            column_type: column_type.to_owned(),
+            is_nullable: if nullable { "YES" } else { "NO" }.to_owned(),
+            column_default: None,
+            column_key: key.to_owned(),
+            extra: extra.to_owned(),
+        };
+    let mut variant = column("variant", "varchar(255)", true, "", "");
+    variant.column_default = Some("normal".to_owned());
+
+    let table = mysql::discover_table(
+        "font",
+        vec![
+            column("id", "int(11)", false, "PRI", "auto_increment"),
+            column("name", "varchar(255)", false, "MUL", ""),
+            variant,
+        ],
+        vec![
+            mysql::IndexRow {
+                index_name: "PRIMARY".to_owned(),
+                column_name: "id".to_owned(),
+                non_unique: false,
+            },
+            mysql::IndexRow {
+                index_name: "idx-font-name".to_owned(),
+                column_name: "name".to_owned(),
+                non_unique: true,
+            },
+        ],
+        vec![],
+    );
+
+    assert_eq!(
+        table
+            .to_table_create_statement()
+            .to_string(MysqlQueryBuilder),
+        [
+            "CREATE TABLE `font` (",
+            "`id` int(11) NOT NULL AUTO_INCREMENT PRIMARY KEY,",
+            "`name` varchar(255) NOT NULL,",
+            "`variant` varchar(255) DEFAULT 'normal'",
+            ")",
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        table
+            .to_index_create_statements()
+            .iter()
+            .map(|index| index.to_string(MysqlQueryBuilder))
+            .collect::<Vec<_>>(),
+        vec!["CREATE INDEX `idx-font-name` ON `font` (`name`)"]
+    );
+}
+
+#[test]
+fn discover_2() {
+    let column = |name: &str, column_type: &str| mysql::ColumnRow {
+        column_name: name.to_owned(),
+        column_type: column_type.to_owned(),
+        is_nullable: "NO".to_owned(),
+        column_default: None,
+        column_key: "PRI".to_owned(),
+        extra: "".to_owned(),
+    };
+    let index = |column_name: &str| mysql::IndexRow {
+        index_name: "PRIMARY".to_owned(),
+        column_name: column_name.to_owned(),
+        non_unique: false,
+    };
+
+    let table = mysql::discover_table(
+        "character",
+        vec![
+            column("font_id", "int"),
+            column("character", "varchar(255)"),
+        ],
+        vec![index("character"), index("font_id")],
+        vec![],
+    );
+
+    assert_eq!(
+        table
+            .to_table_create_statement()
+            .to_string(MysqlQueryBuilder),
+        [
+            "CREATE TABLE `character` (",
+            "`font_id` int NOT NULL,",
+            "`character` varchar(255) NOT NULL,",
+            "PRIMARY KEY (`character`, `font_id`)",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn discover_3() {
+    let column = |name: &str, column_type: &str, default: &str, extra: &str| mysql::ColumnRow {
+        column_name: name.to_owned(),
+        column_type: column_type.to_owned(),
+        is_nullable: "YES".to_owned(),
+        column_default: Some(default.to_owned()),
+        column_key: "".to_owned(),
+        extra: extra.to_owned(),
+    };
+
+    let table = mysql::discover_table(
+        "glyph",
+        vec![
+            column("created_at", "datetime", "2020-01-01 00:00:00", ""),
+            column(
+                "updated_at",
+                "timestamp",
+                "CURRENT_TIMESTAMP",
+                "DEFAULT_GENERATED",
+            ),
+        ],
+        vec![],
+        vec![],
+    );
+
+    assert_eq!(
+        table
+            .to_table_create_statement()
+            .to_string(MysqlQueryBuilder),
+        [
+            "CREATE TABLE `glyph` (",
+            "`created_at` datetime DEFAULT '2020-01-01 00:00:00',",
+            "`updated_at` timestamp DEFAULT CURRENT_TIMESTAMP",
+            ")",
+        ]
+        .join(" ")
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use sea_query::error::Error;
 
 #[test]
 fn create_1() {
@@ -52,6 +53,101 @@ fn create_4() {
     );
 }
 
+#[test]
+fn create_5() {
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col(
+                IndexColumn::expr(Func::cust(Alias::new("lower")).arg(Expr::col(Glyph::Image)))
+                    .order(IndexOrder::Asc)
+            )
+            .to_string(MysqlQueryBuilder),
+        "CREATE INDEX `idx-glyph-image` ON `glyph` ((lower(`image`)) ASC)"
+    );
+}
+
+#[test]
+fn create_6() {
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .and_where(Expr::col(Glyph::Aspect).gt(0))
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Partial index is not available in MySQL.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .include(Glyph::Image)
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Covering index is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Partial index is not available in MySQL.")]
+fn create_7() {
+    Index::create()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .and_where(Expr::col(Glyph::Aspect).gt(0))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn create_8() {
+    assert_eq!(
+        Index::create()
+            .name("idx-font-name")
+            .table(Font::Table)
+            .col(IndexColumn::new(Font::Name).collate("utf8mb4_bin"))
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Index column collation is not available in MySQL.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Name).string())
+            .index(
+                Index::create()
+                    .name("idx-font-name")
+                    .col(IndexColumn::new(Font::Name).nulls(NullOrdering::First))
+            )
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Index column nulls ordering is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_9() {
+    assert_eq!(
+        Index::create()
+            .if_not_exists()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Create index if not exists is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -62,3 +158,73 @@ fn drop_1() {
         "DROP INDEX `idx-glyph-aspect` ON `glyph`"
     );
 }
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        Index::drop()
+            .name("idx-glyph-aspect")
+            .schema(Alias::new("sea"))
+            .table(Glyph::Table)
+            .to_string(MysqlQueryBuilder),
+        "DROP INDEX `idx-glyph-aspect` ON `sea`.`glyph`"
+    );
+}
+
+#[test]
+fn rename_1() {
+    assert_eq!(
+        Index::rename()
+            .table(Glyph::Table)
+            .index("idx-glyph-aspect", "idx-glyph-aspect-new")
+            .to_string(MysqlQueryBuilder),
+        "ALTER TABLE `glyph` RENAME INDEX `idx-glyph-aspect` TO `idx-glyph-aspect-new`"
+    );
+}
+
+#[test]
+fn drop_3() {
+    assert_eq!(
+        Index::drop()
+            .if_exists()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Drop index if exists is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn rename_2() {
+    assert_eq!(
+        Index::rename()
+            .if_exists()
+            .table(Glyph::Table)
+            .index("idx-glyph-aspect", "idx-glyph-aspect-new")
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Rename index if exists is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Reindex is not available in MySQL.")]
+fn reindex_1() {
+    Index::reindex()
+        .name("idx-glyph-aspect")
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn drop_4() {
+    assert_eq!(
+        Index::drop()
+            .name("idx-glyph-`aspect`")
+            .table(Glyph::Table)
+            .to_string(MysqlQueryBuilder),
+        "DROP INDEX `idx-glyph-``aspect``` ON `glyph`"
+    );
+}
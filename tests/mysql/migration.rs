@@ -0,0 +1,53 @@
+use super::*;
+use sea_query::migration::*;
+
+#[derive(Default)]
+struct Recorder(Vec<String>);
+
+impl MigrationExecutor for Recorder {
+    type Error = ();
+
+    fn execute(&mut self, sql: &str, _: Values) -> Result<(), Self::Error> {
+        self.0.push(sql.to_owned());
+        Ok(())
+    }
+
+    fn query_history(&mut self, sql: &str, _: Values) -> Result<Vec<AppliedMigration>, ()> {
+        self.0.push(sql.to_owned());
+        Ok(Vec::new())
+    }
+}
+
+#[test]
+fn migrate_1() {
+    let migrator = Migrator::new()
+        .table(Alias::new("history"))
+        .migration(
+            Migration::new(1, "create_glyph").up(Table::create()
+                .table(Glyph::Table)
+                .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+                .to_owned()),
+        )
+        .to_owned();
+
+    let mut recorder = Recorder::default();
+    assert_eq!(migrator.up(MysqlQueryBuilder, &mut recorder), Ok(vec![1]));
+    assert_eq!(
+        recorder.0,
+        [
+            [
+                "CREATE TABLE IF NOT EXISTS `history` (",
+                "`version` bigint NOT NULL PRIMARY KEY,",
+                "`name` text NOT NULL,",
+                "`checksum` text NOT NULL,",
+                "`applied_at` timestamp DEFAULT CURRENT_TIMESTAMP",
+                ")",
+            ]
+            .join(" ")
+            .as_str(),
+            "SELECT `version`, `name`, `checksum` FROM `history` ORDER BY `version` ASC",
+            "CREATE TABLE `glyph` ( `id` int NOT NULL PRIMARY KEY )",
+            "INSERT INTO `history` (`version`, `name`, `checksum`) VALUES (?, ?, ?)",
+        ]
+    );
+}
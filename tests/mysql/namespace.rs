@@ -0,0 +1,72 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn create_schema_1() {
+    assert_eq!(
+        Schema::create()
+            .name(Alias::new("tenant_1"))
+            .to_string(MysqlQueryBuilder),
+        "CREATE SCHEMA `tenant_1`"
+    );
+}
+
+#[test]
+fn create_database_1() {
+    assert_eq!(
+        Database::create()
+            .name(Alias::new("tenant_1"))
+            .charset("utf8mb4")
+            .to_string(MysqlQueryBuilder),
+        "CREATE DATABASE `tenant_1` CHARACTER SET utf8mb4"
+    );
+}
+
+#[test]
+fn drop_database_1() {
+    assert_eq!(
+        Database::drop()
+            .name(Alias::new("tenant_1"))
+            .to_string(MysqlQueryBuilder),
+        "DROP DATABASE `tenant_1`"
+    );
+}
+
+#[test]
+fn create_schema_2() {
+    assert_eq!(
+        Schema::create()
+            .name(Alias::new("tenant_1"))
+            .authorization(Alias::new("reporting"))
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Schema authorization is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn drop_schema_1() {
+    assert_eq!(
+        Schema::drop()
+            .name(Alias::new("tenant_1"))
+            .name(Alias::new("tenant_2"))
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Dropping multiple schemas is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn drop_schema_2() {
+    assert_eq!(
+        Schema::drop()
+            .name(Alias::new("tenant_1"))
+            .restrict()
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Drop schema option is not available in MySQL.".to_owned()
+        ))
+    );
+}
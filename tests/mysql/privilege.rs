@@ -0,0 +1,98 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn grant_1() {
+    assert_eq!(
+        GrantStatement::new()
+            .privilege(Privilege::All)
+            .on_schema(Alias::new("sales"))
+            .to(Alias::new("reporting"))
+            .to_string(MysqlQueryBuilder),
+        "GRANT ALL PRIVILEGES ON `sales`.* TO `reporting`"
+    );
+}
+
+#[test]
+fn grant_2() {
+    assert_eq!(
+        GrantStatement::new()
+            .privileges([Privilege::Select, Privilege::Insert])
+            .on_table((Alias::new("sales"), Glyph::Table))
+            .to(Alias::new("reporting"))
+            .to(Alias::new("auditing"))
+            .to_string(MysqlQueryBuilder),
+        "GRANT SELECT, INSERT ON `sales`.`glyph` TO `reporting`, `auditing`"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Sequence is not available in MySQL.")]
+fn grant_3() {
+    GrantStatement::new()
+        .privilege(Privilege::Usage)
+        .on_sequence(Alias::new("glyph_id_seq"))
+        .to(Alias::new("reporting"))
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn revoke_1() {
+    assert_eq!(
+        RevokeStatement::new()
+            .column_privilege(Privilege::Update, [Glyph::Image])
+            .on_table(Glyph::Table)
+            .from(Alias::new("reporting"))
+            .to_string(MysqlQueryBuilder),
+        "REVOKE UPDATE (`image`) ON `glyph` FROM `reporting`"
+    );
+}
+
+#[test]
+fn revoke_2() {
+    assert_eq!(
+        RevokeStatement::new()
+            .privileges([Privilege::Select])
+            .on_table(Glyph::Table)
+            .from(Alias::new("reporting"))
+            .cascade()
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Revoke option is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_role_1() {
+    assert_eq!(
+        Role::create()
+            .name(Alias::new("reporting"))
+            .if_not_exists()
+            .to_string(MysqlQueryBuilder),
+        "CREATE ROLE IF NOT EXISTS `reporting`"
+    );
+}
+
+#[test]
+fn create_role_2() {
+    assert_eq!(
+        Role::create()
+            .name(Alias::new("reporting"))
+            .login(false)
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Role options are not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn drop_role_1() {
+    assert_eq!(
+        Role::drop()
+            .name(Alias::new("reporting"))
+            .to_string(MysqlQueryBuilder),
+        "DROP ROLE `reporting`"
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use sea_query::error::Error;
 
 #[test]
 fn select_1() {
@@ -1013,3 +1014,42 @@ fn delete_1() {
         "DELETE FROM `glyph` WHERE `id` = 1 ORDER BY `id` ASC LIMIT 1"
     );
 }
+
+#[test]
+fn explain_1() {
+    assert_eq!(
+        Query::explain()
+            .statement(
+                Query::select()
+                    .column(Char::Character)
+                    .from(Char::Table)
+                    .and_where(Expr::col(Char::SizeW).gt(3))
+                    .to_owned()
+            )
+            .format(ExplainFormat::Json)
+            .build(MysqlQueryBuilder),
+        (
+            "EXPLAIN FORMAT=JSON SELECT `character` FROM `character` WHERE `size_w` > ?".to_owned(),
+            Values(vec![Value::Int(Some(3))])
+        )
+    );
+}
+
+#[test]
+fn explain_2() {
+    assert_eq!(
+        Query::explain()
+            .statement(
+                Query::select()
+                    .column(Char::Character)
+                    .from(Char::Table)
+                    .to_owned()
+            )
+            .analyze()
+            .format(ExplainFormat::Json)
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Explain analyze with a format is not available in MySQL.".to_owned()
+        ))
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use sea_query::error::Error;
 
 #[test]
 fn create_1() {
@@ -150,6 +151,300 @@ fn create_5() {
     );
 }
 
+#[test]
+fn create_6() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .big_integer()
+                    .not_null()
+                    .identity(ColumnIdentity::by_default().start_with(1000))
+                    .primary_key()
+            )
+            .col(ColumnDef::new(Glyph::Aspect).double().not_null())
+            .engine("InnoDB")
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "CREATE TABLE `glyph` (",
+            "`id` bigint NOT NULL AUTO_INCREMENT PRIMARY KEY,",
+            "`aspect` double NOT NULL",
+            ") ENGINE=InnoDB AUTO_INCREMENT=1000",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_7() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Alias::new("uuid"))
+                    .binary_len(16)
+                    .not_null()
+                    .default(Func::cust(Alias::new("UUID_TO_BIN")).arg(Func::cust(Alias::new("UUID"))))
+            )
+            .col(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp_len(6)
+                    .not_null()
+                    .default(Expr::current_timestamp())
+                    .on_update(Expr::current_timestamp())
+            )
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "CREATE TABLE `glyph` (",
+            "`uuid` binary(16) NOT NULL DEFAULT (UUID_TO_BIN(UUID())),",
+            "`updated_at` timestamp(6) NOT NULL DEFAULT CURRENT_TIMESTAMP ON UPDATE CURRENT_TIMESTAMP",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_8() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(
+                ColumnDef::new(Font::Id)
+                    .integer()
+                    .not_null()
+                    .auto_increment()
+                    .primary_key()
+                    .comment("Primary key")
+            )
+            .col(
+                ColumnDef::new(Font::Name)
+                    .string()
+                    .not_null()
+                    .comment("Display name")
+            )
+            .engine("InnoDB")
+            .comment("Font families")
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "CREATE TABLE `font` (",
+            "`id` int NOT NULL AUTO_INCREMENT PRIMARY KEY COMMENT 'Primary key',",
+            "`name` varchar(255) NOT NULL COMMENT 'Display name'",
+            ") ENGINE=InnoDB COMMENT='Font families'",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_9() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null())
+            .col(ColumnDef::new(Font::Language).string_len(2).not_null())
+            .engine("InnoDB")
+            .partition_by(PartitionMethod::List, vec![Expr::col(Font::Language)])
+            .partition(Alias::new("p_en"), PartitionBound::values_in(vec!["en"]))
+            .partition(
+                Alias::new("p_cjk"),
+                PartitionBound::values_in(vec!["zh", "ja", "ko"])
+            )
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "CREATE TABLE `font` (",
+            "`id` int NOT NULL,",
+            "`language` varchar(2) NOT NULL",
+            ") ENGINE=InnoDB",
+            "PARTITION BY LIST COLUMNS (`language`) (",
+            "PARTITION `p_en` VALUES IN ('en'),",
+            "PARTITION `p_cjk` VALUES IN ('zh', 'ja', 'ko')",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_10() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Alias::new("created")).date().not_null())
+            .partition_by(
+                PartitionMethod::Range,
+                vec![Func::cust(Alias::new("YEAR")).arg(Expr::col(Alias::new("created")))]
+            )
+            .partition(Alias::new("p2020"), PartitionBound::less_than(vec![2021]))
+            .partition(Alias::new("p_max"), PartitionBound::LessThanMaxValue)
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "CREATE TABLE `glyph` (",
+            "`id` int NOT NULL,",
+            "`created` date NOT NULL",
+            ")",
+            "PARTITION BY RANGE (YEAR(`created`)) (",
+            "PARTITION `p2020` VALUES LESS THAN (2021),",
+            "PARTITION `p_max` VALUES LESS THAN MAXVALUE",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_11() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("glyph_small"))
+            .partition_of(Glyph::Table, PartitionBound::values_in(vec![1]))
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Partition of is not available in MySQL.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .partition(Alias::new("p0"), PartitionBound::less_than(vec![10]))
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Partitions without partition by are not available in MySQL.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .partition_by(PartitionMethod::Range, vec![Expr::col(Glyph::Id)])
+            .partition(Alias::new("p0"), PartitionBound::Default)
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Partition bound is not available in MySQL.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Glyph::Aspect).integer().not_null())
+            .partition_by(
+                PartitionMethod::Hash,
+                vec![Expr::col(Glyph::Id), Expr::col(Glyph::Aspect)]
+            )
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Partitioning by several keys other than columns of RANGE or LIST is not available in MySQL."
+                .to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_12() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Glyph::Image).string().not_null())
+            .index(
+                Index::create()
+                    .unique()
+                    .name("idx-glyph-image")
+                    .col(Glyph::Image)
+                    .and_where(Expr::col(Glyph::Id).gt(0))
+            )
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Partial index is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_13() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .integer()
+                    .identity(ColumnIdentity::by_default().increment_by(1))
+            )
+            .to_string(MysqlQueryBuilder),
+        "CREATE TABLE `glyph` ( `id` int AUTO_INCREMENT )"
+    );
+}
+
+#[test]
+fn create_14() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Glyph::Image).string().not_null())
+            .partition_by(
+                PartitionMethod::Range,
+                vec![Expr::col(Glyph::Id), Expr::col(Glyph::Image)]
+            )
+            .partition(
+                Alias::new("p0"),
+                PartitionBound::less_than(vec![Expr::val(10), Expr::val("m")])
+            )
+            .partition(Alias::new("p_max"), PartitionBound::LessThanMaxValue)
+            .to_string(MysqlQueryBuilder),
+        vec![
+            "CREATE TABLE `glyph` (",
+            "`id` int NOT NULL,",
+            "`image` varchar(255) NOT NULL",
+            ")",
+            "PARTITION BY RANGE COLUMNS (`id`, `image`) (",
+            "PARTITION `p0` VALUES LESS THAN (10, 'm'),",
+            "PARTITION `p_max` VALUES LESS THAN (MAXVALUE, MAXVALUE)",
+            ")",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_15() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp()
+                    .default(Func::cust(Alias::new("NOW")))
+                    .on_update(Func::cust(Alias::new("NOW")))
+            )
+            .to_string(MysqlQueryBuilder),
+        "CREATE TABLE `glyph` ( `updated_at` timestamp DEFAULT (NOW()) ON UPDATE NOW() )"
+    );
+}
+
+#[test]
+fn create_16() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .integer()
+                    .not_null()
+                    .identity(ColumnIdentity::always())
+                    .primary_key()
+            )
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Identity generated always is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -240,3 +535,20 @@ fn alter_5() {
 fn alter_6() {
     Table::alter().to_string(MysqlQueryBuilder);
 }
+
+#[test]
+fn alter_7() {
+    assert_eq!(
+        Table::alter()
+            .table(Glyph::Table)
+            .add_column(
+                ColumnDef::new(Glyph::Id)
+                    .integer()
+                    .identity(ColumnIdentity::by_default().increment_by(5))
+            )
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Identity increment is not available in MySQL.".to_owned()
+        ))
+    );
+}
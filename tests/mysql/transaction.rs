@@ -0,0 +1,69 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn begin_1() {
+    assert_eq!(
+        Transaction::begin().to_string(MysqlQueryBuilder),
+        "START TRANSACTION"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Isolation level of begin is not available in MySQL")]
+fn begin_2() {
+    Transaction::begin()
+        .isolation_level(IsolationLevel::Serializable)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn set_1() {
+    assert_eq!(
+        Transaction::set()
+            .isolation_level(IsolationLevel::ReadCommitted)
+            .to_string(MysqlQueryBuilder),
+        "SET TRANSACTION ISOLATION LEVEL READ COMMITTED"
+    );
+}
+
+#[test]
+fn rollback_1() {
+    assert_eq!(
+        Transaction::rollback().to_string(MysqlQueryBuilder),
+        "ROLLBACK"
+    );
+}
+
+#[test]
+fn lock_1() {
+    assert_eq!(
+        Transaction::lock()
+            .table(Glyph::Table)
+            .table(Font::Table)
+            .mode(LockMode::ReadLocal)
+            .to_string(MysqlQueryBuilder),
+        "LOCK TABLES `glyph` READ LOCAL, `font` READ LOCAL"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Lock mode is required in MySQL.")]
+fn lock_2() {
+    Transaction::lock()
+        .table(Glyph::Table)
+        .to_string(MysqlQueryBuilder);
+}
+
+#[test]
+fn lock_3() {
+    assert_eq!(
+        Transaction::lock()
+            .table(Glyph::Table)
+            .mode(LockMode::Exclusive)
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Lock mode Exclusive is not available in MySQL.".to_owned()
+        ))
+    );
+}
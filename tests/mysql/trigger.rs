@@ -0,0 +1,83 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Trigger::create()
+            .name(Alias::new("glyph_cleanup"))
+            .before()
+            .delete()
+            .table(Glyph::Table)
+            .for_each_row()
+            .statement(
+                Query::delete()
+                    .from_table(Char::Table)
+                    .and_where(Expr::col(Char::FontId).equals(Alias::new("OLD"), Font::Id))
+                    .to_owned()
+            )
+            .statement(
+                Query::update()
+                    .table(Font::Table)
+                    .value(Font::Name, "unused".into())
+                    .and_where(Expr::col(Font::Id).eq(1))
+                    .to_owned()
+            )
+            .to_string(MysqlQueryBuilder),
+        [
+            "CREATE TRIGGER `glyph_cleanup` BEFORE DELETE ON `glyph` FOR EACH ROW BEGIN",
+            "DELETE FROM `character` WHERE `font_id` = `OLD`.`id`;",
+            "UPDATE `font` SET `name` = 'unused' WHERE `id` = 1;",
+            "END",
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        Trigger::create()
+            .name(Alias::new("glyph_cleanup"))
+            .before()
+            .delete()
+            .table(Glyph::Table)
+            .statement(Query::delete().from_table(Char::Table).to_owned())
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Trigger without FOR EACH ROW is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Trigger::drop()
+            .name(Alias::new("glyph_cleanup"))
+            .to_string(MysqlQueryBuilder),
+        "DROP TRIGGER `glyph_cleanup`"
+    );
+}
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        Trigger::drop()
+            .name(Alias::new("glyph_cleanup"))
+            .table(Glyph::Table)
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Drop trigger ON table is not available in MySQL.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Trigger::drop()
+            .name(Alias::new("glyph_cleanup"))
+            .cascade()
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Drop trigger options are not available in MySQL.".to_owned()
+        ))
+    );
+}
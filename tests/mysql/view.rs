@@ -0,0 +1,57 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        View::create()
+            .or_replace()
+            .view(Alias::new("glyph_image"))
+            .columns(vec![Glyph::Id, Glyph::Image])
+            .query(
+                Query::select()
+                    .columns(vec![Glyph::Id, Glyph::Image])
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Aspect).gt(2))
+                    .to_owned()
+            )
+            .with_check_option()
+            .to_string(MysqlQueryBuilder),
+        "CREATE OR REPLACE VIEW `glyph_image` (`id`, `image`) AS SELECT `id`, `image` FROM `glyph` WHERE `aspect` > 2 WITH CHECK OPTION"
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        View::drop()
+            .view(Alias::new("glyph_image"))
+            .if_exists()
+            .restrict()
+            .to_string(MysqlQueryBuilder),
+        "DROP VIEW IF EXISTS `glyph_image` RESTRICT"
+    );
+}
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        View::drop()
+            .materialized()
+            .view(Alias::new("glyph_image"))
+            .try_to_string(MysqlQueryBuilder),
+        Err(Error::Unsupported(
+            "Materialized view is not available in MySQL.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn rename_1() {
+    assert_eq!(
+        View::rename()
+            .view(Alias::new("glyph_image"), Alias::new("glyph_image_new"))
+            .to_string(MysqlQueryBuilder),
+        "RENAME TABLE `glyph_image` TO `glyph_image_new`"
+    );
+}
@@ -0,0 +1,224 @@
+use super::*;
+use sea_query::diff::SchemaDiff;
+
+fn to_strings(stmts: Vec<SchemaStatement>) -> Vec<String> {
+    stmts
+        .iter()
+        .map(|stmt| stmt.to_string(PostgresQueryBuilder))
+        .collect()
+}
+
+#[test]
+fn diff_1() {
+    let font = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string().not_null())
+        .to_owned();
+
+    let actual = [
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Char::Character).string().not_null())
+            .col(ColumnDef::new(Char::SizeH).integer())
+            .index(
+                Index::create()
+                    .name("idx-character-size_h")
+                    .col(Char::SizeH),
+            )
+            .to_owned(),
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+            .to_owned(),
+    ];
+
+    let desired = [
+        font,
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Char::Character).string().not_null())
+            .col(ColumnDef::new(Char::FontId).integer())
+            .index(
+                Index::create()
+                    .name("idx-character-font_id")
+                    .col(Char::FontId),
+            )
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-character-font")
+                    .from(Char::Table, Char::FontId)
+                    .to(Font::Table, Font::Id)
+                    .on_delete(ForeignKeyAction::Cascade),
+            )
+            .to_owned(),
+    ];
+
+    assert_eq!(
+        to_strings(SchemaDiff::new().diff(PostgresQueryBuilder, &desired, &actual)),
+        [
+            r#"DROP INDEX "idx-character-size_h""#,
+            [
+                r#"CREATE TABLE "font" ("#,
+                r#""id" integer NOT NULL PRIMARY KEY,"#,
+                r#""name" varchar NOT NULL"#,
+                r#")"#,
+            ]
+            .join(" ")
+            .as_str(),
+            r#"ALTER TABLE "character" ADD COLUMN "font_id" integer"#,
+            r#"ALTER TABLE "character" DROP COLUMN "size_h""#,
+            r#"CREATE INDEX "idx-character-font_id" ON "character" ("font_id")"#,
+            [
+                r#"ALTER TABLE "character" ADD CONSTRAINT "fk-character-font""#,
+                r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+                r#"ON DELETE CASCADE"#,
+            ]
+            .join(" ")
+            .as_str(),
+            r#"DROP TABLE "glyph""#,
+        ]
+    );
+}
+
+#[test]
+fn diff_2() {
+    let character = |on_delete: ForeignKeyAction, unique: bool| {
+        let mut index = Index::create();
+        index.name("idx-character-font_id").col(Char::FontId);
+        if unique {
+            index.unique();
+        }
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+            .col(ColumnDef::new(Char::FontId).integer())
+            .index(&mut index)
+            .foreign_key(
+                ForeignKey::create()
+                    .name("fk-character-font")
+                    .from(Char::Table, Char::FontId)
+                    .to(Font::Table, Font::Id)
+                    .on_delete(on_delete),
+            )
+            .to_owned()
+    };
+
+    assert!(SchemaDiff::new()
+        .diff(
+            PostgresQueryBuilder,
+            &[character(ForeignKeyAction::Cascade, false)],
+            &[character(ForeignKeyAction::Cascade, false)],
+        )
+        .is_empty());
+
+    assert_eq!(
+        to_strings(SchemaDiff::new().diff(
+            PostgresQueryBuilder,
+            &[character(ForeignKeyAction::SetNull, true)],
+            &[character(ForeignKeyAction::Cascade, false)],
+        )),
+        [
+            r#"ALTER TABLE "character" DROP CONSTRAINT "fk-character-font""#,
+            r#"DROP INDEX "idx-character-font_id""#,
+            r#"CREATE UNIQUE INDEX "idx-character-font_id" ON "character" ("font_id")"#,
+            [
+                r#"ALTER TABLE "character" ADD CONSTRAINT "fk-character-font""#,
+                r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+                r#"ON DELETE SET NULL"#,
+            ]
+            .join(" ")
+            .as_str(),
+        ]
+    );
+}
+
+#[test]
+fn diff_3() {
+    let actual = Table::create()
+        .table(Alias::new("chars"))
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Alias::new("size")).integer())
+        .to_owned();
+
+    let desired = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Char::FontSize).integer().not_null())
+        .to_owned();
+
+    assert_eq!(
+        to_strings(
+            SchemaDiff::new()
+                .rename_table(Alias::new("chars"), Char::Table)
+                .rename_column(Char::Table, Alias::new("size"), Char::FontSize)
+                .diff(PostgresQueryBuilder, &[desired], &[actual])
+        ),
+        [
+            r#"ALTER TABLE "chars" RENAME TO "character""#,
+            r#"ALTER TABLE "character" RENAME COLUMN "size" TO "font_size""#,
+            r#"ALTER TABLE "character" ALTER COLUMN "font_size" TYPE integer, ALTER COLUMN "font_size" SET NOT NULL"#,
+        ]
+    );
+}
+
+#[test]
+fn diff_4() {
+    let actual = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string().not_null())
+        .col(
+            ColumnDef::new(Font::Variant)
+                .string()
+                .not_null()
+                .default("normal"),
+        )
+        .to_owned();
+
+    let desired = Table::create()
+        .table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Name).string().not_null())
+        .col(ColumnDef::new(Font::Variant).string())
+        .to_owned();
+
+    assert_eq!(
+        to_strings(SchemaDiff::new().diff(PostgresQueryBuilder, &[desired], &[actual])),
+        [
+            r#"ALTER TABLE "font" ALTER COLUMN "variant" TYPE varchar, ALTER COLUMN "variant" DROP NOT NULL"#,
+            r#"ALTER TABLE "font" ALTER COLUMN "variant" DROP DEFAULT"#,
+        ]
+    );
+}
+
+#[test]
+fn diff_5() {
+    let actual = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Char::FontId).integer().not_null())
+        .to_owned();
+
+    let desired = || {
+        Table::create()
+            .table(Char::Table)
+            .col(ColumnDef::new(Char::Id).integer().not_null())
+            .col(ColumnDef::new(Char::FontId).integer().not_null())
+            .primary_key(Index::create().col(Char::FontId).col(Char::Id))
+            .to_owned()
+    };
+
+    assert_eq!(
+        to_strings(SchemaDiff::new().diff(PostgresQueryBuilder, &[desired()], &[actual])),
+        [
+            r#"ALTER TABLE "character" DROP CONSTRAINT "character_pkey""#,
+            r#"ALTER TABLE "character" ADD PRIMARY KEY ("font_id", "id")"#,
+        ]
+    );
+    assert!(SchemaDiff::new()
+        .diff(PostgresQueryBuilder, &[desired()], &[desired()])
+        .is_empty());
+}
@@ -0,0 +1,60 @@
+use super::*;
+use sea_query::schema::discovery::postgres;
+
+#[test]
+fn discover_1() {
+    let column = |name: &str, data_type: &str, nullable: bool| postgres::ColumnRow {
+        column_name: name.to_owned(),
+        data_type: data_type.to_owned(),
+        udt_name: String::new(),
+        character_maximum_length: None,
+        numeric_precision: None,
+        numeric_scale: None,
+        is_nullable: if nullable { "YES" } else { "NO" }.to_owned(),
+        column_default: None,
+    };
+    let mut id = column("id", "integer", false);
+    id.column_default = Some("nextval('character_id_seq'::regclass)".to_owned());
+    let mut character = column("character", "character varying", false);
+    character.character_maximum_length = Some(1);
+    let mut size_w = column("size_w", "numeric", true);
+    size_w.numeric_precision = Some(10);
+    size_w.numeric_scale = Some(2);
+
+    let table = postgres::discover_table(
+        "character",
+        vec![id, character, size_w, column("font_id", "integer", true)],
+        vec![postgres::IndexRow {
+            index_name: "character_pkey".to_owned(),
+            column_name: "id".to_owned(),
+            is_unique: true,
+            is_primary: true,
+        }],
+        vec![postgres::ForeignKeyRow {
+            constraint_name: "fk-character-font".to_owned(),
+            column_name: "font_id".to_owned(),
+            referenced_table_name: "font".to_owned(),
+            referenced_column_name: "id".to_owned(),
+            update_rule: "NO ACTION".to_owned(),
+            delete_rule: "SET NULL".to_owned(),
+        }],
+    );
+
+    assert_eq!(
+        table
+            .to_table_create_statement()
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "character" ("#,
+            r#""id" serial NOT NULL PRIMARY KEY,"#,
+            r#""character" varchar(1) NOT NULL,"#,
+            r#""size_w" decimal(10, 2),"#,
+            r#""font_id" integer,"#,
+            r#"CONSTRAINT "fk-character-font""#,
+            r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+            r#"ON DELETE SET NULL ON UPDATE NO ACTION"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
@@ -39,6 +39,52 @@ fn create_3() {
     );
 }
 
+#[test]
+fn create_4() {
+    assert_eq!(
+        Index::create()
+            .concurrently()
+            .if_not_exists()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .include(Glyph::Image)
+            .and_where(Expr::col(Glyph::Aspect).gt(2))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX CONCURRENTLY IF NOT EXISTS "idx-glyph-aspect" ON "glyph" ("aspect") INCLUDE ("image") WHERE "aspect" > 2"#
+    );
+}
+
+#[test]
+fn create_5() {
+    assert_eq!(
+        Index::create()
+            .index_type(IndexType::Gist)
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col(
+                IndexColumn::expr(Func::cust(Alias::new("lower")).arg(Expr::col(Glyph::Image)))
+                    .collate("C")
+                    .order(IndexOrder::Desc)
+                    .nulls(NullOrdering::First)
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX "idx-glyph-image" ON "glyph" USING GIST ((lower("image")) COLLATE "C" DESC NULLS FIRST)"#
+    );
+}
+
+#[test]
+fn create_6() {
+    assert_eq!(
+        Index::create()
+            .name("idx-font-name")
+            .table(Font::Table)
+            .col(IndexColumn::new(Font::Name).collate(r#"en"US"#))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE INDEX "idx-font-name" ON "font" ("name" COLLATE "en""US")"#
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -48,3 +94,49 @@ fn drop_1() {
         r#"DROP INDEX "idx-glyph-aspect""#
     );
 }
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        Index::drop()
+            .concurrently()
+            .if_exists()
+            .schema(Alias::new("public"))
+            .name("idx-glyph-aspect")
+            .restrict()
+            .to_string(PostgresQueryBuilder),
+        r#"DROP INDEX CONCURRENTLY IF EXISTS "public"."idx-glyph-aspect" RESTRICT"#
+    );
+}
+
+#[test]
+fn drop_3() {
+    assert_eq!(
+        Index::drop()
+            .name(r#"idx-glyph-"aspect""#)
+            .to_string(PostgresQueryBuilder),
+        r#"DROP INDEX "idx-glyph-""aspect""""#
+    );
+}
+
+#[test]
+fn rename_1() {
+    assert_eq!(
+        Index::rename()
+            .index("idx-glyph-aspect", "idx-glyph-aspect-new")
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER INDEX "idx-glyph-aspect" RENAME TO "idx-glyph-aspect-new""#
+    );
+}
+
+#[test]
+fn reindex_1() {
+    assert_eq!(
+        Index::reindex()
+            .concurrently()
+            .schema(Alias::new("public"))
+            .table(Glyph::Table)
+            .to_string(PostgresQueryBuilder),
+        r#"REINDEX TABLE CONCURRENTLY "public"."glyph""#
+    );
+}
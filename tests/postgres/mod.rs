@@ -1,10 +1,19 @@
 use sea_query::{tests_cfg::*, *};
 
+mod diff;
+mod discovery;
 mod foreign_key;
 mod index;
 #[cfg(feature = "postgres-interval")]
 mod interval;
+mod namespace;
+mod privilege;
 #[allow(deprecated)]
 mod query;
+mod schema_set;
+mod sequence;
 mod table;
+mod transaction;
+mod trigger;
 mod types;
+mod view;
@@ -0,0 +1,59 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn create_schema_1() {
+    assert_eq!(
+        Schema::create()
+            .if_not_exists()
+            .name(Alias::new("tenant_1"))
+            .authorization(Alias::new("tenant_1_owner"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE SCHEMA IF NOT EXISTS "tenant_1" AUTHORIZATION "tenant_1_owner""#
+    );
+}
+
+#[test]
+fn drop_schema_1() {
+    assert_eq!(
+        Schema::drop()
+            .name(Alias::new("tenant_1"))
+            .name(Alias::new("tenant_2"))
+            .restrict()
+            .to_string(PostgresQueryBuilder),
+        r#"DROP SCHEMA "tenant_1", "tenant_2" RESTRICT"#
+    );
+}
+
+#[test]
+fn search_path_1() {
+    assert_eq!(
+        Schema::search_path()
+            .schema(Alias::new("tenant_1"))
+            .to_string(PostgresQueryBuilder),
+        r#"SET search_path TO "tenant_1""#
+    );
+}
+
+#[test]
+fn create_database_1() {
+    assert_eq!(
+        Database::create()
+            .name(Alias::new("tenant_1"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE DATABASE "tenant_1""#
+    );
+}
+
+#[test]
+fn create_database_2() {
+    assert_eq!(
+        Database::create()
+            .name(Alias::new("tenant_1"))
+            .if_not_exists()
+            .try_to_string(PostgresQueryBuilder),
+        Err(Error::Unsupported(
+            "CREATE DATABASE IF NOT EXISTS is not available in Postgres.".to_owned()
+        ))
+    );
+}
@@ -0,0 +1,89 @@
+use super::*;
+
+#[test]
+fn grant_1() {
+    assert_eq!(
+        GrantStatement::new()
+            .privilege(Privilege::Usage)
+            .on_schema(Alias::new("sales"))
+            .on_schema(Alias::new("finance"))
+            .to(Alias::new("reporting"))
+            .to_string(PostgresQueryBuilder),
+        r#"GRANT USAGE ON SCHEMA "sales", "finance" TO "reporting""#
+    );
+}
+
+#[test]
+fn grant_2() {
+    assert_eq!(
+        GrantStatement::new()
+            .privilege(Privilege::Select)
+            .on_all_tables_in_schema(Alias::new("sales"))
+            .to(Alias::new("reporting"))
+            .to_string(PostgresQueryBuilder),
+        r#"GRANT SELECT ON ALL TABLES IN SCHEMA "sales" TO "reporting""#
+    );
+}
+
+#[test]
+fn grant_3() {
+    assert_eq!(
+        GrantStatement::new()
+            .privileges([Privilege::Usage, Privilege::Select])
+            .on_sequence(Alias::new("glyph_id_seq"))
+            .to(Alias::new("reporting"))
+            .with_grant_option()
+            .to_string(PostgresQueryBuilder),
+        r#"GRANT USAGE, SELECT ON SEQUENCE "glyph_id_seq" TO "reporting" WITH GRANT OPTION"#
+    );
+}
+
+#[test]
+fn grant_4() {
+    assert_eq!(
+        GrantStatement::new()
+            .privilege(Privilege::Select)
+            .on_table(Glyph::Table)
+            .on_table(Font::Table)
+            .to(Alias::new("reporting"))
+            .to_string(PostgresQueryBuilder),
+        r#"GRANT SELECT ON TABLE "glyph", "font" TO "reporting""#
+    );
+}
+
+#[test]
+fn revoke_1() {
+    assert_eq!(
+        RevokeStatement::new()
+            .privilege(Privilege::All)
+            .on_table(Glyph::Table)
+            .from(Alias::new("reporting"))
+            .restrict()
+            .to_string(PostgresQueryBuilder),
+        r#"REVOKE ALL ON TABLE "glyph" FROM "reporting" RESTRICT"#
+    );
+}
+
+#[test]
+fn create_role_1() {
+    assert_eq!(
+        Role::create()
+            .name(Alias::new("reporting"))
+            .login(false)
+            .superuser(false)
+            .create_db(false)
+            .create_role(false)
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE ROLE "reporting" WITH NOLOGIN NOSUPERUSER NOCREATEDB NOCREATEROLE"#
+    );
+}
+
+#[test]
+fn drop_role_1() {
+    assert_eq!(
+        Role::drop()
+            .name(Alias::new("reporting"))
+            .to_string(PostgresQueryBuilder),
+        r#"DROP ROLE "reporting""#
+    );
+}
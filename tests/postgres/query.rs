@@ -980,3 +980,27 @@ fn delete_1() {
         r#"DELETE FROM "glyph" WHERE "id" = 1"#
     );
 }
+
+#[test]
+fn explain_1() {
+    assert_eq!(
+        Query::explain()
+            .statement(
+                Query::update()
+                    .table(Glyph::Table)
+                    .value(Glyph::Aspect, 2.into())
+                    .and_where(Expr::col(Glyph::Id).eq(1))
+                    .to_owned()
+            )
+            .analyze()
+            .verbose()
+            .buffers()
+            .format(ExplainFormat::Text)
+            .build(PostgresQueryBuilder),
+        (
+            r#"EXPLAIN (ANALYZE, VERBOSE, BUFFERS, FORMAT TEXT) UPDATE "glyph" SET "aspect" = $1 WHERE "id" = $2"#
+                .to_owned(),
+            Values(vec![Value::Int(Some(2)), Value::Int(Some(1))])
+        )
+    );
+}
@@ -0,0 +1,207 @@
+use super::*;
+
+fn table(name: &str, column: &str, references: Option<(&str, &str)>) -> TableCreateStatement {
+    let mut stmt = Table::create();
+    stmt.table(Alias::new(name))
+        .col(
+            ColumnDef::new(Alias::new("id"))
+                .integer()
+                .not_null()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(Alias::new(column)).integer());
+    if let Some((fk, ref_table)) = references {
+        stmt.foreign_key(
+            ForeignKey::create()
+                .name(fk)
+                .from(Alias::new(name), Alias::new(column))
+                .to(Alias::new(ref_table), Alias::new("id")),
+        );
+    }
+    stmt
+}
+
+#[test]
+fn schema_set_1() {
+    let schema = SchemaSet::new()
+        .table(&mut table(
+            "character",
+            "font_id",
+            Some(("fk-character-font", "font")),
+        ))
+        .table(&mut table("font", "variant", None))
+        .table(&mut table(
+            "glyph",
+            "aspect",
+            Some(("fk-glyph-character", "character")),
+        ))
+        .to_owned();
+
+    assert!(schema.cycles().is_empty());
+    assert_eq!(
+        schema.build_create(PostgresQueryBuilder).unwrap(),
+        [
+            r#"CREATE TABLE "font" ( "id" integer NOT NULL PRIMARY KEY, "variant" integer )"#,
+            [
+                r#"CREATE TABLE "character" ( "id" integer NOT NULL PRIMARY KEY, "font_id" integer,"#,
+                r#"CONSTRAINT "fk-character-font" FOREIGN KEY ("font_id") REFERENCES "font" ("id") )"#,
+            ]
+            .join(" ")
+            .as_str(),
+            [
+                r#"CREATE TABLE "glyph" ( "id" integer NOT NULL PRIMARY KEY, "aspect" integer,"#,
+                r#"CONSTRAINT "fk-glyph-character" FOREIGN KEY ("aspect") REFERENCES "character" ("id") )"#,
+            ]
+            .join(" ")
+            .as_str(),
+        ]
+    );
+    assert_eq!(
+        schema.build_drop(PostgresQueryBuilder),
+        [
+            r#"DROP TABLE "glyph""#,
+            r#"DROP TABLE "character""#,
+            r#"DROP TABLE "font""#,
+        ]
+    );
+}
+
+#[test]
+fn schema_set_2() {
+    let schema = SchemaSet::new()
+        .table(&mut table(
+            "character",
+            "font_id",
+            Some(("fk-character-font", "font")),
+        ))
+        .table(&mut table(
+            "font",
+            "variant",
+            Some(("fk-font-character", "character")),
+        ))
+        .table(&mut table(
+            "glyph",
+            "aspect",
+            Some(("fk-glyph-glyph", "glyph")),
+        ))
+        .to_owned();
+
+    assert_eq!(schema.cycles(), [["character", "font"]]);
+    assert_eq!(
+        schema.build_create(PostgresQueryBuilder).unwrap(),
+        [
+            [
+                r#"CREATE TABLE "glyph" ( "id" integer NOT NULL PRIMARY KEY, "aspect" integer,"#,
+                r#"CONSTRAINT "fk-glyph-glyph" FOREIGN KEY ("aspect") REFERENCES "glyph" ("id") )"#,
+            ]
+            .join(" ")
+            .as_str(),
+            r#"CREATE TABLE "character" ( "id" integer NOT NULL PRIMARY KEY, "font_id" integer )"#,
+            [
+                r#"CREATE TABLE "font" ( "id" integer NOT NULL PRIMARY KEY, "variant" integer,"#,
+                r#"CONSTRAINT "fk-font-character" FOREIGN KEY ("variant") REFERENCES "character" ("id") )"#,
+            ]
+            .join(" ")
+            .as_str(),
+            [
+                r#"ALTER TABLE "character" ADD CONSTRAINT "fk-character-font""#,
+                r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+            ]
+            .join(" ")
+            .as_str(),
+        ]
+    );
+    assert_eq!(
+        schema.build_drop(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "character" DROP CONSTRAINT "fk-character-font""#,
+            r#"DROP TABLE "font""#,
+            r#"DROP TABLE "character""#,
+            r#"DROP TABLE "glyph""#,
+        ]
+    );
+}
+
+#[test]
+fn schema_set_3() {
+    let schema = SchemaSet::new()
+        .table(&mut table(
+            "glyph",
+            "aspect",
+            Some(("fk-glyph-character", "character")),
+        ))
+        .table(&mut table(
+            "character",
+            "font_id",
+            Some(("fk-character-font", "font")),
+        ))
+        .table(&mut table(
+            "font",
+            "variant",
+            Some(("fk-font-character", "character")),
+        ))
+        .to_owned();
+
+    assert_eq!(schema.cycles(), [["character", "font"]]);
+    assert_eq!(
+        schema.build_create(PostgresQueryBuilder).unwrap(),
+        [
+            r#"CREATE TABLE "character" ( "id" integer NOT NULL PRIMARY KEY, "font_id" integer )"#,
+            [
+                r#"CREATE TABLE "glyph" ( "id" integer NOT NULL PRIMARY KEY, "aspect" integer,"#,
+                r#"CONSTRAINT "fk-glyph-character" FOREIGN KEY ("aspect") REFERENCES "character" ("id") )"#,
+            ]
+            .join(" ")
+            .as_str(),
+            [
+                r#"CREATE TABLE "font" ( "id" integer NOT NULL PRIMARY KEY, "variant" integer,"#,
+                r#"CONSTRAINT "fk-font-character" FOREIGN KEY ("variant") REFERENCES "character" ("id") )"#,
+            ]
+            .join(" ")
+            .as_str(),
+            [
+                r#"ALTER TABLE "character" ADD CONSTRAINT "fk-character-font""#,
+                r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+            ]
+            .join(" ")
+            .as_str(),
+        ]
+    );
+}
+
+#[test]
+fn schema_set_4() {
+    let schema = SchemaSet::new()
+        .table(
+            table("character", "font_id", None).foreign_key(
+                ForeignKey::create()
+                    .from(Alias::new("character"), Alias::new("font_id"))
+                    .to(Alias::new("font"), Alias::new("id")),
+            ),
+        )
+        .table(&mut table(
+            "font",
+            "variant",
+            Some(("fk-font-character", "character")),
+        ))
+        .to_owned();
+
+    assert_eq!(
+        schema.build_create(PostgresQueryBuilder).unwrap().last(),
+        Some(
+            &[
+                r#"ALTER TABLE "character" ADD CONSTRAINT "character_font_id_fkey""#,
+                r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+            ]
+            .join(" ")
+        )
+    );
+    assert_eq!(
+        schema.build_drop(PostgresQueryBuilder),
+        [
+            r#"ALTER TABLE "character" DROP CONSTRAINT "character_font_id_fkey""#,
+            r#"DROP TABLE "font""#,
+            r#"DROP TABLE "character""#,
+        ]
+    );
+}
@@ -0,0 +1,74 @@
+use sea_query::{extension::postgres::Sequence, Alias, PostgresQueryBuilder};
+
+use super::*;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Sequence::create()
+            .name(Alias::new("glyph_id_seq"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE SEQUENCE "glyph_id_seq""#
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        Sequence::create()
+            .name(Alias::new("glyph_id_seq"))
+            .increment_by(-1)
+            .no_min_value()
+            .max_value(1000)
+            .start_with(1000)
+            .cycle()
+            .owned_by_none()
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE SEQUENCE "glyph_id_seq" INCREMENT BY -1 NO MINVALUE MAXVALUE 1000 START WITH 1000 CYCLE OWNED BY NONE"#
+    );
+}
+
+#[test]
+fn alter_1() {
+    assert_eq!(
+        Sequence::alter()
+            .name(Alias::new("glyph_id_seq"))
+            .restart()
+            .owned_by(Glyph::Table, Glyph::Id)
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER SEQUENCE "glyph_id_seq" RESTART OWNED BY "glyph"."id""#
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Sequence::drop()
+            .names(vec![Alias::new("glyph_id_seq"), Alias::new("font_id_seq")])
+            .restrict()
+            .to_string(PostgresQueryBuilder),
+        r#"DROP SEQUENCE "glyph_id_seq", "font_id_seq" RESTRICT"#
+    );
+}
+
+#[test]
+fn nextval_1() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::nextval(Alias::new("glyph_id_seq")))
+            .expr(Func::setval(Alias::new("font_id_seq"), 1, None))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT NEXTVAL('"glyph_id_seq"'), SETVAL('"font_id_seq"', 1)"#
+    );
+}
+
+#[test]
+fn nextval_2() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::currval(Alias::new(r#"My"Seq"#)))
+            .expr(Func::currval(Alias::new("Font's")))
+            .to_string(PostgresQueryBuilder),
+        r#"SELECT CURRVAL('"My""Seq"'), CURRVAL('"Font''s"')"#
+    );
+}
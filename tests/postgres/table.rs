@@ -1,4 +1,5 @@
 use super::*;
+use sea_query::error::Error;
 
 #[test]
 fn create_1() {
@@ -232,6 +233,307 @@ fn create_10() {
     );
 }
 
+#[test]
+fn create_11() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .big_integer()
+                    .not_null()
+                    .identity(ColumnIdentity::by_default())
+                    .primary_key()
+            )
+            .col(ColumnDef::new(Glyph::Aspect).double().not_null())
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"CREATE TABLE "glyph" ("#,
+            r#""id" bigint NOT NULL GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,"#,
+            r#""aspect" double precision NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_12() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Alias::new("uuid"))
+                    .uuid()
+                    .not_null()
+                    .default(Func::cust(Alias::new("gen_random_uuid")).into_simple_expr())
+            )
+            .col(
+                ColumnDef::new(Alias::new("expires_at"))
+                    .timestamp_with_time_zone()
+                    .default(
+                        Func::cust(Alias::new("now"))
+                            .into_simple_expr()
+                            .add(Expr::cust("interval '1 day'"))
+                    )
+            )
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp_with_time_zone()
+                    .default(Expr::current_timestamp())
+            )
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"CREATE TABLE "glyph" ("#,
+            r#""uuid" uuid NOT NULL DEFAULT gen_random_uuid(),"#,
+            r#""expires_at" timestamp with time zone DEFAULT (now() + interval '1 day'),"#,
+            r#""created_at" timestamp with time zone DEFAULT CURRENT_TIMESTAMP"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_13() {
+    let table = Table::create()
+        .table(Font::Table)
+        .col(
+            ColumnDef::new(Font::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key()
+                .comment("Primary key"),
+        )
+        .col(
+            ColumnDef::new(Font::Name)
+                .string()
+                .not_null()
+                .comment("Display name"),
+        )
+        .comment("Font families")
+        .to_owned();
+
+    assert_eq!(
+        table.to_string(PostgresQueryBuilder),
+        vec![
+            r#"CREATE TABLE "font" ("#,
+            r#""id" serial NOT NULL PRIMARY KEY,"#,
+            r#""name" varchar NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        table.comment_statements(PostgresQueryBuilder),
+        vec![
+            r#"COMMENT ON TABLE "font" IS 'Font families'"#,
+            r#"COMMENT ON COLUMN "font"."id" IS 'Primary key'"#,
+            r#"COMMENT ON COLUMN "font"."name" IS 'Display name'"#,
+        ]
+    );
+    assert!(table.comment_statements(MysqlQueryBuilder).is_empty());
+}
+
+#[test]
+fn create_14() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("measurement"))
+            .col(ColumnDef::new(Alias::new("city_id")).integer().not_null())
+            .col(ColumnDef::new(Alias::new("logdate")).date().not_null())
+            .col(ColumnDef::new(Alias::new("peaktemp")).integer())
+            .partition_by(
+                PartitionMethod::Range,
+                vec![Expr::col(Alias::new("logdate"))]
+            )
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"CREATE TABLE "measurement" ("#,
+            r#""city_id" integer NOT NULL,"#,
+            r#""logdate" date NOT NULL,"#,
+            r#""peaktemp" integer"#,
+            r#") PARTITION BY RANGE ("logdate")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_15() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("measurement_y2006m02"))
+            .partition_of(
+                Alias::new("measurement"),
+                PartitionBound::from_to(vec!["2006-02-01"], vec!["2006-03-01"])
+            )
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"CREATE TABLE "measurement_y2006m02" PARTITION OF "measurement""#,
+            r#"FOR VALUES FROM ('2006-02-01') TO ('2006-03-01')"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_16() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("measurement_y2006"))
+            .if_not_exists()
+            .partition_of(
+                Alias::new("measurement"),
+                PartitionBound::from_to(vec!["2006-01-01"], vec!["2007-01-01"])
+            )
+            .primary_key(Index::create().col(Alias::new("city_id")))
+            .partition_by(
+                PartitionMethod::Hash,
+                vec![Expr::col(Alias::new("city_id"))]
+            )
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"CREATE TABLE IF NOT EXISTS "measurement_y2006" PARTITION OF "measurement""#,
+            r#"( PRIMARY KEY ("city_id") )"#,
+            r#"FOR VALUES FROM ('2006-01-01') TO ('2007-01-01')"#,
+            r#"PARTITION BY HASH ("city_id")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_17() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("measurement_y2006_h0"))
+            .partition_of(
+                Alias::new("measurement_y2006"),
+                PartitionBound::modulus(4, 0)
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TABLE "measurement_y2006_h0" PARTITION OF "measurement_y2006" FOR VALUES WITH (MODULUS 4, REMAINDER 0)"#
+    );
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("measurement_default"))
+            .partition_of(Alias::new("measurement"), PartitionBound::Default)
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TABLE "measurement_default" PARTITION OF "measurement" DEFAULT"#
+    );
+}
+
+#[test]
+fn create_18() {
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("measurement_y2006m03"))
+            .partition_of(
+                Alias::new("measurement"),
+                PartitionBound::from_to(vec!["2006-03-01"], vec!["2006-04-01"])
+            )
+            .col(
+                ColumnDef::new(Alias::new("peaktemp"))
+                    .integer()
+                    .not_null()
+                    .default(0)
+            )
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"CREATE TABLE "measurement_y2006m03" PARTITION OF "measurement""#,
+            r#"( "peaktemp" WITH OPTIONS NOT NULL DEFAULT 0 )"#,
+            r#"FOR VALUES FROM ('2006-03-01') TO ('2006-04-01')"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_19() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .partition_by(PartitionMethod::Hash, vec![Expr::col(Glyph::Id)])
+            .partitions(4)
+            .try_to_string(PostgresQueryBuilder),
+        Err(Error::Unsupported(
+            "Partition definition is not available in Postgres.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("glyph_small"))
+            .partition_of(Glyph::Table, PartitionBound::LessThanMaxValue)
+            .try_to_string(PostgresQueryBuilder),
+        Err(Error::Unsupported(
+            "Partition bound is not available in Postgres.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_20() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Glyph::Image).string().not_null())
+            .index(
+                Index::create()
+                    .unique()
+                    .name("idx-glyph-image")
+                    .col(Glyph::Image)
+                    .include(Glyph::Id)
+            )
+            .to_string(PostgresQueryBuilder),
+        [
+            r#"CREATE TABLE "glyph" ("#,
+            r#""id" integer NOT NULL,"#,
+            r#""image" varchar NOT NULL,"#,
+            r#"CONSTRAINT "idx-glyph-image" UNIQUE ("image") INCLUDE ("id")"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Glyph::Image).string().not_null())
+            .index(
+                Index::create()
+                    .unique()
+                    .name("idx-glyph-image")
+                    .col(Glyph::Image)
+                    .and_where(Expr::col(Glyph::Id).gt(0))
+            )
+            .try_to_string(PostgresQueryBuilder),
+        Err(Error::Unsupported(
+            "Partial index is not available in a table definition.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_21() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp()
+                    .on_update(Expr::current_timestamp())
+            )
+            .try_to_string(PostgresQueryBuilder),
+        Err(Error::Unsupported(
+            "Column ON UPDATE is not available in Postgres.".to_owned()
+        ))
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -327,3 +629,56 @@ fn alter_5() {
 fn alter_6() {
     Table::alter().to_string(PostgresQueryBuilder);
 }
+
+#[test]
+fn alter_7() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .modify_column(
+                ColumnDef::new(Font::Id)
+                    .integer()
+                    .identity(ColumnIdentity::always().start_with(10))
+            )
+            .to_string(PostgresQueryBuilder),
+        vec![
+            r#"ALTER TABLE "font""#,
+            r#"ALTER COLUMN "id" TYPE integer,"#,
+            r#"ALTER COLUMN "id" ADD GENERATED ALWAYS AS IDENTITY (START WITH 10)"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_8() {
+    let alter = Table::alter()
+        .table(Font::Table)
+        .add_column(
+            ColumnDef::new(Alias::new("new_col"))
+                .integer()
+                .not_null()
+                .comment("New column"),
+        )
+        .to_owned();
+
+    assert_eq!(
+        alter.to_string(PostgresQueryBuilder),
+        r#"ALTER TABLE "font" ADD COLUMN "new_col" integer NOT NULL"#
+    );
+    assert_eq!(
+        alter.comment_statements(PostgresQueryBuilder),
+        vec![r#"COMMENT ON COLUMN "font"."new_col" IS 'New column'"#]
+    );
+}
+
+#[test]
+fn alter_9() {
+    assert_eq!(
+        Table::alter()
+            .table(Alias::new(r#"font"s"#))
+            .drop_primary_key()
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER TABLE "font""s" DROP CONSTRAINT "font""s_pkey""#
+    );
+}
@@ -0,0 +1,53 @@
+use super::*;
+
+#[test]
+fn begin_1() {
+    assert_eq!(
+        Transaction::begin().to_string(PostgresQueryBuilder),
+        "BEGIN"
+    );
+}
+
+#[test]
+fn begin_2() {
+    assert_eq!(
+        Transaction::begin()
+            .isolation_level(IsolationLevel::ReadUncommitted)
+            .read_write()
+            .deferrable(false)
+            .to_string(PostgresQueryBuilder),
+        "BEGIN ISOLATION LEVEL READ UNCOMMITTED, READ WRITE, NOT DEFERRABLE"
+    );
+}
+
+#[test]
+fn set_1() {
+    assert_eq!(
+        Transaction::set()
+            .read_only()
+            .deferrable(true)
+            .to_string(PostgresQueryBuilder),
+        "SET TRANSACTION READ ONLY, DEFERRABLE"
+    );
+}
+
+#[test]
+fn lock_1() {
+    assert_eq!(
+        Transaction::lock()
+            .table((Alias::new("sales"), Glyph::Table))
+            .mode(LockMode::AccessExclusive)
+            .to_string(PostgresQueryBuilder),
+        r#"LOCK TABLE "sales"."glyph" IN ACCESS EXCLUSIVE MODE"#
+    );
+}
+
+#[test]
+fn lock_2() {
+    assert_eq!(
+        Transaction::lock()
+            .table(Glyph::Table)
+            .to_string(PostgresQueryBuilder),
+        r#"LOCK TABLE "glyph""#
+    );
+}
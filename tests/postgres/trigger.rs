@@ -0,0 +1,113 @@
+use sea_query::extension::postgres::FunctionCreateStatement;
+
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Trigger::create()
+            .name(Alias::new("glyph_audit"))
+            .after()
+            .insert()
+            .update()
+            .delete()
+            .table(Glyph::Table)
+            .for_each_row()
+            .execute_function(Alias::new("audit_glyph"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TRIGGER "glyph_audit" AFTER INSERT OR UPDATE OR DELETE ON "glyph" FOR EACH ROW EXECUTE FUNCTION "audit_glyph"()"#
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        Trigger::create()
+            .name(Alias::new("glyph_truncate"))
+            .before()
+            .truncate()
+            .table(Glyph::Table)
+            .execute_function(Alias::new("audit_glyph"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TRIGGER "glyph_truncate" BEFORE TRUNCATE ON "glyph" EXECUTE FUNCTION "audit_glyph"()"#
+    );
+}
+
+#[test]
+fn create_3() {
+    assert_eq!(
+        Trigger::create()
+            .if_not_exists()
+            .name(Alias::new("glyph_audit"))
+            .after()
+            .insert()
+            .table(Glyph::Table)
+            .for_each_row()
+            .execute_function(Alias::new("audit_glyph"))
+            .try_to_string(PostgresQueryBuilder),
+        Err(Error::Unsupported(
+            "Create trigger if not exists is not available in Postgres.".to_owned()
+        ))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Create trigger if not exists is not available in Postgres.")]
+fn create_4() {
+    Trigger::create()
+        .if_not_exists()
+        .name(Alias::new("glyph_audit"))
+        .after()
+        .insert()
+        .table(Glyph::Table)
+        .execute_function(Alias::new("audit_glyph"))
+        .to_string(PostgresQueryBuilder);
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Trigger::drop()
+            .name(Alias::new("glyph_audit"))
+            .table(Glyph::Table)
+            .cascade()
+            .to_string(PostgresQueryBuilder),
+        r#"DROP TRIGGER "glyph_audit" ON "glyph" CASCADE"#
+    );
+}
+
+#[test]
+fn create_function_1() {
+    assert_eq!(
+        FunctionCreateStatement::new()
+            .name(Alias::new("audit_glyph"))
+            .returns("trigger")
+            .language("plpgsql")
+            .body("BEGIN RAISE NOTICE '$$'; RETURN NULL; END;")
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE FUNCTION "audit_glyph"() RETURNS trigger LANGUAGE plpgsql AS $body$BEGIN RAISE NOTICE '$$'; RETURN NULL; END;$body$"#
+    );
+}
+
+#[test]
+fn create_function_2() {
+    assert_eq!(
+        FunctionCreateStatement::new()
+            .name(Alias::new("price"))
+            .returns("text")
+            .language("sql")
+            .body("SELECT '$$ $body$ ' || 1 || ' $'")
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE FUNCTION "price"() RETURNS text LANGUAGE sql AS $body1$SELECT '$$ $body$ ' || 1 || ' $'$body1$"#
+    );
+    assert_eq!(
+        FunctionCreateStatement::new()
+            .name(Alias::new("dollar"))
+            .returns("text")
+            .language("sql")
+            .body("SELECT 'USD' -- US$")
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE FUNCTION "dollar"() RETURNS text LANGUAGE sql AS $body$SELECT 'USD' -- US$$body$"#
+    );
+}
@@ -1,4 +1,7 @@
-use sea_query::{extension::postgres::Type, Alias, PostgresQueryBuilder};
+use sea_query::{
+    extension::postgres::{Domain, Extension, Type},
+    Alias, ColumnType, Expr, PostgresQueryBuilder,
+};
 
 use super::*;
 
@@ -13,6 +16,29 @@ fn create_1() {
     );
 }
 
+#[test]
+fn create_2() {
+    assert_eq!(
+        Type::create()
+            .as_composite(Alias::new("address"))
+            .field(Alias::new("street"), ColumnType::Text)
+            .field(Alias::new("zip"), ColumnType::String(Some(10)))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TYPE "address" AS ("street" text, "zip" varchar(10))"#
+    );
+}
+
+#[test]
+fn create_3() {
+    assert_eq!(
+        Type::create()
+            .as_range(Alias::new("float_range"))
+            .subtype(ColumnType::Double(None))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE TYPE "float_range" AS RANGE (SUBTYPE = double precision)"#
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -102,3 +128,69 @@ fn alter_5() {
         r#"ALTER TYPE "font" RENAME VALUE 'variant' TO 'language'"#
     )
 }
+
+#[test]
+fn create_domain_1() {
+    assert_eq!(
+        Domain::create()
+            .name(Alias::new("us_postal_code"))
+            .as_type(ColumnType::Text)
+            .check(Expr::cust("VALUE ~ '^\\d{5}$'"))
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE DOMAIN "us_postal_code" AS text CHECK (VALUE ~ '^\d{5}$')"#
+    );
+}
+
+#[test]
+fn drop_domain_1() {
+    assert_eq!(
+        Domain::drop()
+            .name(Alias::new("us_postal_code"))
+            .name(Alias::new("positive_int"))
+            .restrict()
+            .to_string(PostgresQueryBuilder),
+        r#"DROP DOMAIN "us_postal_code", "positive_int" RESTRICT"#
+    );
+}
+
+#[test]
+fn create_extension_1() {
+    assert_eq!(
+        Extension::create()
+            .name("uuid-ossp")
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE EXTENSION "uuid-ossp""#
+    );
+}
+
+#[test]
+fn create_extension_2() {
+    assert_eq!(
+        Extension::create()
+            .name(r#"my"ext"#)
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE EXTENSION "my""ext""#
+    );
+}
+
+#[test]
+fn drop_extension_1() {
+    assert_eq!(
+        Extension::drop()
+            .name("hstore")
+            .cascade()
+            .to_string(PostgresQueryBuilder),
+        r#"DROP EXTENSION "hstore" CASCADE"#
+    );
+}
+
+#[test]
+fn drop_extension_2() {
+    assert_eq!(
+        Extension::drop()
+            .name(r#"my"ext"#)
+            .name("hstore")
+            .to_string(PostgresQueryBuilder),
+        r#"DROP EXTENSION "my""ext", "hstore""#
+    );
+}
@@ -0,0 +1,101 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        View::create()
+            .view((Alias::new("public"), Alias::new("glyph_image")))
+            .query(
+                Query::select()
+                    .columns(vec![Glyph::Id, Glyph::Image])
+                    .from(Glyph::Table)
+                    .and_where(Expr::col(Glyph::Image).like("A%"))
+                    .to_owned()
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE VIEW "public"."glyph_image" AS SELECT "id", "image" FROM "glyph" WHERE "image" LIKE 'A%'"#
+    );
+}
+
+#[test]
+fn create_2() {
+    assert_eq!(
+        View::create()
+            .materialized()
+            .view(Alias::new("glyph_aspect"))
+            .column(Alias::new("max_aspect"))
+            .query(
+                Query::select()
+                    .expr(Func::max(Expr::col(Glyph::Aspect)))
+                    .from(Glyph::Table)
+                    .to_owned()
+            )
+            .to_string(PostgresQueryBuilder),
+        r#"CREATE MATERIALIZED VIEW "glyph_aspect" ("max_aspect") AS SELECT MAX("aspect") FROM "glyph""#
+    );
+}
+
+#[test]
+fn create_3() {
+    let query = Query::select()
+        .column(Glyph::Aspect)
+        .from(Glyph::Table)
+        .to_owned();
+    assert_eq!(
+        View::create()
+            .materialized()
+            .or_replace()
+            .view(Alias::new("glyph_aspect"))
+            .query(query.clone())
+            .try_to_string(PostgresQueryBuilder),
+        Err(Error::Unsupported(
+            "OR REPLACE of materialized view is not available in Postgres.".to_owned()
+        ))
+    );
+    assert_eq!(
+        View::create()
+            .materialized()
+            .view(Alias::new("glyph_aspect"))
+            .query(query)
+            .with_check_option()
+            .try_to_string(PostgresQueryBuilder),
+        Err(Error::Unsupported(
+            "WITH CHECK OPTION of materialized view is not available in Postgres.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        View::drop()
+            .materialized()
+            .view(Alias::new("glyph_aspect"))
+            .if_exists()
+            .cascade()
+            .to_string(PostgresQueryBuilder),
+        r#"DROP MATERIALIZED VIEW IF EXISTS "glyph_aspect" CASCADE"#
+    );
+}
+
+#[test]
+fn rename_1() {
+    assert_eq!(
+        View::rename()
+            .view(Alias::new("glyph_image"), Alias::new("glyph_image_new"))
+            .to_string(PostgresQueryBuilder),
+        r#"ALTER VIEW "glyph_image" RENAME TO "glyph_image_new""#
+    );
+}
+
+#[test]
+fn refresh_1() {
+    assert_eq!(
+        View::refresh()
+            .view(Alias::new("glyph_aspect"))
+            .with_data(true)
+            .to_string(PostgresQueryBuilder),
+        r#"REFRESH MATERIALIZED VIEW "glyph_aspect" WITH DATA"#
+    );
+}
@@ -0,0 +1,162 @@
+use sea_query::{tests_cfg::*, *};
+use serde::{de::DeserializeOwned, Serialize};
+
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap()
+}
+
+macro_rules! assert_same_sql {
+    ( $left: expr, $right: expr ) => {
+        assert_eq!(
+            $left.to_string(MysqlQueryBuilder),
+            $right.to_string(MysqlQueryBuilder)
+        );
+        assert_eq!(
+            $left.to_string(PostgresQueryBuilder),
+            $right.to_string(PostgresQueryBuilder)
+        );
+        assert_eq!(
+            $left.to_string(SqliteQueryBuilder),
+            $right.to_string(SqliteQueryBuilder)
+        );
+    };
+}
+
+#[test]
+fn select_1() {
+    let query = Query::select()
+        .columns([Char::Character, Char::SizeW])
+        .expr_as(Func::count(Expr::col(Char::Id)), Alias::new("count"))
+        .from(Char::Table)
+        .left_join(
+            Font::Table,
+            Expr::tbl(Char::Table, Char::FontId).equals(Font::Table, Font::Id),
+        )
+        .cond_where(
+            Cond::any()
+                .add(Expr::col(Char::SizeW).between(1, 10))
+                .add(Expr::col(Char::Character).like("A%"))
+                .add(
+                    Expr::col(Char::FontId).in_subquery(
+                        Query::select()
+                            .column(Font::Id)
+                            .from(Font::Table)
+                            .and_where(Expr::col(Font::Name).is_not_null())
+                            .to_owned(),
+                    ),
+                ),
+        )
+        .group_by_col(Char::Character)
+        .order_by(Char::SizeW, Order::Desc)
+        .limit(10)
+        .offset(20)
+        .to_owned();
+
+    assert_same_sql!(round_trip(&query), query);
+}
+
+#[test]
+fn insert_1() {
+    let query = Query::insert()
+        .into_table(Glyph::Table)
+        .columns([Glyph::Aspect, Glyph::Image])
+        .values_panic([5.15.into(), "12A".into()])
+        .values_panic([
+            Value::Double(None),
+            Value::Bytes(Some(Box::new(vec![1, 2]))),
+        ])
+        .to_owned();
+
+    assert_same_sql!(round_trip(&query), query);
+}
+
+#[test]
+fn update_1() {
+    let query = Query::update()
+        .table(Glyph::Table)
+        .values([
+            (Glyph::Aspect, 2.1345.into()),
+            (Glyph::Image, "235m".into()),
+        ])
+        .and_where(Expr::col(Glyph::Id).eq(1))
+        .to_owned();
+
+    assert_same_sql!(round_trip(&query), query);
+}
+
+#[test]
+fn table_create_1() {
+    let table = Table::create()
+        .table(Char::Table)
+        .if_not_exists()
+        .col(
+            ColumnDef::new(Char::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(
+            ColumnDef::new(Char::FontSize)
+                .integer()
+                .not_null()
+                .default(12),
+        )
+        .col(ColumnDef::new(Char::Character).string_len(255).not_null())
+        .col(ColumnDef::new(Char::SizeW).decimal_len(10, 2))
+        .col(ColumnDef::new(Char::FontId).integer())
+        .foreign_key(
+            ForeignKey::create()
+                .name("FK_character_font")
+                .from(Char::Table, Char::FontId)
+                .to(Font::Table, Font::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::Restrict),
+        )
+        .to_owned();
+
+    assert_same_sql!(round_trip(&table), table);
+}
+
+#[test]
+fn schema_statement_1() {
+    let stmt = SchemaStatement::IndexStatement(IndexStatement::Create(
+        Index::create()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .unique()
+            .to_owned(),
+    ));
+
+    assert_same_sql!(round_trip(&stmt), stmt);
+}
+
+#[test]
+fn iden_1() {
+    let expr = Expr::tbl(Glyph::Table, Glyph::Image).eq("A");
+
+    assert_eq!(
+        serde_json::to_value(&expr).unwrap(),
+        serde_json::json!({
+            "Binary": [
+                { "Column": { "TableColumn": ["glyph", "image"] } },
+                "Equal",
+                { "Value": { "String": "A" } }
+            ]
+        })
+    );
+}
+
+#[test]
+fn value_1() {
+    let values = vec![
+        Value::Bool(Some(true)),
+        Value::TinyInt(Some(-1)),
+        Value::BigUnsigned(Some(u64::MAX)),
+        Value::Float(None),
+        Value::String(Some(Box::new("a\"b".to_owned()))),
+    ];
+
+    assert_eq!(round_trip(&Values(values.clone())), Values(values));
+}
@@ -0,0 +1,76 @@
+use super::discovery::discover;
+use super::*;
+use rusqlite::Connection;
+use sea_query::{diff::SchemaDiff, error::Error};
+
+#[test]
+fn diff_1() {
+    let actual = Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Glyph::Image).string())
+        .to_owned();
+
+    let desired = Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Glyph::Image).binary())
+        .col(ColumnDef::new(Glyph::Aspect).double())
+        .to_owned();
+
+    assert_eq!(
+        SchemaDiff::new()
+            .diff(SqliteQueryBuilder, &[desired], &[actual])
+            .iter()
+            .map(|stmt| stmt.try_to_string(SqliteQueryBuilder))
+            .collect::<Vec<_>>(),
+        [
+            Ok(r#"ALTER TABLE "glyph" ADD COLUMN "aspect" real"#.to_owned()),
+            Err(Error::Unsupported(
+                "Sqlite not support modifying table column".to_owned()
+            )),
+        ]
+    );
+}
+
+#[test]
+fn diff_2() {
+    let conn = Connection::open_in_memory().unwrap();
+    let font = Table::create()
+        .table(Font::Table)
+        .col(
+            ColumnDef::new(Font::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(Font::Name).string().not_null().unique_key())
+        .col(ColumnDef::new(Font::Variant).string().default("normal"))
+        .col(ColumnDef::new(Font::Language).integer().default(3))
+        .to_owned();
+    conn.execute(&font.to_string(SqliteQueryBuilder), [])
+        .unwrap();
+    let character = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Char::FontId).integer())
+        .foreign_key(
+            ForeignKey::create()
+                .from(Char::Table, Char::FontId)
+                .to(Font::Table, Font::Id)
+                .on_delete(ForeignKeyAction::Cascade),
+        )
+        .to_owned();
+    conn.execute(&character.to_string(SqliteQueryBuilder), [])
+        .unwrap();
+
+    let actual = [
+        discover(&conn, "font").to_table_create_statement(),
+        discover(&conn, "character").to_table_create_statement(),
+    ];
+
+    assert!(SchemaDiff::new()
+        .diff(SqliteQueryBuilder, &[font, character], &actual)
+        .is_empty());
+}
@@ -0,0 +1,223 @@
+use super::*;
+use rusqlite::Connection;
+use sea_query::schema::discovery::{sqlite, DiscoveredTable};
+
+pub fn discover(conn: &Connection, table: &str) -> DiscoveredTable {
+    let table_row = conn
+        .query_row(
+            &sqlite::tables_query()
+                .and_where(Expr::col(Alias::new("name")).eq(table))
+                .to_string(SqliteQueryBuilder),
+            [],
+            |row| {
+                Ok(sqlite::TableRow {
+                    name: row.get(0)?,
+                    sql: row.get(1)?,
+                })
+            },
+        )
+        .unwrap();
+
+    let columns = conn
+        .prepare(&sqlite::columns_query(table).to_string(SqliteQueryBuilder))
+        .unwrap()
+        .query_map([], |row| {
+            Ok(sqlite::ColumnRow {
+                name: row.get(0)?,
+                column_type: row.get(1)?,
+                not_null: row.get(2)?,
+                default: row.get(3)?,
+                primary_key: row.get(4)?,
+            })
+        })
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    let indexes = conn
+        .prepare(&sqlite::indexes_query(table).to_string(SqliteQueryBuilder))
+        .unwrap()
+        .query_map([], |row| {
+            Ok(sqlite::IndexRow {
+                name: row.get(0)?,
+                unique: row.get(1)?,
+                origin: row.get(2)?,
+                columns: Vec::new(),
+            })
+        })
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap()
+        .into_iter()
+        .map(|mut index| {
+            index.columns = conn
+                .prepare(&sqlite::index_columns_query(&index.name).to_string(SqliteQueryBuilder))
+                .unwrap()
+                .query_map([], |row| row.get(0))
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            index
+        })
+        .collect();
+
+    let foreign_keys = conn
+        .prepare(&sqlite::foreign_keys_query(table).to_string(SqliteQueryBuilder))
+        .unwrap()
+        .query_map([], |row| {
+            Ok(sqlite::ForeignKeyRow {
+                id: row.get(0)?,
+                table: row.get(1)?,
+                from: row.get(2)?,
+                to: row.get(3)?,
+                on_update: row.get(4)?,
+                on_delete: row.get(5)?,
+            })
+        })
+        .unwrap()
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    sqlite::discover_table(table_row, columns, indexes, foreign_keys)
+}
+
+#[test]
+fn discover_1() {
+    let conn = Connection::open_in_memory().unwrap();
+    let font = Table::create()
+        .table(Font::Table)
+        .col(
+            ColumnDef::new(Font::Id)
+                .integer()
+                .not_null()
+                .auto_increment()
+                .primary_key(),
+        )
+        .col(ColumnDef::new(Font::Name).string().not_null().unique_key())
+        .col(ColumnDef::new(Font::Variant).string().default("normal"))
+        .to_string(SqliteQueryBuilder);
+    conn.execute(&font, []).unwrap();
+
+    assert_eq!(
+        discover(&conn, "font")
+            .to_table_create_statement()
+            .to_string(SqliteQueryBuilder),
+        font
+    );
+}
+
+#[test]
+fn discover_2() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute(
+        &Table::create()
+            .table(Font::Table)
+            .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+            .to_string(SqliteQueryBuilder),
+        [],
+    )
+    .unwrap();
+    let character = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::FontId).integer().not_null())
+        .col(ColumnDef::new(Char::Character).string().not_null())
+        .col(ColumnDef::new(Char::SizeW).decimal_len(10, 2))
+        .primary_key(Index::create().col(Char::FontId).col(Char::Character))
+        .foreign_key(
+            ForeignKey::create()
+                .from(Char::Table, Char::FontId)
+                .to(Font::Table, Font::Id)
+                .on_delete(ForeignKeyAction::Cascade)
+                .on_update(ForeignKeyAction::SetNull),
+        )
+        .to_string(SqliteQueryBuilder);
+    conn.execute(&character, []).unwrap();
+    let index = Index::create()
+        .name("idx-character-size_w")
+        .table(Char::Table)
+        .col(Char::SizeW)
+        .to_string(SqliteQueryBuilder);
+    conn.execute(&index, []).unwrap();
+
+    let discovered = discover(&conn, "character");
+    assert_eq!(
+        discovered
+            .to_table_create_statement()
+            .to_string(SqliteQueryBuilder),
+        character
+    );
+    assert_eq!(
+        discovered
+            .to_index_create_statements()
+            .iter()
+            .map(|index| index.to_string(SqliteQueryBuilder))
+            .collect::<Vec<_>>(),
+        vec![index]
+    );
+}
+
+#[test]
+fn discover_3() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute(
+        r#"CREATE TABLE "font" ("id" integer NOT NULL PRIMARY KEY AUTOINCREMENT)"#,
+        [],
+    )
+    .unwrap();
+    conn.execute(
+        r#"CREATE TABLE "character" ("font_id" integer NOT NULL, "character" text NOT NULL, "autoincrement_step" integer, PRIMARY KEY ("font_id", "character"), FOREIGN KEY ("font_id") REFERENCES "font")"#,
+        [],
+    )
+    .unwrap();
+
+    let font = discover(&conn, "font");
+    assert!(font.columns[0].auto_increment);
+
+    let character = discover(&conn, "character");
+    assert!(character
+        .columns
+        .iter()
+        .all(|column| !column.auto_increment));
+    assert_eq!(character.foreign_keys[0].ref_table, "font");
+    assert_eq!(character.foreign_keys[0].ref_columns, vec!["id".to_owned()]);
+}
+
+#[test]
+fn discover_4() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute(
+        r#"CREATE TABLE "glyph" ("id" integer PRIMARY KEY, "aspect" text DEFAULT 'AUTOINCREMENT', "image" text, /* AUTOINCREMENT */ UNIQUE ("aspect", "image"))"#,
+        [],
+    )
+    .unwrap();
+
+    let glyph = discover(&conn, "glyph");
+    assert!(glyph.columns.iter().all(|column| !column.auto_increment));
+    assert_eq!(glyph.indexes[0].name, "sqlite_autoindex_glyph_1");
+    assert!(glyph.indexes[0].unique);
+    assert_eq!(
+        glyph.indexes[0].columns,
+        vec!["aspect".to_owned(), "image".to_owned()]
+    );
+}
+
+#[test]
+fn discover_5() {
+    let conn = Connection::open_in_memory().unwrap();
+    let character = Table::create()
+        .table(Char::Table)
+        .col(ColumnDef::new(Char::FontId).integer().not_null())
+        .col(ColumnDef::new(Char::Character).string().not_null())
+        .primary_key(Index::create().col(Char::Character).col(Char::FontId))
+        .to_string(SqliteQueryBuilder);
+    conn.execute(&character, []).unwrap();
+
+    let discovered = discover(&conn, "character");
+    assert_eq!(discovered.primary_key, vec!["character", "font_id"]);
+    assert_eq!(
+        discovered
+            .to_table_create_statement()
+            .to_string(SqliteQueryBuilder),
+        character
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use sea_query::error::Error;
 
 #[test]
 fn create_1() {
@@ -26,6 +27,59 @@ fn create_2() {
     );
 }
 
+#[test]
+fn create_3() {
+    assert_eq!(
+        Index::create()
+            .if_not_exists()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(IndexColumn::new(Glyph::Aspect).collate("NOCASE"))
+            .and_where(Expr::col(Glyph::Image).is_not_null())
+            .to_string(SqliteQueryBuilder),
+        r#"CREATE INDEX IF NOT EXISTS "idx-glyph-aspect" ON "glyph" ("aspect" COLLATE "NOCASE") WHERE "image" IS NOT NULL"#
+    );
+}
+
+#[test]
+fn create_4() {
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-image")
+            .table(Glyph::Table)
+            .col(Glyph::Image)
+            .index_type(IndexType::Gin)
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Index type is not available in SQLite.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Index::create()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(IndexColumn::new(Glyph::Aspect).nulls(NullOrdering::Last))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Index column nulls ordering is not available in SQLite.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Aspect).double())
+            .index(
+                Index::create()
+                    .unique()
+                    .col(IndexColumn::new(Glyph::Aspect).nulls(NullOrdering::First))
+            )
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Index column nulls ordering is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -33,6 +87,40 @@ fn drop_1() {
             .name("idx-glyph-aspect")
             .table(Glyph::Table)
             .to_string(SqliteQueryBuilder),
-        r#"DROP INDEX "idx-glyph-aspect" ON "glyph""#
+        r#"DROP INDEX "idx-glyph-aspect""#
+    );
+}
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        Index::drop()
+            .if_exists()
+            .name("idx-glyph-aspect")
+            .to_string(SqliteQueryBuilder),
+        r#"DROP INDEX IF EXISTS "idx-glyph-aspect""#
+    );
+}
+
+#[test]
+fn reindex_1() {
+    assert_eq!(
+        Index::reindex()
+            .schema(Alias::new("main"))
+            .name("idx-glyph-aspect")
+            .to_string(SqliteQueryBuilder),
+        r#"REINDEX "main"."idx-glyph-aspect""#
+    );
+}
+
+#[test]
+fn rename_1() {
+    assert_eq!(
+        Index::rename()
+            .index("idx-glyph-aspect", "idx-glyph-aspect-new")
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Index rename is not available in SQLite.".to_owned()
+        ))
     );
 }
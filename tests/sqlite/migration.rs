@@ -0,0 +1,266 @@
+use super::*;
+use rusqlite::{types::Value as SqliteValue, Connection};
+use sea_query::{error::Error, migration::*};
+
+struct Executor<'a>(&'a Connection);
+
+fn params(values: Values) -> Vec<SqliteValue> {
+    values
+        .0
+        .into_iter()
+        .map(|value| match value {
+            Value::Int(Some(v)) => SqliteValue::Integer(v as i64),
+            Value::BigInt(Some(v)) => SqliteValue::Integer(v),
+            Value::String(Some(v)) => SqliteValue::Text(*v),
+            value => unimplemented!("{:?}", value),
+        })
+        .collect()
+}
+
+impl MigrationExecutor for Executor<'_> {
+    type Error = rusqlite::Error;
+
+    fn execute(&mut self, sql: &str, values: Values) -> Result<(), Self::Error> {
+        self.0
+            .execute(sql, rusqlite::params_from_iter(params(values)))
+            .map(|_| ())
+    }
+
+    fn query_history(
+        &mut self,
+        sql: &str,
+        values: Values,
+    ) -> Result<Vec<AppliedMigration>, Self::Error> {
+        self.0
+            .prepare(sql)?
+            .query_map(rusqlite::params_from_iter(params(values)), |row| {
+                Ok(AppliedMigration {
+                    version: row.get(0)?,
+                    name: row.get(1)?,
+                    checksum: row.get(2)?,
+                })
+            })?
+            .collect()
+    }
+}
+
+fn migrator() -> Migrator {
+    Migrator::new()
+        .migration(
+            Migration::new(1, "create_font")
+                .up(Table::create()
+                    .table(Font::Table)
+                    .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+                    .col(ColumnDef::new(Font::Name).string().not_null())
+                    .to_owned())
+                .down(Table::drop().table(Font::Table).to_owned()),
+        )
+        .migration(
+            Migration::new(2, "seed_font")
+                .up(Query::insert()
+                    .into_table(Font::Table)
+                    .columns([Font::Id, Font::Name])
+                    .values_panic([1.into(), "Arial".into()])
+                    .to_owned())
+                .down(Query::delete().from_table(Font::Table).to_owned()),
+        )
+        .to_owned()
+}
+
+fn tables(conn: &Connection) -> Vec<String> {
+    conn.prepare("SELECT name FROM sqlite_master WHERE type = 'table' ORDER BY name")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap()
+}
+
+fn fonts(conn: &Connection) -> i64 {
+    conn.query_row("SELECT COUNT(*) FROM font", [], |row| row.get(0))
+        .unwrap()
+}
+
+#[test]
+fn migrate_1() {
+    let conn = Connection::open_in_memory().unwrap();
+    let migrator = migrator();
+
+    assert_eq!(
+        migrator.up_to(SqliteQueryBuilder, &mut Executor(&conn), 1),
+        Ok(vec![1])
+    );
+    assert_eq!(tables(&conn), ["font", "seaql_migrations"]);
+    assert_eq!(
+        migrator.up(SqliteQueryBuilder, &mut Executor(&conn)),
+        Ok(vec![2])
+    );
+    assert_eq!(fonts(&conn), 1);
+    assert_eq!(
+        migrator.up(SqliteQueryBuilder, &mut Executor(&conn)),
+        Ok(vec![])
+    );
+
+    let applied = migrator
+        .check(SqliteQueryBuilder, &mut Executor(&conn))
+        .unwrap();
+    assert_eq!(
+        applied
+            .iter()
+            .map(|a| (a.version, a.name.as_str()))
+            .collect::<Vec<_>>(),
+        [(1, "create_font"), (2, "seed_font")]
+    );
+    assert_eq!(
+        applied[0].checksum,
+        migrator.get_migrations()[0].checksum(SqliteQueryBuilder)
+    );
+
+    assert_eq!(
+        migrator.down(SqliteQueryBuilder, &mut Executor(&conn), 1),
+        Ok(vec![2])
+    );
+    assert_eq!(fonts(&conn), 0);
+    assert_eq!(
+        migrator.down(SqliteQueryBuilder, &mut Executor(&conn), 5),
+        Ok(vec![1])
+    );
+    assert_eq!(tables(&conn), ["seaql_migrations"]);
+}
+
+#[test]
+fn migrate_2() {
+    let conn = Connection::open_in_memory().unwrap();
+    migrator()
+        .up(SqliteQueryBuilder, &mut Executor(&conn))
+        .unwrap();
+
+    let drifted = Migrator::new()
+        .migration(
+            Migration::new(1, "create_font").up(Table::create()
+                .table(Font::Table)
+                .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+                .to_owned()),
+        )
+        .migration(&mut migrator().get_migrations()[1].clone())
+        .to_owned();
+    assert_eq!(
+        drifted.up(SqliteQueryBuilder, &mut Executor(&conn)),
+        Err(MigrationError::Drift {
+            version: 1,
+            name: "create_font".to_owned()
+        })
+    );
+
+    let unknown = Migrator::new()
+        .migration(&mut migrator().get_migrations()[0].clone())
+        .to_owned();
+    assert_eq!(
+        unknown.check(SqliteQueryBuilder, &mut Executor(&conn)),
+        Err(MigrationError::Unknown {
+            version: 2,
+            name: "seed_font".to_owned()
+        })
+    );
+
+    let out_of_order = migrator()
+        .migration(&mut Migration::new(0, "too_late"))
+        .to_owned();
+    assert_eq!(
+        out_of_order.up(SqliteQueryBuilder, &mut Executor(&conn)),
+        Err(MigrationError::OutOfOrder {
+            version: 0,
+            name: "too_late".to_owned()
+        })
+    );
+}
+
+#[test]
+fn migrate_3() {
+    let conn = Connection::open_in_memory().unwrap();
+    let migrator = migrator()
+        .migration(
+            Migration::new(3, "broken")
+                .up(Table::create()
+                    .table(Glyph::Table)
+                    .col(ColumnDef::new(Glyph::Id).integer().not_null().primary_key())
+                    .to_owned())
+                .up(Query::insert()
+                    .into_table(Alias::new("missing"))
+                    .columns([Glyph::Id])
+                    .values_panic([1.into()])
+                    .to_owned()),
+        )
+        .to_owned();
+
+    assert!(matches!(
+        migrator.up(SqliteQueryBuilder, &mut Executor(&conn)),
+        Err(MigrationError::Executor(_))
+    ));
+    // The failed migration is rolled back, the earlier ones stay applied
+    assert_eq!(tables(&conn), ["font", "seaql_migrations"]);
+    assert_eq!(
+        migrator
+            .check(SqliteQueryBuilder, &mut Executor(&conn))
+            .unwrap()
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn migrate_4() {
+    let conn = Connection::open_in_memory().unwrap();
+    let migrator = migrator()
+        .migration(
+            Migration::new(3, "unsupported").up(Table::create()
+                .table(Glyph::Table)
+                .col(
+                    ColumnDef::new(Glyph::Id)
+                        .integer()
+                        .identity(ColumnIdentity::by_default().increment_by(2)),
+                )
+                .to_owned()),
+        )
+        .to_owned();
+
+    assert_eq!(
+        migrator.up(SqliteQueryBuilder, &mut Executor(&conn)),
+        Err(MigrationError::Unsupported {
+            version: 3,
+            name: "unsupported".to_owned(),
+            error: Error::Unsupported("Identity increment is not available in SQLite.".to_owned()),
+        })
+    );
+    // The pending migrations are checked before any of them is run
+    assert_eq!(tables(&conn), ["seaql_migrations"]);
+}
+
+#[test]
+fn migrate_5() {
+    struct AsyncExecutor;
+
+    impl AsyncMigrationExecutor for AsyncExecutor {
+        type Error = ();
+
+        async fn execute(&mut self, _: &str, _: Values) -> Result<(), ()> {
+            Ok(())
+        }
+
+        async fn query_history(&mut self, _: &str, _: Values) -> Result<Vec<AppliedMigration>, ()> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn assert_send<T: Send>(_: T) {}
+
+    fn up_async<E>(migrator: &Migrator, executor: &mut E)
+    where
+        E: AsyncMigrationExecutor + Send,
+        E::Error: Send,
+    {
+        assert_send(migrator.up_async(SqliteQueryBuilder, executor));
+    }
+
+    up_async(&migrator(), &mut AsyncExecutor);
+}
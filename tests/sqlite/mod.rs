@@ -1,7 +1,16 @@
 use sea_query::{tests_cfg::*, *};
 
+mod diff;
+mod discovery;
 mod foreign_key;
 mod index;
+mod migration;
+mod namespace;
+mod privilege;
 #[allow(deprecated)]
 mod query;
+mod schema_set;
 mod table;
+mod transaction;
+mod trigger;
+mod view;
@@ -0,0 +1,43 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn attach_1() {
+    assert_eq!(
+        Database::attach()
+            .file("tenant_1.db")
+            .name(Alias::new("tenant_1"))
+            .to_string(SqliteQueryBuilder),
+        r#"ATTACH DATABASE 'tenant_1.db' AS "tenant_1""#
+    );
+}
+
+#[test]
+fn detach_1() {
+    assert_eq!(
+        Database::detach()
+            .name(Alias::new("tenant_1"))
+            .to_string(SqliteQueryBuilder),
+        r#"DETACH DATABASE "tenant_1""#
+    );
+}
+
+#[test]
+fn create_schema_1() {
+    assert_eq!(
+        Schema::create()
+            .name(Alias::new("tenant_1"))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Schema is not available in SQLite, attach a database instead.".to_owned()
+        ))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Search path is not available in SQLite.")]
+fn search_path_1() {
+    Schema::search_path()
+        .schema(Alias::new("tenant_1"))
+        .to_string(SqliteQueryBuilder);
+}
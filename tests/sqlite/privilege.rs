@@ -0,0 +1,54 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn grant_1() {
+    assert_eq!(
+        GrantStatement::new()
+            .privilege(Privilege::Select)
+            .on_table(Glyph::Table)
+            .to(Alias::new("reporting"))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Grant is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn revoke_1() {
+    assert_eq!(
+        RevokeStatement::new()
+            .privilege(Privilege::Select)
+            .on_table(Glyph::Table)
+            .from(Alias::new("reporting"))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Revoke is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_role_1() {
+    assert_eq!(
+        Role::create()
+            .name(Alias::new("reporting"))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Role is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn drop_role_1() {
+    assert_eq!(
+        Role::drop()
+            .name(Alias::new("reporting"))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Role is not available in SQLite.".to_owned()
+        ))
+    );
+}
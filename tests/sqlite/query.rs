@@ -980,3 +980,33 @@ fn delete_1() {
         r#"DELETE FROM "glyph" WHERE "id" = 1"#
     );
 }
+
+#[test]
+fn explain_1() {
+    assert_eq!(
+        Query::explain()
+            .statement(
+                Query::insert()
+                    .into_table(Glyph::Table)
+                    .columns(vec![Glyph::Image])
+                    .values_panic(vec!["04108048005887010020060000204E0180400400".into()])
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        r#"EXPLAIN QUERY PLAN INSERT INTO "glyph" ("image") VALUES ('04108048005887010020060000204E0180400400')"#
+    );
+}
+
+#[test]
+#[should_panic(expected = "Explain options are not available in SQLite.")]
+fn explain_2() {
+    Query::explain()
+        .statement(
+            Query::select()
+                .column(Glyph::Id)
+                .from(Glyph::Table)
+                .to_owned(),
+        )
+        .analyze()
+        .to_string(SqliteQueryBuilder);
+}
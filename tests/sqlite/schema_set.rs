@@ -0,0 +1,78 @@
+use super::*;
+use rusqlite::Connection;
+use sea_query::error::Error;
+
+fn schema(cyclic: bool) -> SchemaSet {
+    let mut font = Table::create();
+    font.table(Font::Table)
+        .col(ColumnDef::new(Font::Id).integer().not_null().primary_key())
+        .col(ColumnDef::new(Font::Variant).integer());
+    if cyclic {
+        font.foreign_key(
+            ForeignKey::create()
+                .from(Font::Table, Font::Variant)
+                .to(Char::Table, Char::Id),
+        );
+    }
+    SchemaSet::new()
+        .table(
+            Table::create()
+                .table(Char::Table)
+                .col(ColumnDef::new(Char::Id).integer().not_null().primary_key())
+                .col(ColumnDef::new(Char::FontId).integer())
+                .foreign_key(
+                    ForeignKey::create()
+                        .from(Char::Table, Char::FontId)
+                        .to(Font::Table, Font::Id),
+                ),
+        )
+        .table(&mut font)
+        .to_owned()
+}
+
+#[test]
+fn schema_set_1() {
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch("PRAGMA foreign_keys = ON").unwrap();
+    let schema = schema(false);
+
+    let create = schema.build_create(SqliteQueryBuilder).unwrap();
+    assert_eq!(
+        create,
+        [
+            r#"CREATE TABLE "font" ( "id" integer NOT NULL PRIMARY KEY, "variant" integer )"#,
+            [
+                r#"CREATE TABLE "character" ( "id" integer NOT NULL PRIMARY KEY, "font_id" integer,"#,
+                r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id") )"#,
+            ]
+            .join(" ")
+            .as_str(),
+        ]
+    );
+    for sql in create {
+        conn.execute(&sql, []).unwrap();
+    }
+    conn.execute_batch(
+        r#"INSERT INTO "font" VALUES (1, NULL); INSERT INTO "character" VALUES (1, 1)"#,
+    )
+    .unwrap();
+
+    for sql in schema.build_drop(SqliteQueryBuilder) {
+        conn.execute(&sql, []).unwrap();
+    }
+}
+
+#[test]
+fn schema_set_2() {
+    assert_eq!(
+        schema(true).build_create(SqliteQueryBuilder),
+        Err(Error::ForeignKeyCycle(vec![
+            "character".to_owned(),
+            "font".to_owned()
+        ]))
+    );
+    assert_eq!(
+        schema(true).build_drop(SqliteQueryBuilder),
+        [r#"DROP TABLE "font""#, r#"DROP TABLE "character""#]
+    );
+}
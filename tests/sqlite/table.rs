@@ -1,4 +1,5 @@
 use super::*;
+use sea_query::error::Error;
 
 #[test]
 fn create_1() {
@@ -99,6 +100,221 @@ fn create_3() {
     );
 }
 
+#[test]
+fn create_4() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .big_integer_len(20)
+                    .not_null()
+                    .identity(ColumnIdentity::by_default())
+            )
+            .col(ColumnDef::new(Glyph::Aspect).double().not_null())
+            .to_string(SqliteQueryBuilder),
+        vec![
+            r#"CREATE TABLE "glyph" ("#,
+            r#""id" integer NOT NULL PRIMARY KEY,"#,
+            r#""aspect" real NOT NULL"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_5() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Alias::new("created_at"))
+                    .timestamp()
+                    .not_null()
+                    .default(Expr::current_timestamp())
+            )
+            .col(
+                ColumnDef::new(Alias::new("expires_at"))
+                    .timestamp()
+                    .default(Func::cust(Alias::new("datetime")).args(vec!["now", "+1 day"]))
+            )
+            .to_string(SqliteQueryBuilder),
+        vec![
+            r#"CREATE TABLE "glyph" ("#,
+            r#""created_at" text NOT NULL DEFAULT CURRENT_TIMESTAMP,"#,
+            r#""expires_at" text DEFAULT (datetime('now', '+1 day'))"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_6() {
+    assert_eq!(
+        Table::create()
+            .table(Font::Table)
+            .col(
+                ColumnDef::new(Font::Name)
+                    .string()
+                    .not_null()
+                    .comment("Display name")
+            )
+            .comment("Font families")
+            .to_string(SqliteQueryBuilder),
+        r#"CREATE TABLE "font" ( "name" text NOT NULL )"#
+    );
+}
+
+#[test]
+fn create_7() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .partition_by(PartitionMethod::Hash, vec![Expr::col(Glyph::Id)])
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Table partitioning is not available in SQLite.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Table::create()
+            .table(Alias::new("glyph_small"))
+            .partition_of(Glyph::Table, PartitionBound::values_in(vec![1]))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Table partitioning is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Table partitioning is not available in SQLite.")]
+fn create_8() {
+    Table::create()
+        .table(Glyph::Table)
+        .col(ColumnDef::new(Glyph::Id).integer().not_null())
+        .partition_by(PartitionMethod::Hash, vec![Expr::col(Glyph::Id)])
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn create_9() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer().not_null())
+            .col(ColumnDef::new(Glyph::Image).string().not_null())
+            .index(
+                Index::create()
+                    .unique()
+                    .name("idx-glyph-image")
+                    .col(Glyph::Image)
+                    .include(Glyph::Id)
+            )
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Covering index is not available in a table definition.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_10() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).identity(ColumnIdentity::by_default()))
+            .to_string(SqliteQueryBuilder),
+        r#"CREATE TABLE "glyph" ( "id" integer PRIMARY KEY )"#
+    );
+}
+
+#[test]
+fn create_11() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .integer()
+                    .identity(ColumnIdentity::by_default().increment_by(2))
+            )
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Identity increment is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_12() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Alias::new("updated_at"))
+                    .timestamp()
+                    .on_update(Expr::current_timestamp())
+            )
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Column ON UPDATE is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_13() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .integer()
+                    .identity(ColumnIdentity::by_default().start_with(100))
+            )
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Identity start is not available in SQLite.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .integer()
+                    .identity(ColumnIdentity::by_default())
+            )
+            .col(ColumnDef::new(Glyph::Aspect).double().not_null())
+            .primary_key(Index::create().col(Glyph::Id).col(Glyph::Aspect))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Identity column with another primary key is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn create_14() {
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(
+                ColumnDef::new(Glyph::Id)
+                    .integer()
+                    .identity(ColumnIdentity::always())
+            )
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Identity generated always is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -0,0 +1,52 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn begin_1() {
+    assert_eq!(
+        Transaction::begin()
+            .behavior(TransactionBehavior::Exclusive)
+            .to_string(SqliteQueryBuilder),
+        "BEGIN EXCLUSIVE"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Transaction modes are not available in SQLite.")]
+fn begin_2() {
+    Transaction::begin()
+        .read_only()
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+#[should_panic(expected = "Lock table is not available in SQLite.")]
+fn lock_1() {
+    Transaction::lock()
+        .table(Glyph::Table)
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn lock_2() {
+    assert_eq!(
+        Transaction::lock()
+            .table(Glyph::Table)
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Lock table is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn set_1() {
+    assert_eq!(
+        Transaction::set()
+            .read_only()
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Set transaction is not available in SQLite.".to_owned()
+        ))
+    );
+}
@@ -0,0 +1,63 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        Trigger::create()
+            .if_not_exists()
+            .name(Alias::new("glyph_audit"))
+            .after()
+            .update_of(vec![Glyph::Image])
+            .table(Glyph::Table)
+            .for_each_row()
+            .when(Expr::cust("NEW.aspect > 2"))
+            .statement(
+                Query::insert()
+                    .into_table(Alias::new("audit"))
+                    .columns(vec![Alias::new("glyph_id")])
+                    .exprs_panic(vec![Expr::cust("NEW.id")])
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        [
+            r#"CREATE TRIGGER IF NOT EXISTS "glyph_audit" AFTER UPDATE OF "image" ON "glyph""#,
+            r#"FOR EACH ROW WHEN (NEW.aspect > 2)"#,
+            r#"BEGIN INSERT INTO "audit" ("glyph_id") VALUES (NEW.id); END"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        Trigger::drop()
+            .if_exists()
+            .name(Alias::new("glyph_audit"))
+            .to_string(SqliteQueryBuilder),
+        r#"DROP TRIGGER IF EXISTS "glyph_audit""#
+    );
+}
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        Trigger::drop()
+            .name(Alias::new("glyph_audit"))
+            .table(Glyph::Table)
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Drop trigger ON table is not available in SQLite.".to_owned()
+        ))
+    );
+    assert_eq!(
+        Trigger::drop()
+            .name(Alias::new("glyph_audit"))
+            .restrict()
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Drop trigger options are not available in SQLite.".to_owned()
+        ))
+    );
+}
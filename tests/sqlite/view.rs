@@ -0,0 +1,91 @@
+use super::*;
+use sea_query::error::Error;
+
+#[test]
+fn create_1() {
+    assert_eq!(
+        View::create()
+            .view(Alias::new("glyph_image"))
+            .columns(vec![Glyph::Id, Glyph::Image])
+            .query(
+                Query::select()
+                    .columns(vec![Glyph::Id, Glyph::Image])
+                    .from(Glyph::Table)
+                    .to_owned()
+            )
+            .to_string(SqliteQueryBuilder),
+        r#"CREATE VIEW "glyph_image" ("id", "image") AS SELECT "id", "image" FROM "glyph""#
+    );
+}
+
+#[test]
+fn drop_1() {
+    assert_eq!(
+        View::drop()
+            .view(Alias::new("glyph_image"))
+            .if_exists()
+            .cascade()
+            .to_string(SqliteQueryBuilder),
+        r#"DROP VIEW IF EXISTS "glyph_image""#
+    );
+}
+
+#[test]
+fn drop_2() {
+    assert_eq!(
+        View::drop()
+            .view(Alias::new("font_name"))
+            .view(Alias::new("glyph_image"))
+            .if_exists()
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Dropping multiple views is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn drop_3() {
+    assert_eq!(
+        View::drop()
+            .materialized()
+            .view(Alias::new("glyph_image"))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Materialized view is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Materialized view is not available in SQLite.")]
+fn drop_4() {
+    View::drop()
+        .materialized()
+        .view(Alias::new("glyph_image"))
+        .to_string(SqliteQueryBuilder);
+}
+
+#[test]
+fn rename_1() {
+    assert_eq!(
+        View::rename()
+            .view(Alias::new("glyph_image"), Alias::new("glyph_image_new"))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "View rename is not available in SQLite.".to_owned()
+        ))
+    );
+}
+
+#[test]
+fn refresh_1() {
+    assert_eq!(
+        View::refresh()
+            .view(Alias::new("glyph_image"))
+            .try_to_string(SqliteQueryBuilder),
+        Err(Error::Unsupported(
+            "Materialized view is not available in SQLite.".to_owned()
+        ))
+    );
+}